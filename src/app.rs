@@ -0,0 +1,1173 @@
+// Estado da aplicação: jogo, menus, configurações, temas e idiomas
+
+use crate::game::ai::CpuPlayer;
+use crate::game::arena::Arena;
+use crate::game::game_state::{GamePhase, GameState, Side};
+use crate::game::handicap::HandicapOption;
+use crate::game::powerups::PowerUpKind;
+use crate::game::practice::PracticeTarget;
+use crate::game::rating;
+use crate::game::rules::{ServeRule, WinRule};
+use crate::game::theme::Theme;
+use crate::game::tournament::{self, Tournament, TournamentFormat, TournamentMatch};
+use crate::locale::Locale;
+use crate::storage::leaderboards::{Board, Leaderboards, Record};
+use crate::storage::profiles::Profiles;
+use crate::storage::savegame;
+use crate::storage::settings::{self, ControlScheme, DisplayMode, Settings};
+use crate::ui::editor::{Editor, EditorAction};
+use crate::ui::menu::{Menu, MenuCommand, MenuId, MenuInput, SettingId};
+use crate::ui::name_entry::{NameEntry, NameEntryAction, NamePurpose};
+use sdl2::controller::Button;
+use sdl2::keyboard::Keycode;
+use std::path::PathBuf;
+
+/// Reúne tudo o que o loop principal precisa para atualizar e desenhar o jogo
+pub struct App {
+    /// Estado do jogo (bola, raquetes, placar)
+    pub game_state: GameState,
+    /// Temas de cores disponíveis
+    pub themes: Vec<Theme>,
+    /// Arenas disponíveis
+    pub arenas: Vec<Arena>,
+    /// Editor de arenas (`Some` enquanto estiver aberto, inclusive durante o teste)
+    pub editor: Option<Editor>,
+    /// Recordes de cada modo
+    pub leaderboards: Leaderboards,
+    /// Ranking mostrado na tela de recordes
+    pub leaderboard_board: Board,
+    /// Posição no ranking da última partida de arcade (`None` se ela não entrou)
+    pub arcade_rank: Option<usize>,
+    /// Indica que o resultado da partida encerrada já entrou nos rankings
+    match_recorded: bool,
+    /// Arquivo onde os recordes são salvos (`None` se não houver diretório disponível)
+    leaderboards_path: Option<PathBuf>,
+    /// Perfis dos jogadores
+    pub profiles: Profiles,
+    /// Índice do perfil mostrado na tela de estatísticas
+    pub stats_profile: usize,
+    /// Nome sendo digitado (`Some` enquanto a tela de nome estiver aberta)
+    pub name_entry: Option<NameEntry>,
+    /// Arquivo onde os perfis são salvos (`None` se não houver diretório disponível)
+    profiles_path: Option<PathBuf>,
+    /// Jogadores inscritos no próximo torneio
+    pub tournament_players: Vec<String>,
+    /// Formato do próximo torneio
+    pub tournament_format: TournamentFormat,
+    /// Torneio em andamento
+    pub tournament: Option<Tournament>,
+    /// Jogadores da partida de torneio em jogo (`Some` até a volta para a chave)
+    pub tournament_match: Option<TournamentMatch>,
+    /// Chave do catálogo de mensagens com o motivo de o torneio não ter começado
+    pub tournament_error: Option<&'static str>,
+    /// Arquivo do jogo salvo (`None` se não houver diretório disponível)
+    savegame_path: Option<PathBuf>,
+    /// Resultado da última gravação ou leitura do jogo salvo, mostrado no menu
+    pub save_status: Option<String>,
+    /// Indica que a partida atual veio de um jogo salvo (as regras gravadas
+    /// valem até ela acabar, mesmo que as configurações mudem)
+    restored_match: bool,
+    /// Idiomas disponíveis e idioma selecionado
+    pub locale: Locale,
+    /// Menu com pilha de navegação
+    pub menu: Menu,
+    /// Configurações do jogador
+    pub settings: Settings,
+    /// Última posição Y do mouse (em coordenadas do jogo)
+    pub mouse_y: i32,
+    /// Arquivo onde as configurações são salvas (`None` se não houver diretório disponível)
+    settings_path: Option<PathBuf>,
+    /// Diretório onde o editor salva as arenas
+    arenas_dir: PathBuf,
+    /// Indica que o jogador pediu para sair (o loop principal termina no fim do quadro)
+    quit_requested: bool,
+}
+
+impl App {
+    /// Cria a aplicação e aplica as configurações salvas
+    ///
+    /// # Argumentos
+    ///
+    /// * `game_state` - Estado inicial do jogo
+    /// * `themes` - Temas disponíveis
+    /// * `arenas` - Arenas disponíveis
+    /// * `arenas_dir` - Diretório onde o editor salva as arenas
+    /// * `locale` - Idiomas disponíveis
+    /// * `settings` - Configurações carregadas do disco
+    /// * `settings_path` - Arquivo onde as configurações serão salvas
+    pub fn new(
+        game_state: GameState,
+        themes: Vec<Theme>,
+        arenas: Vec<Arena>,
+        arenas_dir: PathBuf,
+        mut locale: Locale,
+        settings: Settings,
+        settings_path: Option<PathBuf>,
+    ) -> App {
+        // Idioma vazio significa "usar o idioma do sistema" (já selecionado)
+        if !settings.language.is_empty() {
+            locale.set_language(&settings.language);
+        }
+
+        let mut app = App {
+            game_state,
+            themes,
+            arenas,
+            editor: None,
+            leaderboards: Leaderboards::default(),
+            leaderboard_board: Board::LongestRally,
+            arcade_rank: None,
+            match_recorded: false,
+            leaderboards_path: None,
+            profiles: Profiles::default(),
+            stats_profile: 0,
+            name_entry: None,
+            profiles_path: None,
+            tournament_players: Vec::new(),
+            tournament_format: TournamentFormat::SingleElimination,
+            tournament: None,
+            tournament_match: None,
+            tournament_error: None,
+            savegame_path: None,
+            save_status: None,
+            restored_match: false,
+            locale,
+            menu: Menu::new(),
+            settings,
+            mouse_y: 0,
+            settings_path,
+            arenas_dir,
+            quit_requested: false,
+        };
+
+        if let Some(theme) = app
+            .themes
+            .iter()
+            .find(|theme| theme.id == app.settings.theme)
+        {
+            app.game_state.theme = theme.clone();
+        }
+        app.select_arena();
+        app.apply_game_settings();
+
+        app
+    }
+
+    /// Pede para encerrar o jogo ao fim do quadro atual
+    pub fn request_quit(&mut self) {
+        self.quit_requested = true;
+    }
+
+    /// Indica se o loop principal deve terminar
+    pub fn should_quit(&self) -> bool {
+        self.quit_requested
+    }
+
+    /// Sequência de encerramento: grava em disco o estado que precisa ser mantido
+    ///
+    /// Chamada pelo loop principal depois que ele termina, seja pelo menu ou
+    /// pelo fechamento da janela.
+    pub fn shutdown(&mut self) {
+        self.save_settings();
+    }
+
+    /// Carrega os recordes salvos e passa a salvá-los no mesmo arquivo
+    ///
+    /// # Argumentos
+    ///
+    /// * `path` - Arquivo de recordes (`None` se não houver diretório disponível)
+    pub fn load_leaderboards(&mut self, path: Option<PathBuf>) {
+        if let Some(path) = &path {
+            self.leaderboards = Leaderboards::load(path);
+        }
+        self.leaderboards_path = path;
+    }
+
+    /// Carrega os perfis salvos e passa a salvá-los no mesmo arquivo
+    ///
+    /// # Argumentos
+    ///
+    /// * `path` - Arquivo de perfis (`None` se não houver diretório disponível)
+    pub fn load_profiles(&mut self, path: Option<PathBuf>) {
+        if let Some(path) = &path {
+            self.profiles = Profiles::load(path);
+        }
+        self.profiles_path = path;
+
+        // Perfis escolhidos que não existem mais voltam a ser convidados
+        for name in &mut self.settings.profiles {
+            if self.profiles.find(name).is_none() {
+                name.clear();
+            }
+        }
+    }
+
+    /// Define o arquivo onde a partida pausada é salva
+    ///
+    /// # Argumentos
+    ///
+    /// * `path` - Arquivo do jogo salvo (`None` se não houver diretório disponível)
+    pub fn set_savegame_path(&mut self, path: Option<PathBuf>) {
+        self.savegame_path = path;
+    }
+
+    /// Atualiza o jogo e, no fim de uma partida, registra o resultado nos rankings
+    ///
+    /// # Argumentos
+    ///
+    /// * `delta_time` - Tempo decorrido desde o último frame (em segundos)
+    pub fn update(&mut self, delta_time: f32) {
+        self.game_state.update(delta_time);
+
+        // O resultado entra nos rankings uma vez, seja pelo fim da partida ou por desistência
+        if self.game_state.phase == GamePhase::GameOver {
+            if !self.match_recorded {
+                self.record_match();
+                self.match_recorded = true;
+            }
+        } else {
+            self.match_recorded = false;
+        }
+    }
+
+    /// Registra nos rankings o resultado da partida encerrada e salva os recordes
+    fn record_match(&mut self) {
+        let game_state = &self.game_state;
+
+        // Testes de arena e treino não valem recorde
+        if self.editor.is_some() || game_state.practice.is_some() {
+            return;
+        }
+
+        if let Some(arcade) = &game_state.arcade {
+            self.arcade_rank = self
+                .leaderboards
+                .submit(Board::arcade(arcade.mode), arcade.result());
+        } else {
+            let stats = &game_state.stats;
+            let record = |value, won, lost| Record {
+                value,
+                time: stats.duration,
+                returns: stats.longest_rally,
+                points_won: won,
+                points_lost: lost,
+            };
+            let mut records = vec![(
+                Board::LongestRally,
+                record(stats.longest_rally, stats.points_left, stats.points_right),
+            )];
+
+            // Vitória mais rápida: somente partidas normais vencidas em quadra
+            // por um jogador (contra a CPU, só vale a vitória do jogador 1)
+            let standard = game_state.four_player.is_none() && game_state.breakout.is_none();
+            let winner = game_state
+                .winner
+                .filter(|side| *side == Side::Left || game_state.cpu.is_none());
+            if let (true, false, Some(side)) = (standard, game_state.forfeited, winner) {
+                let (won, lost) = match side {
+                    Side::Left => (stats.points_left, stats.points_right),
+                    Side::Right => (stats.points_right, stats.points_left),
+                };
+                records.push((
+                    Board::FastestWin,
+                    record(stats.duration.ceil() as u32, won, lost),
+                ));
+            }
+
+            if let Some(cpu) = &game_state.cpu {
+                records.push((
+                    Board::VsCpu(cpu.difficulty),
+                    record(stats.points_left, stats.points_left, stats.points_right),
+                ));
+            }
+
+            for (board, record) in records {
+                self.leaderboards.submit(board, record);
+            }
+
+            // No torneio os jogadores são os inscritos, não os perfis escolhidos
+            if self.tournament_match.is_some() {
+                self.record_tournament_match();
+            } else {
+                self.record_profiles();
+            }
+        }
+
+        self.save_leaderboards();
+    }
+
+    /// Registra na chave do torneio o resultado da partida encerrada
+    ///
+    /// Uma partida sem vencedor não é registrada e volta a ser a próxima da chave.
+    fn record_tournament_match(&mut self) {
+        let game_state = &self.game_state;
+        if let (Some(tournament), Some(winner)) = (&mut self.tournament, game_state.winner) {
+            tournament.record_result(
+                winner,
+                game_state.stats.points_left,
+                game_state.stats.points_right,
+            );
+        }
+    }
+
+    /// Soma a partida encerrada às estatísticas dos perfis escolhidos e
+    /// atualiza o rating dos dois lados
+    ///
+    /// Só valem as partidas de dois lados (o modo de 4 jogadores não usa
+    /// perfis); contra a CPU, só o perfil do jogador 1, que joga contra o
+    /// rating fixo da dificuldade. Contra um convidado o rating não muda.
+    fn record_profiles(&mut self) {
+        let game_state = &self.game_state;
+        if game_state.four_player.is_some() {
+            return;
+        }
+
+        // Perfis dos dois lados (vazio = convidado ou CPU)
+        let mut names = self.settings.profiles.clone();
+        if game_state.cpu.is_some() || names[1] == names[0] {
+            names[1].clear();
+        }
+
+        let ratings = [
+            self.profiles.find(&names[0]).map(|profile| profile.rating),
+            match &game_state.cpu {
+                Some(cpu) => Some(cpu.difficulty.rating()),
+                None => self.profiles.find(&names[1]).map(|profile| profile.rating),
+            },
+        ];
+        // Resultado de cada lado (1 = vitória, 0.5 = empate, 0 = derrota)
+        let scores = match game_state.winner {
+            Some(Side::Left) => [1.0, 0.0],
+            Some(Side::Right) => [0.0, 1.0],
+            None => [0.5, 0.5],
+        };
+
+        let mut recorded = false;
+        for (player, side) in [(0, Side::Left), (1, Side::Right)] {
+            let Some(profile) = self.profiles.find_mut(&names[player]) else {
+                continue;
+            };
+            profile.record(&game_state.stats, side, game_state.winner);
+            if let Some(opponent) = ratings[1 - player] {
+                let rating = rating::updated(
+                    profile.rating,
+                    opponent,
+                    scores[player],
+                    profile.rated_matches,
+                );
+                profile.set_rating(rating);
+            }
+            recorded = true;
+        }
+
+        if recorded {
+            self.save_profiles();
+        }
+    }
+
+    /// Salva os perfis no disco (erros são apenas informados no terminal)
+    fn save_profiles(&self) {
+        if let Some(path) = &self.profiles_path {
+            if let Err(e) = self.profiles.save(path) {
+                eprintln!("Não foi possível salvar os perfis: {}", e);
+            }
+        }
+    }
+
+    /// Salva os recordes no disco (erros são apenas informados no terminal)
+    fn save_leaderboards(&self) {
+        if let Some(path) = &self.leaderboards_path {
+            if let Err(e) = self.leaderboards.save(path) {
+                eprintln!("Não foi possível salvar os recordes: {}", e);
+            }
+        }
+    }
+
+    /// Indica se o menu está recebendo a entrada do jogador
+    pub fn menu_active(&self) -> bool {
+        self.name_entry.is_none()
+            && matches!(self.game_state.phase, GamePhase::Menu | GamePhase::Paused)
+    }
+
+    /// Trata eventos de teclas pressionadas
+    ///
+    /// # Argumentos
+    ///
+    /// * `keycode` - Código da tecla pressionada
+    pub fn handle_keydown(&mut self, keycode: Keycode) {
+        if let Some(name_entry) = &mut self.name_entry {
+            if let Some(action) = name_entry.handle_keydown(keycode) {
+                self.run_name_entry_action(action);
+            }
+            return;
+        }
+
+        if self.game_state.phase == GamePhase::Editing {
+            let action = self
+                .editor
+                .as_mut()
+                .and_then(|editor| editor.handle_keydown(keycode));
+            if let Some(action) = action {
+                self.run_editor_action(action);
+            }
+            return;
+        }
+
+        if self.menu_active() {
+            let input = match keycode {
+                Keycode::Up | Keycode::W => MenuInput::Up,
+                Keycode::Down | Keycode::S => MenuInput::Down,
+                Keycode::Left | Keycode::A => MenuInput::Left,
+                Keycode::Right | Keycode::D => MenuInput::Right,
+                Keycode::Return | Keycode::KpEnter | Keycode::Space => MenuInput::Select,
+                Keycode::Escape | Keycode::Backspace => MenuInput::Back,
+                _ => return,
+            };
+            self.handle_menu_input(input);
+            return;
+        }
+
+        match keycode {
+            // ESC ou ESPAÇO para pausar; no fim de jogo, voltam ao menu
+            Keycode::Escape | Keycode::Space => match self.game_state.phase {
+                GamePhase::GameOver => self.back_to_menu(),
+                _ => self.pause(),
+            },
+
+            // Tecla de saque de cada jogador
+            _ => {
+                if let Some(side) = serve_side(self.settings.controls, keycode) {
+                    self.game_state.request_serve(side);
+                }
+            }
+        }
+    }
+
+    /// Retorna a chave do catálogo com o nome da tecla de saque de um jogador
+    ///
+    /// # Argumentos
+    ///
+    /// * `side` - Lado do jogador
+    pub fn serve_key_label(&self, side: Side) -> &'static str {
+        match (self.settings.controls, side) {
+            (ControlScheme::Keyboard, Side::Left) => "key.d",
+            (ControlScheme::Mouse, Side::Left) => "key.click",
+            (ControlScheme::Keyboard | ControlScheme::Mouse, Side::Right) => "key.left",
+            (ControlScheme::Swapped, Side::Left) => "key.right",
+            (ControlScheme::Swapped, Side::Right) => "key.a",
+        }
+    }
+
+    /// Trata botões pressionados em um controle (gamepad)
+    ///
+    /// # Argumentos
+    ///
+    /// * `button` - Botão pressionado
+    pub fn handle_controller_button(&mut self, button: Button) {
+        // O nome é digitado no teclado; o controle só cancela
+        if self.name_entry.is_some() {
+            if matches!(button, Button::B | Button::Back) {
+                self.run_name_entry_action(NameEntryAction::Cancel);
+            }
+            return;
+        }
+
+        // O editor é usado com mouse e teclado; o controle só sai dele
+        if self.game_state.phase == GamePhase::Editing {
+            if matches!(button, Button::B | Button::Back) {
+                self.run_editor_action(EditorAction::Exit);
+            }
+            return;
+        }
+
+        if self.menu_active() {
+            let input = match button {
+                Button::DPadUp => MenuInput::Up,
+                Button::DPadDown => MenuInput::Down,
+                Button::DPadLeft => MenuInput::Left,
+                Button::DPadRight => MenuInput::Right,
+                Button::A | Button::Start => MenuInput::Select,
+                Button::B | Button::Back => MenuInput::Back,
+                _ => return,
+            };
+            self.handle_menu_input(input);
+            return;
+        }
+
+        match button {
+            // A saca pelo jogador da vez quando o saque espera uma tecla
+            Button::A if self.game_state.waiting_for_serve() => {
+                self.game_state.request_serve(self.game_state.server);
+            }
+            Button::Start | Button::Back | Button::A => match self.game_state.phase {
+                GamePhase::GameOver => self.back_to_menu(),
+                _ => self.pause(),
+            },
+            _ => {}
+        }
+    }
+
+    /// Trata o texto digitado (nome de um perfil novo)
+    ///
+    /// # Argumentos
+    ///
+    /// * `text` - Texto recebido do teclado
+    pub fn handle_text_input(&mut self, text: &str) {
+        if let Some(name_entry) = &mut self.name_entry {
+            name_entry.insert(text);
+        }
+    }
+
+    /// Pausa automaticamente quando a janela perde o foco
+    pub fn handle_focus_lost(&mut self) {
+        if matches!(
+            self.game_state.phase,
+            GamePhase::Serving | GamePhase::Playing | GamePhase::Resuming
+        ) {
+            self.pause();
+        }
+    }
+
+    /// Trata o movimento do mouse (destaca o item sob o cursor ou, no editor,
+    /// arrasta o item preso ao cursor)
+    pub fn handle_mouse_motion(&mut self, x: i32, y: i32) {
+        self.mouse_y = y;
+        if self.menu_active() {
+            self.menu.hover(y);
+        } else if let Some(editor) = self.active_editor() {
+            editor.drag(x, y);
+        }
+    }
+
+    /// Trata o clique do mouse (escolhe o item sob o cursor, saca ou, no
+    /// editor, pega ou coloca um item)
+    pub fn handle_mouse_click(&mut self, x: i32, y: i32) {
+        if let Some(editor) = self.active_editor() {
+            editor.press(x, y);
+        } else if self.menu_active() {
+            self.drive_menu(|menu| menu.click(y));
+        } else if self.settings.controls == ControlScheme::Mouse {
+            self.game_state.request_serve(Side::Left);
+        }
+    }
+
+    /// Trata o botão esquerdo do mouse solto (larga o item arrastado no editor)
+    pub fn handle_mouse_release(&mut self) {
+        if let Some(editor) = self.active_editor() {
+            editor.release();
+        }
+    }
+
+    /// Trata o clique com o botão direito (apaga o item sob o cursor no editor)
+    pub fn handle_right_click(&mut self, x: i32, y: i32) {
+        if let Some(editor) = self.active_editor() {
+            editor.remove(x, y);
+        }
+    }
+
+    /// Retorna o editor quando ele está na tela (e não testando a arena)
+    fn active_editor(&mut self) -> Option<&mut Editor> {
+        if self.game_state.phase == GamePhase::Editing {
+            self.editor.as_mut()
+        } else {
+            None
+        }
+    }
+
+    /// Envia uma entrada de navegação ao menu e executa o comando resultante
+    fn handle_menu_input(&mut self, input: MenuInput) {
+        self.drive_menu(|menu| menu.handle_input(input));
+    }
+
+    /// Executa uma ação no menu e salva as configurações ao sair da tela de configurações
+    fn drive_menu<F>(&mut self, action: F)
+    where
+        F: FnOnce(&mut Menu) -> Option<MenuCommand>,
+    {
+        let was_in_settings = self.menu.current().is_settings();
+
+        if let Some(command) = action(&mut self.menu) {
+            self.run_menu_command(command);
+        }
+
+        let left_settings = !self.menu_active() || !self.menu.current().is_settings();
+        if was_in_settings && left_settings {
+            self.save_settings();
+        }
+    }
+
+    /// Executa um comando pedido pelo menu
+    fn run_menu_command(&mut self, command: MenuCommand) {
+        self.save_status = None;
+        match command {
+            MenuCommand::StartMatch(difficulty) => {
+                self.game_state.start_match(difficulty.map(CpuPlayer::new));
+                self.menu.players = self.game_state.players();
+            }
+            MenuCommand::StartFourPlayer => {
+                self.game_state.start_four_player();
+                self.menu.players = self.game_state.players();
+            }
+            MenuCommand::StartDoubles => {
+                self.game_state.start_doubles();
+                self.menu.players = self.game_state.players();
+            }
+            MenuCommand::StartBreakout => {
+                self.game_state.start_breakout();
+                self.menu.players = self.game_state.players();
+            }
+            MenuCommand::StartPractice => {
+                self.game_state
+                    .start_practice(self.settings.practice_target);
+                self.menu.players = self.game_state.players();
+            }
+            MenuCommand::StartArcade(mode) => {
+                self.game_state.start_arcade(mode);
+                self.menu.players = self.game_state.players();
+            }
+            MenuCommand::OpenEditor => {
+                // O editor começa com uma cópia da arena escolhida
+                let paddle = &self.game_state.paddle_left;
+                self.editor = Some(Editor::new(
+                    self.game_state.arena.clone(),
+                    paddle.width,
+                    paddle.height,
+                ));
+                self.game_state.start_editing();
+            }
+            MenuCommand::NewProfile => {
+                self.name_entry = Some(NameEntry::new(NamePurpose::Profile));
+            }
+            MenuCommand::AddTournamentPlayer => {
+                self.tournament_error = None;
+                if self.tournament_players.len() < tournament::TOURNAMENT_MAX_PLAYERS {
+                    self.name_entry = Some(NameEntry::new(NamePurpose::TournamentPlayer));
+                } else {
+                    self.tournament_error = Some("tournament.full");
+                }
+            }
+            MenuCommand::RemoveTournamentPlayer => {
+                self.tournament_players.pop();
+                self.tournament_error = None;
+            }
+            MenuCommand::StartTournament => {
+                if self.tournament_players.len() < tournament::TOURNAMENT_MIN_PLAYERS {
+                    self.tournament_error = Some("tournament.too_few");
+                } else {
+                    self.tournament = Some(Tournament::new(
+                        self.tournament_format,
+                        &self.tournament_players,
+                        self.game_state.rng(),
+                    ));
+                    self.tournament_error = None;
+                    self.menu.open(MenuId::Bracket);
+                }
+            }
+            MenuCommand::PlayTournamentMatch => {
+                let next = self.tournament.as_mut().and_then(Tournament::start_next);
+                if let Some(next) = next {
+                    self.game_state.start_match(next.cpu.map(CpuPlayer::new));
+                    self.menu.players = self.game_state.players();
+                    self.tournament_match = Some(next);
+                }
+            }
+            MenuCommand::AbandonTournament => {
+                self.tournament = None;
+                self.menu.back();
+            }
+            MenuCommand::ChangeSetting(setting, direction) => {
+                self.change_setting(setting, direction);
+            }
+            MenuCommand::Resume => self.game_state.resume(),
+            MenuCommand::Restart => self.game_state.restart_match(),
+            MenuCommand::SaveGame => self.save_game(),
+            MenuCommand::LoadGame => self.load_game(),
+            MenuCommand::Forfeit(player) => self.game_state.forfeit(player),
+            MenuCommand::QuitToMenu => self.back_to_menu(),
+            MenuCommand::Close => {
+                // No menu de pausa, voltar continua a partida; no menu principal, sai do jogo
+                if self.game_state.phase == GamePhase::Paused {
+                    self.game_state.resume();
+                } else {
+                    self.request_quit();
+                }
+            }
+            MenuCommand::Quit => self.request_quit(),
+        }
+    }
+
+    /// Altera uma configuração na direção indicada e aplica o novo valor imediatamente
+    fn change_setting(&mut self, setting: SettingId, direction: i32) {
+        let settings = &mut self.settings;
+
+        match setting {
+            SettingId::WinRule => {
+                settings.win_rule = cycle(&WinRule::ALL, settings.win_rule, direction);
+            }
+            SettingId::MatchTime => {
+                settings.match_minutes = settings::step_value(
+                    settings.match_minutes,
+                    Settings::MATCH_MINUTES_RANGE,
+                    direction,
+                );
+            }
+            SettingId::BestOfSets => {
+                settings.best_of_sets = settings::step_value(
+                    settings.best_of_sets,
+                    Settings::BEST_OF_SETS_RANGE,
+                    direction,
+                );
+            }
+            SettingId::GamesPerSet => {
+                settings.games_per_set = settings::step_value(
+                    settings.games_per_set,
+                    Settings::GAMES_PER_SET_RANGE,
+                    direction,
+                );
+            }
+            SettingId::Lives => {
+                settings.lives =
+                    settings::step_value(settings.lives, Settings::LIVES_RANGE, direction);
+            }
+            SettingId::Balls => {
+                settings.balls =
+                    settings::step_value(settings.balls, Settings::BALLS_RANGE, direction);
+            }
+            SettingId::WinScore => {
+                settings.win_score =
+                    settings::step_value(settings.win_score, Settings::WIN_SCORE_RANGE, direction);
+            }
+            SettingId::BallSpeed => {
+                settings.ball_speed = settings::step_value(
+                    settings.ball_speed,
+                    Settings::BALL_SPEED_RANGE,
+                    direction,
+                );
+            }
+            SettingId::PaddleSize => {
+                settings.paddle_height = settings::step_value(
+                    settings.paddle_height,
+                    Settings::PADDLE_HEIGHT_RANGE,
+                    direction,
+                );
+            }
+            SettingId::ServeRule => {
+                settings.serve_rule = cycle(&ServeRule::ALL, settings.serve_rule, direction);
+            }
+            SettingId::PressToServe => {
+                settings.press_to_serve = !settings.press_to_serve;
+            }
+            SettingId::PowerUps => {
+                settings.power_ups = !settings.power_ups;
+            }
+            SettingId::PowerUp(kind) => {
+                // Liga ou desliga o power-up mantendo a ordem do menu
+                let enabled = !settings.power_up_kinds.contains(&kind);
+                settings.power_up_kinds = PowerUpKind::ALL
+                    .into_iter()
+                    .filter(|other| {
+                        if *other == kind {
+                            enabled
+                        } else {
+                            settings.power_up_kinds.contains(other)
+                        }
+                    })
+                    .collect();
+            }
+            SettingId::PracticeTarget => {
+                settings.practice_target =
+                    cycle(&PracticeTarget::ALL, settings.practice_target, direction);
+            }
+            SettingId::Handicap(player, option) => {
+                let handicap = &mut settings.handicaps[player];
+                match option {
+                    HandicapOption::PaddleSize => {
+                        handicap.paddle_size = settings::step_value(
+                            handicap.paddle_size,
+                            Settings::HANDICAP_SIZE_RANGE,
+                            direction,
+                        );
+                    }
+                    HandicapOption::PaddleSpeed => {
+                        handicap.paddle_speed = settings::step_value(
+                            handicap.paddle_speed,
+                            Settings::HANDICAP_SPEED_RANGE,
+                            direction,
+                        );
+                    }
+                    HandicapOption::StartPoints => {
+                        handicap.start_points = settings::step_value(
+                            handicap.start_points,
+                            Settings::START_POINTS_RANGE,
+                            direction,
+                        );
+                    }
+                    HandicapOption::AiAssist => handicap.ai_assist = !handicap.ai_assist,
+                }
+            }
+            SettingId::Arena => self.cycle_arena(direction),
+            SettingId::Volume => {
+                settings.volume =
+                    settings::step_value(settings.volume, Settings::VOLUME_RANGE, direction);
+            }
+            SettingId::Theme => {
+                self.cycle_theme(direction);
+                self.settings.theme = self.game_state.theme.id.clone();
+            }
+            SettingId::Language => {
+                self.locale.cycle_language(direction);
+                self.settings.language = self.locale.language().code.clone();
+            }
+            SettingId::Controls => {
+                settings.controls = cycle(&ControlScheme::ALL, settings.controls, direction);
+            }
+            SettingId::DisplayMode => {
+                settings.display_mode = cycle(&DisplayMode::ALL, settings.display_mode, direction);
+            }
+            SettingId::LeaderboardBoard => {
+                self.leaderboard_board = cycle(&Board::ALL, self.leaderboard_board, direction);
+            }
+            SettingId::Profile(player) => self.cycle_profile(player, direction),
+            SettingId::TournamentFormat => {
+                self.tournament_format =
+                    cycle(&TournamentFormat::ALL, self.tournament_format, direction);
+            }
+            SettingId::StatsProfile => {
+                let count = self.profiles.list().len().max(1) as i32;
+                self.stats_profile =
+                    (self.stats_profile as i32 + direction).rem_euclid(count) as usize;
+            }
+        }
+
+        // Só as configurações da partida mexem no estado do jogo (uma partida
+        // recuperada de um jogo salvo continua com as suas regras)
+        if setting.affects_match() && !self.restored_match {
+            self.apply_game_settings();
+        }
+    }
+
+    /// Aplica ao estado do jogo as configurações que afetam a partida
+    fn apply_game_settings(&mut self) {
+        self.game_state.set_max_score(self.settings.win_score);
+        self.game_state
+            .set_match_format(self.settings.best_of_sets, self.settings.games_per_set);
+        self.game_state.set_lives(self.settings.lives);
+        self.game_state.set_ball_count(self.settings.balls);
+        self.game_state.set_power_ups(if self.settings.power_ups {
+            self.settings.power_up_kinds.clone()
+        } else {
+            Vec::new()
+        });
+        self.game_state.set_win_rule(
+            self.settings.win_rule,
+            self.settings.match_minutes as f32 * 60.0,
+        );
+        self.game_state
+            .set_ball_speed(self.settings.ball_speed as f32);
+        self.game_state.set_handicaps(self.settings.handicaps);
+        self.game_state
+            .set_paddle_height(self.settings.paddle_height as f32);
+        self.game_state.serve_rule = self.settings.serve_rule;
+        self.game_state.press_to_serve = self.settings.press_to_serve;
+    }
+
+    /// Salva as configurações no disco
+    fn save_settings(&self) {
+        if let Some(path) = &self.settings_path {
+            if let Err(e) = self.settings.save(path) {
+                eprintln!("Não foi possível salvar as configurações: {}", e);
+            }
+        }
+    }
+
+    /// Troca para o tema seguinte (ou anterior) da lista
+    fn cycle_theme(&mut self, direction: i32) {
+        if self.themes.is_empty() {
+            return;
+        }
+
+        let current = self
+            .themes
+            .iter()
+            .position(|theme| theme.id == self.game_state.theme.id)
+            .unwrap_or(0) as i32;
+        let next = (current + direction).rem_euclid(self.themes.len() as i32) as usize;
+        self.game_state.theme = self.themes[next].clone();
+    }
+
+    /// Troca para a arena seguinte (ou anterior) da lista
+    fn cycle_arena(&mut self, direction: i32) {
+        if self.arenas.is_empty() {
+            return;
+        }
+
+        let current = self
+            .arenas
+            .iter()
+            .position(|arena| arena.id == self.settings.arena)
+            .unwrap_or(0) as i32;
+        let next = (current + direction).rem_euclid(self.arenas.len() as i32) as usize;
+        self.settings.arena = self.arenas[next].id.clone();
+        if !self.restored_match {
+            self.select_arena();
+        }
+    }
+
+    /// Troca o perfil de um jogador pelo seguinte (ou anterior) da lista
+    ///
+    /// A lista começa com o convidado (sem perfil) e pula o perfil escolhido
+    /// pelo outro jogador.
+    ///
+    /// # Argumentos
+    ///
+    /// * `player` - Índice do jogador (0 ou 1)
+    /// * `direction` - 1 para o próximo perfil, -1 para o anterior
+    fn cycle_profile(&mut self, player: usize, direction: i32) {
+        let other = &self.settings.profiles[1 - player];
+        let options: Vec<&str> = std::iter::once("")
+            .chain(
+                self.profiles
+                    .list()
+                    .iter()
+                    .map(|profile| profile.name.as_str()),
+            )
+            .filter(|name| name.is_empty() || name != other)
+            .collect();
+
+        let current = options
+            .iter()
+            .position(|name| *name == self.settings.profiles[player])
+            .unwrap_or(0) as i32;
+        let next = (current + direction).rem_euclid(options.len() as i32) as usize;
+        self.settings.profiles[player] = options[next].to_string();
+    }
+
+    /// Executa um pedido da tela de nome (perfil novo ou jogador do torneio)
+    fn run_name_entry_action(&mut self, action: NameEntryAction) {
+        let Some(name_entry) = &mut self.name_entry else {
+            return;
+        };
+
+        match (action, name_entry.purpose) {
+            (NameEntryAction::Confirm, NamePurpose::TournamentPlayer) => {
+                if let Some(error) =
+                    tournament::player_name_error(&self.tournament_players, &name_entry.text)
+                {
+                    name_entry.error = Some(error);
+                    return;
+                }
+
+                self.tournament_players
+                    .push(name_entry.text.trim().to_string());
+                self.name_entry = None;
+            }
+            (NameEntryAction::Confirm, NamePurpose::Profile) => {
+                if let Some(error) = self.profiles.name_error(&name_entry.text) {
+                    name_entry.error = Some(error);
+                    return;
+                }
+
+                self.profiles.add(&name_entry.text);
+                self.save_profiles();
+
+                // O perfil novo já fica escolhido para o primeiro jogador sem perfil
+                let index = self.profiles.list().len() - 1;
+                let name = self.profiles.list()[index].name.clone();
+                if let Some(slot) = self
+                    .settings
+                    .profiles
+                    .iter_mut()
+                    .find(|slot| slot.is_empty())
+                {
+                    *slot = name;
+                    self.save_settings();
+                }
+                self.stats_profile = index;
+                self.name_entry = None;
+            }
+            (NameEntryAction::Cancel, _) => self.name_entry = None,
+        }
+    }
+
+    /// Executa um pedido do editor de arenas
+    fn run_editor_action(&mut self, action: EditorAction) {
+        let Some(editor) = &mut self.editor else {
+            return;
+        };
+
+        match action {
+            EditorAction::TestPlay => {
+                editor.testing = true;
+                self.game_state.set_arena(editor.arena.clone());
+                self.game_state.start_match(None);
+                self.menu.players = self.game_state.players();
+            }
+            EditorAction::Save => self.save_arena(),
+            EditorAction::Exit => {
+                self.editor = None;
+                self.select_arena();
+                self.back_to_menu();
+            }
+        }
+    }
+
+    /// Salva a arena do editor em `assets/arenas` e a escolhe para as partidas
+    ///
+    /// Arenas embutidas não são sobrescritas: a cópia editada ganha um novo
+    /// identificador.
+    fn save_arena(&mut self) {
+        let Some(editor) = &mut self.editor else {
+            return;
+        };
+
+        let arena = &mut editor.arena;
+        if Arena::builtin()
+            .iter()
+            .any(|builtin| builtin.id == arena.id)
+        {
+            let number = (1..)
+                .find(|n| {
+                    let id = format!("arena{}", n);
+                    !self.arenas.iter().any(|other| other.id == id)
+                })
+                .unwrap_or(1);
+            arena.id = format!("arena{}", number);
+            arena.name = self
+                .locale
+                .format("editor.new_name", &[("number", &number.to_string())]);
+        }
+
+        match arena.save(&self.arenas_dir) {
+            Ok(path) => {
+                let path = path.display().to_string();
+                editor.status = Some(self.locale.format("editor.saved", &[("path", &path)]));
+                match self.arenas.iter_mut().find(|other| other.id == arena.id) {
+                    Some(other) => *other = arena.clone(),
+                    None => self.arenas.push(arena.clone()),
+                }
+                self.settings.arena = arena.id.clone();
+                self.save_settings();
+            }
+            Err(e) => {
+                editor.status = Some(self.locale.format("editor.save_failed", &[("error", &e)]));
+            }
+        }
+    }
+
+    /// Coloca na quadra a arena escolhida nas configurações
+    fn select_arena(&mut self) {
+        if let Some(arena) = self
+            .arenas
+            .iter()
+            .find(|arena| arena.id == self.settings.arena)
+        {
+            self.game_state.set_arena(arena.clone());
+        }
+    }
+
+    /// Salva a partida pausada no arquivo do jogo salvo
+    ///
+    /// O teste de uma arena e as partidas de torneio não podem ser salvos,
+    /// porque dependem do editor ou da chave, que não vão para o arquivo.
+    fn save_game(&mut self) {
+        let testing = self.editor.as_ref().is_some_and(|editor| editor.testing);
+        let status = match &self.savegame_path {
+            Some(_) if testing || self.tournament_match.is_some() => {
+                self.locale.get("savegame.unavailable").to_string()
+            }
+            Some(path) => match savegame::save(&self.game_state, path) {
+                Ok(()) => self.locale.get("savegame.saved").to_string(),
+                Err(e) => self.locale.format("savegame.save_failed", &[("error", &e)]),
+            },
+            None => self.locale.get("savegame.unavailable").to_string(),
+        };
+        self.save_status = Some(status);
+    }
+
+    /// Carrega o jogo salvo e abre o menu de pausa da partida recuperada
+    fn load_game(&mut self) {
+        let path = match &self.savegame_path {
+            Some(path) if path.exists() => path,
+            _ => {
+                self.save_status = Some(self.locale.get("savegame.none").to_string());
+                return;
+            }
+        };
+
+        let (width, height) = (self.game_state.screen_width, self.game_state.screen_height);
+        match savegame::load(path, width, height) {
+            Ok(mut game_state) => {
+                // A partida continua com o tema escolhido agora
+                game_state.theme = self.game_state.theme.clone();
+                self.game_state = game_state;
+                self.restored_match = true;
+                self.menu.players = self.game_state.players();
+                self.menu.open_root(MenuId::Pause);
+                self.save_status = Some(self.locale.get("savegame.loaded").to_string());
+            }
+            Err(e) => {
+                self.save_status =
+                    Some(self.locale.format("savegame.load_failed", &[("error", &e)]));
+            }
+        }
+    }
+
+    /// Pausa a partida e abre o menu de pausa
+    fn pause(&mut self) {
+        self.save_status = None;
+        self.game_state.pause();
+        if self.game_state.phase == GamePhase::Paused {
+            self.menu.open_root(MenuId::Pause);
+        }
+    }
+
+    /// Encerra a partida e volta ao menu principal (ao editor, quando a
+    /// partida era o teste de uma arena, ou à chave, quando era de um torneio)
+    fn back_to_menu(&mut self) {
+        self.save_status = None;
+        if let Some(editor) = &mut self.editor {
+            if editor.testing {
+                editor.testing = false;
+                self.game_state.start_editing();
+                return;
+            }
+        }
+        self.game_state.reset();
+        self.menu.reset();
+
+        // Depois de uma partida recuperada, voltam a valer as configurações
+        if self.restored_match {
+            self.restored_match = false;
+            self.apply_game_settings();
+            self.select_arena();
+        }
+
+        if self.tournament_match.take().is_some() {
+            for id in [MenuId::Modes, MenuId::Tournament, MenuId::Bracket] {
+                self.menu.open(id);
+            }
+        }
+    }
+}
+
+/// Retorna o lado do jogador cuja tecla de saque foi pressionada
+///
+/// Cada jogador saca com a tecla que aponta para o adversário.
+fn serve_side(controls: ControlScheme, keycode: Keycode) -> Option<Side> {
+    match (controls, keycode) {
+        (ControlScheme::Keyboard, Keycode::D) => Some(Side::Left),
+        (ControlScheme::Keyboard | ControlScheme::Mouse, Keycode::Left) => Some(Side::Right),
+        (ControlScheme::Swapped, Keycode::Right) => Some(Side::Left),
+        (ControlScheme::Swapped, Keycode::A) => Some(Side::Right),
+        _ => None,
+    }
+}
+
+/// Retorna o valor seguinte (ou anterior) de uma lista de opções
+fn cycle<T: PartialEq + Copy>(options: &[T], current: T, direction: i32) -> T {
+    let index = options
+        .iter()
+        .position(|option| *option == current)
+        .unwrap_or(0) as i32;
+    options[(index + direction).rem_euclid(options.len() as i32) as usize]
+}
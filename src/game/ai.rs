@@ -0,0 +1,186 @@
+// Jogador controlado pelo computador (CPU)
+
+use super::ball::Ball;
+use super::paddle::Paddle;
+use super::random::GameRng;
+use super::snapshot::{Fields, Snapshot};
+use rand::Rng;
+
+/// Níveis de dificuldade da CPU
+#[derive(PartialEq, Clone, Copy)]
+pub enum Difficulty {
+    /// CPU lenta e que só reage quando a bola está perto
+    Easy,
+    /// CPU equilibrada
+    Normal,
+    /// CPU rápida e precisa
+    Hard,
+}
+
+impl Difficulty {
+    /// Todas as dificuldades, da mais fácil para a mais difícil
+    pub const ALL: [Difficulty; 3] = [Difficulty::Easy, Difficulty::Normal, Difficulty::Hard];
+
+    /// Identificador usado nos arquivos do jogo
+    pub fn id(self) -> &'static str {
+        match self {
+            Difficulty::Easy => "easy",
+            Difficulty::Normal => "normal",
+            Difficulty::Hard => "hard",
+        }
+    }
+
+    /// Chave do catálogo de mensagens com o nome da dificuldade
+    pub fn label_key(self) -> &'static str {
+        match self {
+            Difficulty::Easy => "difficulty.easy",
+            Difficulty::Normal => "difficulty.normal",
+            Difficulty::Hard => "difficulty.hard",
+        }
+    }
+
+    /// Rating fixo da CPU, usado como adversário no rating dos perfis
+    pub fn rating(self) -> f32 {
+        match self {
+            Difficulty::Easy => 1200.0,
+            Difficulty::Normal => 1500.0,
+            Difficulty::Hard => 1800.0,
+        }
+    }
+
+    /// Fração da velocidade da raquete usada pela CPU
+    fn speed_factor(self) -> f32 {
+        match self {
+            Difficulty::Easy => 0.55,
+            Difficulty::Normal => 0.8,
+            Difficulty::Hard => 1.0,
+        }
+    }
+
+    /// Distância até a bola (em fração da largura da tela) a partir da qual a
+    /// CPU começa a segui-la (1.0 = segue sempre)
+    fn reaction_distance(self) -> f32 {
+        match self {
+            Difficulty::Easy => 0.45,
+            Difficulty::Normal => 0.7,
+            Difficulty::Hard => 1.0,
+        }
+    }
+
+    /// Erro máximo de mira (em pixels) ao prever onde a bola vai chegar
+    fn max_aim_error(self) -> f32 {
+        match self {
+            Difficulty::Easy => 45.0,
+            Difficulty::Normal => 25.0,
+            Difficulty::Hard => 8.0,
+        }
+    }
+}
+
+/// Controla uma raquete automaticamente seguindo a bola
+pub struct CpuPlayer {
+    /// Dificuldade da CPU
+    pub difficulty: Difficulty,
+    /// Erro de mira atual (sorteado a cada vez que a bola muda de direção)
+    aim_error: f32,
+    /// Direção horizontal da bola no último quadro (para detectar rebatidas)
+    last_direction: f32,
+}
+
+impl CpuPlayer {
+    /// Cria um jogador CPU
+    ///
+    /// # Argumentos
+    ///
+    /// * `difficulty` - Dificuldade da CPU
+    pub fn new(difficulty: Difficulty) -> CpuPlayer {
+        CpuPlayer {
+            difficulty,
+            aim_error: 0.0,
+            last_direction: 0.0,
+        }
+    }
+
+    /// Move a raquete em direção à bola
+    ///
+    /// Com várias bolas em jogo, a CPU segue a mais próxima entre as que vêm
+    /// na sua direção.
+    ///
+    /// # Argumentos
+    ///
+    /// * `paddle` - Raquete controlada pela CPU
+    /// * `balls` - Bolas em jogo
+    /// * `screen_width` - Largura da tela
+    /// * `screen_height` - Altura da tela
+    /// * `delta_time` - Tempo decorrido desde o último frame (em segundos)
+    /// * `rng` - Gerador de números aleatórios da partida
+    pub fn update(
+        &mut self,
+        paddle: &mut Paddle,
+        balls: &[Ball],
+        screen_width: f32,
+        screen_height: f32,
+        delta_time: f32,
+        rng: &mut GameRng,
+    ) {
+        // Descobre de que lado a raquete está e qual bola vem na sua direção
+        let on_right = paddle.x > screen_width / 2.0;
+        let paddle_x = paddle.x + paddle.width / 2.0;
+        let distance = |ball: &Ball| (ball.x - paddle_x).abs() / screen_width;
+        let target = balls
+            .iter()
+            .filter(|ball| {
+                if on_right {
+                    ball.vel_x > 0.0
+                } else {
+                    ball.vel_x < 0.0
+                }
+            })
+            .min_by(|a, b| distance(a).total_cmp(&distance(b)));
+
+        // Sorteia um novo erro de mira sempre que uma bola passa a vir na sua direção
+        let direction = if target.is_some() { 1.0 } else { -1.0 };
+        if direction != self.last_direction {
+            let max_error = self.difficulty.max_aim_error();
+            self.aim_error = rng.random_range(-max_error..=max_error);
+            self.last_direction = direction;
+        }
+
+        // Segue a bola quando ela se aproxima; caso contrário, volta ao centro
+        let target_y = match target {
+            Some(ball) if distance(ball) <= self.difficulty.reaction_distance() => {
+                ball.y + self.aim_error
+            }
+            _ => screen_height / 2.0,
+        };
+
+        let paddle_center = paddle.y + paddle.height / 2.0;
+        let dead_zone = paddle.height / 8.0;
+        let scaled_time = delta_time * self.difficulty.speed_factor();
+
+        if target_y < paddle_center - dead_zone {
+            paddle.move_up(scaled_time);
+        } else if target_y > paddle_center + dead_zone {
+            paddle.move_down(scaled_time, screen_height);
+        }
+    }
+}
+
+impl Snapshot for CpuPlayer {
+    fn snapshot(&self) -> String {
+        format!(
+            "{} {} {}",
+            self.difficulty.id(),
+            self.aim_error,
+            self.last_direction
+        )
+    }
+
+    fn restore(fields: &mut Fields) -> Result<CpuPlayer, String> {
+        Ok(CpuPlayer {
+            difficulty: fields.next_id(&Difficulty::ALL, Difficulty::id)?,
+            aim_error: fields.next()?,
+            last_direction: fields.next()?,
+        })
+    }
+}
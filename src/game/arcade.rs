@@ -0,0 +1,264 @@
+// Modos arcade para um jogador: sobrevivência e contra o relógio
+
+use super::ball::Ball;
+use super::four_player::Wall;
+use super::game_state::Side;
+use super::snapshot::{Fields, Snapshot};
+use super::theme::Theme;
+use crate::storage::leaderboards::Record;
+use sdl2::render::Canvas;
+use sdl2::video::Window;
+
+/// Duração do modo contra o relógio (em segundos de bola em jogo)
+pub const TIME_ATTACK_DURATION: f32 = 90.0;
+
+/// Aumento da velocidade das bolas a cada segundo na sobrevivência (fração da velocidade)
+const SURVIVAL_ACCELERATION: f32 = 0.02;
+
+/// Intervalo entre as bolas extras da sobrevivência (em segundos)
+const SURVIVAL_BALL_INTERVAL: f32 = 20.0;
+
+/// Pontos por segundo sobrevivido
+const SURVIVAL_SECOND_SCORE: u32 = 10;
+
+/// Pontos por rebatida na sobrevivência
+const SURVIVAL_RETURN_SCORE: u32 = 5;
+
+/// Pontos por ponto marcado contra a CPU
+const TIME_ATTACK_POINT_SCORE: u32 = 100;
+
+/// Bônus por rebatida da troca de bola que terminou em ponto
+const TIME_ATTACK_RALLY_BONUS: u32 = 10;
+
+/// Pontos perdidos a cada ponto da CPU
+const TIME_ATTACK_CONCEDED_PENALTY: u32 = 50;
+
+/// Modos arcade
+#[derive(PartialEq, Clone, Copy)]
+pub enum ArcadeMode {
+    /// Manter a bola em jogo contra uma parede pelo maior tempo possível
+    /// (as bolas aceleram e bolas extras entram em jogo)
+    Survival,
+    /// Marcar o máximo de pontos contra a CPU em 90 segundos
+    TimeAttack,
+}
+
+impl ArcadeMode {
+    /// Todos os modos, na ordem em que aparecem no menu
+    pub const ALL: [ArcadeMode; 2] = [ArcadeMode::Survival, ArcadeMode::TimeAttack];
+
+    /// Identificador usado nos arquivos do jogo
+    pub fn id(self) -> &'static str {
+        match self {
+            ArcadeMode::Survival => "survival",
+            ArcadeMode::TimeAttack => "time_attack",
+        }
+    }
+
+    /// Chave do catálogo de mensagens com o nome do modo
+    pub fn label_key(self) -> &'static str {
+        match self {
+            ArcadeMode::Survival => "arcade.survival",
+            ArcadeMode::TimeAttack => "arcade.time_attack",
+        }
+    }
+
+    /// Chave do catálogo de mensagens com a regra de pontuação do modo
+    pub fn rule_key(self) -> &'static str {
+        match self {
+            ArcadeMode::Survival => "arcade.survival_rule",
+            ArcadeMode::TimeAttack => "arcade.time_attack_rule",
+        }
+    }
+}
+
+/// Estado de uma partida de arcade
+pub struct Arcade {
+    /// Modo em jogo
+    pub mode: ArcadeMode,
+    /// Tempo de bola em jogo (em segundos)
+    pub elapsed: f32,
+    /// Rebatidas do jogador
+    pub returns: u32,
+    /// Pontos marcados contra a CPU
+    pub points_won: u32,
+    /// Pontos da CPU
+    pub points_lost: u32,
+    /// Pontos ganhos com as trocas de bola (contra o relógio)
+    rally_score: u32,
+    /// Tempo até a próxima bola extra da sobrevivência (em segundos)
+    next_ball: f32,
+}
+
+impl Arcade {
+    /// Começa uma partida de arcade
+    ///
+    /// # Argumentos
+    ///
+    /// * `mode` - Modo escolhido
+    pub fn new(mode: ArcadeMode) -> Arcade {
+        Arcade {
+            mode,
+            elapsed: 0.0,
+            returns: 0,
+            points_won: 0,
+            points_lost: 0,
+            rally_score: 0,
+            next_ball: SURVIVAL_BALL_INTERVAL,
+        }
+    }
+
+    /// Indica se o modo é jogado contra a CPU
+    pub fn uses_cpu(&self) -> bool {
+        self.mode == ArcadeMode::TimeAttack
+    }
+
+    /// Conta o tempo e acelera as bolas da sobrevivência
+    ///
+    /// # Argumentos
+    ///
+    /// * `delta_time` - Tempo decorrido desde o último frame (em segundos)
+    /// * `balls` - Bolas em jogo
+    ///
+    /// # Retorna
+    ///
+    /// `true` quando uma bola extra deve entrar em jogo
+    pub fn update(&mut self, delta_time: f32, balls: &mut [Ball]) -> bool {
+        self.elapsed += delta_time;
+        if self.mode != ArcadeMode::Survival {
+            return false;
+        }
+
+        for ball in balls {
+            ball.accelerate(1.0 + SURVIVAL_ACCELERATION * delta_time);
+        }
+
+        self.next_ball -= delta_time;
+        if self.next_ball <= 0.0 {
+            self.next_ball += SURVIVAL_BALL_INTERVAL;
+            return true;
+        }
+        false
+    }
+
+    /// Tempo restante do modo contra o relógio (em segundos)
+    pub fn time_left(&self) -> f32 {
+        (TIME_ATTACK_DURATION - self.elapsed).max(0.0)
+    }
+
+    /// Indica se o tempo do modo contra o relógio acabou
+    pub fn time_up(&self) -> bool {
+        self.mode == ArcadeMode::TimeAttack && self.elapsed >= TIME_ATTACK_DURATION
+    }
+
+    /// Conta uma rebatida do jogador
+    pub fn record_return(&mut self) {
+        self.returns += 1;
+    }
+
+    /// Conta um ponto do modo contra o relógio
+    ///
+    /// # Argumentos
+    ///
+    /// * `scorer` - Lado de quem marcou o ponto
+    /// * `rally` - Rebatidas da troca de bola que terminou
+    pub fn record_point(&mut self, scorer: Side, rally: u32) {
+        match scorer {
+            Side::Left => {
+                self.points_won += 1;
+                self.rally_score += TIME_ATTACK_RALLY_BONUS * rally;
+            }
+            Side::Right => self.points_lost += 1,
+        }
+    }
+
+    /// Pontuação atual, de acordo com as regras do modo
+    ///
+    /// Na sobrevivência, vale cada segundo sobrevivido e cada rebatida; contra
+    /// o relógio, cada ponto marcado (com bônus pelas rebatidas da troca de
+    /// bola) e cada ponto sofrido desconta.
+    pub fn score(&self) -> u32 {
+        match self.mode {
+            ArcadeMode::Survival => {
+                self.elapsed as u32 * SURVIVAL_SECOND_SCORE + self.returns * SURVIVAL_RETURN_SCORE
+            }
+            ArcadeMode::TimeAttack => (self.points_won * TIME_ATTACK_POINT_SCORE
+                + self.rally_score)
+                .saturating_sub(self.points_lost * TIME_ATTACK_CONCEDED_PENALTY),
+        }
+    }
+
+    /// Resultado da partida para o ranking
+    pub fn result(&self) -> Record {
+        Record {
+            value: self.score(),
+            time: self.elapsed,
+            returns: self.returns,
+            points_won: self.points_won,
+            points_lost: self.points_lost,
+        }
+    }
+
+    /// Rebate a bola na parede direita (somente na sobrevivência)
+    ///
+    /// # Argumentos
+    ///
+    /// * `ball` - Bola do jogo
+    /// * `screen_width` - Largura da tela
+    /// * `screen_height` - Altura da tela
+    pub fn bounce_wall(&self, ball: &mut Ball, screen_width: f32, screen_height: f32) {
+        if self.mode == ArcadeMode::Survival {
+            Wall::Right.bounce(ball, screen_width, screen_height);
+        }
+    }
+
+    /// Desenha a parede da sobrevivência
+    ///
+    /// # Argumentos
+    ///
+    /// * `canvas` - Canvas SDL para desenhar
+    /// * `theme` - Tema de cores
+    /// * `screen_width` - Largura da tela
+    /// * `screen_height` - Altura da tela
+    pub fn render(
+        &self,
+        canvas: &mut Canvas<Window>,
+        theme: &Theme,
+        screen_width: f32,
+        screen_height: f32,
+    ) {
+        if self.mode == ArcadeMode::Survival {
+            canvas.set_draw_color(theme.player_right);
+            canvas
+                .fill_rect(Wall::Right.rect(screen_width, screen_height))
+                .unwrap();
+        }
+    }
+}
+
+impl Snapshot for Arcade {
+    fn snapshot(&self) -> String {
+        format!(
+            "{} {} {} {} {} {} {}",
+            self.mode.id(),
+            self.elapsed,
+            self.returns,
+            self.points_won,
+            self.points_lost,
+            self.rally_score,
+            self.next_ball
+        )
+    }
+
+    fn restore(fields: &mut Fields) -> Result<Arcade, String> {
+        Ok(Arcade {
+            mode: fields.next_id(&ArcadeMode::ALL, ArcadeMode::id)?,
+            elapsed: fields.next()?,
+            returns: fields.next()?,
+            points_won: fields.next()?,
+            points_lost: fields.next()?,
+            rally_score: fields.next()?,
+            next_ball: fields.next()?,
+        })
+    }
+}
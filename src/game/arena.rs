@@ -0,0 +1,1023 @@
+// Arenas: obstáculos fixos e móveis dentro da quadra
+
+use super::ball::Ball;
+use super::random::GameRng;
+use super::theme::Theme;
+use crate::storage::kv;
+use rand::seq::IndexedRandom;
+use sdl2::rect::{Point, Rect};
+use sdl2::render::Canvas;
+use sdl2::video::Window;
+use std::f32::consts::PI;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Extensão dos arquivos de arena em `assets/arenas`
+const ARENA_EXTENSION: &str = "arena";
+
+/// Largura da quadra para a qual as arenas embutidas foram desenhadas
+const DEFAULT_WIDTH: f32 = 800.0;
+
+/// Altura da quadra para a qual as arenas embutidas foram desenhadas
+const DEFAULT_HEIGHT: f32 = 600.0;
+
+/// Aumento de velocidade da bola a cada batida em um bumper
+const BUMPER_BOOST: f32 = 1.1;
+
+/// Espessura (em pixels) com que os defletores e paredes são desenhados
+const LINE_THICKNESS: i32 = 4;
+
+/// Segmento de reta entre dois pontos (paredes e lados dos obstáculos)
+#[derive(Clone, Copy)]
+pub struct Segment {
+    /// Posição X do primeiro ponto
+    pub x1: f32,
+    /// Posição Y do primeiro ponto
+    pub y1: f32,
+    /// Posição X do segundo ponto
+    pub x2: f32,
+    /// Posição Y do segundo ponto
+    pub y2: f32,
+}
+
+impl Segment {
+    /// Cria um segmento entre dois pontos
+    pub fn new(x1: f32, y1: f32, x2: f32, y2: f32) -> Segment {
+        Segment { x1, y1, x2, y2 }
+    }
+
+    /// Retorna os quatro lados de um retângulo
+    ///
+    /// # Argumentos
+    ///
+    /// * `x` - Posição X do canto superior esquerdo
+    /// * `y` - Posição Y do canto superior esquerdo
+    /// * `width` - Largura
+    /// * `height` - Altura
+    pub fn rect(x: f32, y: f32, width: f32, height: f32) -> [Segment; 4] {
+        let (right, bottom) = (x + width, y + height);
+        [
+            Segment::new(x, y, right, y),
+            Segment::new(right, y, right, bottom),
+            Segment::new(right, bottom, x, bottom),
+            Segment::new(x, bottom, x, y),
+        ]
+    }
+
+    /// Retorna o segmento deslocado
+    pub fn offset(&self, dx: f32, dy: f32) -> Segment {
+        Segment::new(self.x1 + dx, self.y1 + dy, self.x2 + dx, self.y2 + dy)
+    }
+
+    /// Retorna o segmento em outra escala (quadra de outro tamanho)
+    pub fn scale(&self, sx: f32, sy: f32) -> Segment {
+        Segment::new(self.x1 * sx, self.y1 * sy, self.x2 * sx, self.y2 * sy)
+    }
+
+    /// Desenha o segmento com espessura
+    ///
+    /// # Argumentos
+    ///
+    /// * `canvas` - Canvas SDL para desenhar
+    pub fn render(&self, canvas: &mut Canvas<Window>) {
+        // Linhas paralelas dão espessura ao segmento
+        let vertical = (self.x2 - self.x1).abs() < (self.y2 - self.y1).abs();
+        let half = LINE_THICKNESS / 2;
+        for shift in -half..=half {
+            let (sx, sy) = if vertical { (shift, 0) } else { (0, shift) };
+            canvas
+                .draw_line(
+                    Point::new(self.x1 as i32 + sx, self.y1 as i32 + sy),
+                    Point::new(self.x2 as i32 + sx, self.y2 as i32 + sy),
+                )
+                .unwrap();
+        }
+    }
+}
+
+/// Movimento de vai e vem de um obstáculo
+#[derive(Clone, Copy)]
+pub struct Motion {
+    /// Deslocamento horizontal máximo (em pixels)
+    pub dx: f32,
+    /// Deslocamento vertical máximo (em pixels)
+    pub dy: f32,
+    /// Tempo de uma ida e volta completa (em segundos)
+    pub period: f32,
+}
+
+/// Tipos de obstáculo
+#[derive(Clone, Copy)]
+pub enum ObstacleKind {
+    /// Bloco retangular que rebate a bola
+    Block {
+        /// Posição X do canto superior esquerdo
+        x: f32,
+        /// Posição Y do canto superior esquerdo
+        y: f32,
+        /// Largura
+        width: f32,
+        /// Altura
+        height: f32,
+    },
+    /// Bloco quadrado que rebate a bola e a acelera
+    Bumper {
+        /// Posição X do centro
+        x: f32,
+        /// Posição Y do centro
+        y: f32,
+        /// Tamanho do lado
+        size: f32,
+    },
+    /// Parede fina em qualquer ângulo que desvia a bola
+    Deflector(Segment),
+    /// Par de portais: a bola que entra em um sai pelo outro, na mesma direção
+    Portal {
+        /// Posição X do centro do primeiro portal
+        x: f32,
+        /// Posição Y do centro do primeiro portal
+        y: f32,
+        /// Posição X do centro do segundo portal
+        exit_x: f32,
+        /// Posição Y do centro do segundo portal
+        exit_y: f32,
+        /// Tamanho do lado de cada portal
+        size: f32,
+    },
+}
+
+/// Um obstáculo da arena, parado ou em movimento
+#[derive(Clone, Copy)]
+pub struct Obstacle {
+    /// Tipo e posição inicial do obstáculo
+    pub kind: ObstacleKind,
+    /// Movimento de vai e vem (`None` para obstáculos parados)
+    pub motion: Option<Motion>,
+}
+
+impl Obstacle {
+    /// Cria um obstáculo parado
+    pub fn fixed(kind: ObstacleKind) -> Obstacle {
+        Obstacle { kind, motion: None }
+    }
+
+    /// Cria um obstáculo que vai e volta
+    pub fn moving(kind: ObstacleKind, dx: f32, dy: f32, period: f32) -> Obstacle {
+        Obstacle {
+            kind,
+            motion: Some(Motion { dx, dy, period }),
+        }
+    }
+
+    /// Deslocamento do obstáculo em relação à posição inicial
+    ///
+    /// # Argumentos
+    ///
+    /// * `time` - Tempo de bola em jogo (em segundos)
+    fn offset(&self, time: f32) -> (f32, f32) {
+        match self.motion {
+            Some(motion) if motion.period > 0.0 => {
+                let phase = (2.0 * PI * time / motion.period).sin();
+                (motion.dx * phase, motion.dy * phase)
+            }
+            _ => (0.0, 0.0),
+        }
+    }
+
+    /// Rebate, acelera ou teletransporta a bola que encostou no obstáculo
+    ///
+    /// # Argumentos
+    ///
+    /// * `ball` - Bola do jogo
+    /// * `time` - Tempo de bola em jogo (em segundos)
+    ///
+    /// # Retorna
+    ///
+    /// `true` se a bola encostou no obstáculo
+    pub fn collide(&self, ball: &mut Ball, time: f32) -> bool {
+        let (ox, oy) = self.offset(time);
+
+        match self.kind {
+            ObstacleKind::Block {
+                x,
+                y,
+                width,
+                height,
+            } => collide_segments(ball, &Segment::rect(x + ox, y + oy, width, height)),
+            ObstacleKind::Bumper { x, y, size } => {
+                let half = size / 2.0;
+                let sides = Segment::rect(x + ox - half, y + oy - half, size, size);
+                let hit = collide_segments(ball, &sides);
+                if hit {
+                    ball.vel_x *= BUMPER_BOOST;
+                    ball.vel_y *= BUMPER_BOOST;
+                }
+                hit
+            }
+            ObstacleKind::Deflector(segment) => {
+                ball.check_segment_collision(&segment.offset(ox, oy))
+            }
+            ObstacleKind::Portal {
+                x,
+                y,
+                exit_x,
+                exit_y,
+                size,
+            } => {
+                let half = size / 2.0;
+                let inside =
+                    |px: f32, py: f32| (ball.x - px).abs() < half && (ball.y - py).abs() < half;
+                let (entry, exit) = ((x + ox, y + oy), (exit_x + ox, exit_y + oy));
+                let target = if inside(entry.0, entry.1) {
+                    exit
+                } else if inside(exit.0, exit.1) {
+                    entry
+                } else {
+                    return false;
+                };
+
+                // A bola sai já fora do outro portal, para não voltar pelo mesmo caminho
+                let speed = ball.vel_x.hypot(ball.vel_y).max(f32::EPSILON);
+                let distance = half + ball.size;
+                ball.x = target.0 + ball.vel_x / speed * distance;
+                ball.y = target.1 + ball.vel_y / speed * distance;
+                true
+            }
+        }
+    }
+
+    /// Muda a escala do obstáculo (quadra de outro tamanho)
+    ///
+    /// # Argumentos
+    ///
+    /// * `sx` - Escala horizontal
+    /// * `sy` - Escala vertical
+    fn scale(&mut self, sx: f32, sy: f32) {
+        // Tamanhos de bumpers e portais usam a menor escala para continuarem quadrados
+        let size_scale = sx.min(sy);
+        self.kind = match self.kind {
+            ObstacleKind::Block {
+                x,
+                y,
+                width,
+                height,
+            } => ObstacleKind::Block {
+                x: x * sx,
+                y: y * sy,
+                width: width * sx,
+                height: height * sy,
+            },
+            ObstacleKind::Bumper { x, y, size } => ObstacleKind::Bumper {
+                x: x * sx,
+                y: y * sy,
+                size: size * size_scale,
+            },
+            ObstacleKind::Deflector(segment) => ObstacleKind::Deflector(segment.scale(sx, sy)),
+            ObstacleKind::Portal {
+                x,
+                y,
+                exit_x,
+                exit_y,
+                size,
+            } => ObstacleKind::Portal {
+                x: x * sx,
+                y: y * sy,
+                exit_x: exit_x * sx,
+                exit_y: exit_y * sy,
+                size: size * size_scale,
+            },
+        };
+        if let Some(motion) = &mut self.motion {
+            motion.dx *= sx;
+            motion.dy *= sy;
+        }
+    }
+
+    /// Desenha o obstáculo
+    ///
+    /// # Argumentos
+    ///
+    /// * `canvas` - Canvas SDL para desenhar
+    /// * `theme` - Tema de cores
+    /// * `time` - Tempo de bola em jogo (em segundos)
+    pub fn render(&self, canvas: &mut Canvas<Window>, theme: &Theme, time: f32) {
+        let (ox, oy) = self.offset(time);
+
+        match self.kind {
+            ObstacleKind::Block {
+                x,
+                y,
+                width,
+                height,
+            } => {
+                canvas.set_draw_color(theme.text_dim);
+                canvas
+                    .fill_rect(Rect::new(
+                        (x + ox) as i32,
+                        (y + oy) as i32,
+                        width as u32,
+                        height as u32,
+                    ))
+                    .unwrap();
+            }
+            ObstacleKind::Bumper { x, y, size } => {
+                canvas.set_draw_color(theme.accent);
+                canvas
+                    .fill_rect(centered_rect(x + ox, y + oy, size))
+                    .unwrap();
+            }
+            ObstacleKind::Deflector(segment) => {
+                canvas.set_draw_color(theme.highlight);
+                segment.offset(ox, oy).render(canvas);
+            }
+            ObstacleKind::Portal {
+                x,
+                y,
+                exit_x,
+                exit_y,
+                size,
+            } => {
+                // Portais são molduras duplas (a bola passa por dentro delas)
+                canvas.set_draw_color(theme.highlight);
+                for (px, py) in [(x + ox, y + oy), (exit_x + ox, exit_y + oy)] {
+                    canvas.draw_rect(centered_rect(px, py, size)).unwrap();
+                    canvas.draw_rect(centered_rect(px, py, size - 8.0)).unwrap();
+                }
+            }
+        }
+    }
+}
+
+/// Arena: dimensões, paredes, obstáculos, raquetes, saques e gols da quadra
+#[derive(Clone)]
+pub struct Arena {
+    /// Identificador da arena (usado para traduzir o nome e salvar a escolha)
+    pub id: String,
+    /// Nome exibido no menu
+    pub name: String,
+    /// Largura da quadra a que as coordenadas se referem
+    pub width: f32,
+    /// Altura da quadra a que as coordenadas se referem
+    pub height: f32,
+    /// Paredes extras (segmentos fixos)
+    pub walls: Vec<Segment>,
+    /// Obstáculos da arena
+    pub obstacles: Vec<Obstacle>,
+    /// Distância da raquete esquerda à parede esquerda (`None` = posição padrão)
+    pub lane_left: Option<f32>,
+    /// Distância da raquete direita à parede direita (`None` = posição padrão)
+    pub lane_right: Option<f32>,
+    /// Pontos de onde a bola é lançada (vazio = centro da quadra)
+    pub spawns: Vec<(f32, f32)>,
+    /// Trecho da parede esquerda que vale ponto, de cima para baixo (`None` = a parede inteira)
+    pub goal_left: Option<(f32, f32)>,
+    /// Trecho da parede direita que vale ponto, de cima para baixo (`None` = a parede inteira)
+    pub goal_right: Option<(f32, f32)>,
+    /// Tempo de bola em jogo (move os obstáculos móveis)
+    time: f32,
+}
+
+impl Arena {
+    /// Cria uma arena
+    ///
+    /// # Argumentos
+    ///
+    /// * `id` - Identificador da arena
+    /// * `name` - Nome exibido no menu
+    /// * `obstacles` - Obstáculos da arena
+    pub fn new(id: &str, name: &str, obstacles: Vec<Obstacle>) -> Arena {
+        Arena {
+            id: id.to_string(),
+            name: name.to_string(),
+            width: DEFAULT_WIDTH,
+            height: DEFAULT_HEIGHT,
+            walls: Vec::new(),
+            obstacles,
+            lane_left: None,
+            lane_right: None,
+            spawns: Vec::new(),
+            goal_left: None,
+            goal_right: None,
+            time: 0.0,
+        }
+    }
+
+    /// Quadra clássica, sem obstáculos
+    pub fn classic() -> Arena {
+        Arena::new("classic", "Clássica", Vec::new())
+    }
+
+    /// Blocos no meio da quadra
+    pub fn blocks() -> Arena {
+        let block = |x, y, width, height| {
+            Obstacle::fixed(ObstacleKind::Block {
+                x,
+                y,
+                width,
+                height,
+            })
+        };
+        Arena::new(
+            "blocks",
+            "Blocos",
+            vec![
+                block(380.0, 60.0, 40.0, 100.0),
+                block(380.0, 440.0, 40.0, 100.0),
+                block(230.0, 270.0, 30.0, 60.0),
+                block(540.0, 270.0, 30.0, 60.0),
+            ],
+        )
+    }
+
+    /// Bumpers que aceleram a bola
+    pub fn bumpers() -> Arena {
+        let bumper = |x, y| Obstacle::fixed(ObstacleKind::Bumper { x, y, size: 36.0 });
+        Arena::new(
+            "bumpers",
+            "Bumpers",
+            vec![
+                bumper(260.0, 150.0),
+                bumper(540.0, 150.0),
+                bumper(260.0, 450.0),
+                bumper(540.0, 450.0),
+            ],
+        )
+    }
+
+    /// Defletores inclinados nos cantos do meio da quadra
+    pub fn deflectors() -> Arena {
+        let deflector =
+            |x1, y1, x2, y2| Obstacle::fixed(ObstacleKind::Deflector(Segment::new(x1, y1, x2, y2)));
+        Arena::new(
+            "deflectors",
+            "Defletores",
+            vec![
+                deflector(300.0, 90.0, 360.0, 170.0),
+                deflector(500.0, 90.0, 440.0, 170.0),
+                deflector(300.0, 510.0, 360.0, 430.0),
+                deflector(500.0, 510.0, 440.0, 430.0),
+            ],
+        )
+    }
+
+    /// Portais ligando o alto e o fundo da quadra
+    pub fn portals() -> Arena {
+        Arena::new(
+            "portals",
+            "Portais",
+            vec![
+                Obstacle::fixed(ObstacleKind::Portal {
+                    x: 250.0,
+                    y: 110.0,
+                    exit_x: 550.0,
+                    exit_y: 490.0,
+                    size: 50.0,
+                }),
+                Obstacle::fixed(ObstacleKind::Portal {
+                    x: 550.0,
+                    y: 110.0,
+                    exit_x: 250.0,
+                    exit_y: 490.0,
+                    size: 50.0,
+                }),
+            ],
+        )
+    }
+
+    /// Blocos e defletor em movimento
+    pub fn moving() -> Arena {
+        Arena::new(
+            "moving",
+            "Em Movimento",
+            vec![
+                Obstacle::moving(
+                    ObstacleKind::Block {
+                        x: 385.0,
+                        y: 60.0,
+                        width: 30.0,
+                        height: 80.0,
+                    },
+                    0.0,
+                    100.0,
+                    4.0,
+                ),
+                Obstacle::moving(
+                    ObstacleKind::Block {
+                        x: 385.0,
+                        y: 460.0,
+                        width: 30.0,
+                        height: 80.0,
+                    },
+                    0.0,
+                    -100.0,
+                    4.0,
+                ),
+                Obstacle::moving(
+                    ObstacleKind::Deflector(Segment::new(230.0, 260.0, 270.0, 340.0)),
+                    0.0,
+                    150.0,
+                    6.0,
+                ),
+                Obstacle::moving(
+                    ObstacleKind::Deflector(Segment::new(570.0, 260.0, 530.0, 340.0)),
+                    0.0,
+                    -150.0,
+                    6.0,
+                ),
+            ],
+        )
+    }
+
+    /// Retorna todas as arenas embutidas no jogo
+    pub fn builtin() -> Vec<Arena> {
+        vec![
+            Arena::classic(),
+            Arena::blocks(),
+            Arena::bumpers(),
+            Arena::deflectors(),
+            Arena::portals(),
+            Arena::moving(),
+        ]
+    }
+
+    /// Lê uma arena a partir do texto de um arquivo de arena
+    ///
+    /// Cada linha tem uma chave e números separados por espaços. As chaves
+    /// `wall`, `block`, `bumper`, `deflector`, `portal` e `spawn` podem se
+    /// repetir; `move` faz o obstáculo anterior ir e voltar.
+    ///
+    /// # Argumentos
+    ///
+    /// * `text` - Conteúdo no formato "chave = valores"
+    pub fn parse(text: &str) -> Result<Arena, String> {
+        let mut arena = Arena::new("custom", "Personalizada", Vec::new());
+
+        for entry in kv::parse(text)? {
+            let line = entry.line;
+            match entry.key.as_str() {
+                "name" => arena.name = entry.value,
+                "size" => {
+                    let [width, height] = parse_numbers(&entry)?;
+                    if width <= 0.0 || height <= 0.0 {
+                        return Err(format!("linha {}: tamanho da quadra inválido", line));
+                    }
+                    arena.width = width;
+                    arena.height = height;
+                }
+                "wall" => {
+                    let [x1, y1, x2, y2] = parse_numbers(&entry)?;
+                    arena.walls.push(Segment::new(x1, y1, x2, y2));
+                }
+                "block" => {
+                    let [x, y, width, height] = parse_numbers(&entry)?;
+                    if width <= 0.0 || height <= 0.0 {
+                        return Err(format!("linha {}: tamanho do bloco inválido", line));
+                    }
+                    arena.obstacles.push(Obstacle::fixed(ObstacleKind::Block {
+                        x,
+                        y,
+                        width,
+                        height,
+                    }));
+                }
+                "bumper" => {
+                    let [x, y, size] = parse_numbers(&entry)?;
+                    if size <= 0.0 {
+                        return Err(format!("linha {}: tamanho do bumper inválido", line));
+                    }
+                    arena
+                        .obstacles
+                        .push(Obstacle::fixed(ObstacleKind::Bumper { x, y, size }));
+                }
+                "deflector" => {
+                    let [x1, y1, x2, y2] = parse_numbers(&entry)?;
+                    arena
+                        .obstacles
+                        .push(Obstacle::fixed(ObstacleKind::Deflector(Segment::new(
+                            x1, y1, x2, y2,
+                        ))));
+                }
+                "portal" => {
+                    let [x, y, exit_x, exit_y, size] = parse_numbers(&entry)?;
+                    if size <= 0.0 {
+                        return Err(format!("linha {}: tamanho do portal inválido", line));
+                    }
+                    arena.obstacles.push(Obstacle::fixed(ObstacleKind::Portal {
+                        x,
+                        y,
+                        exit_x,
+                        exit_y,
+                        size,
+                    }));
+                }
+                "move" => {
+                    let [dx, dy, period] = parse_numbers(&entry)?;
+                    if period <= 0.0 {
+                        return Err(format!("linha {}: período do movimento inválido", line));
+                    }
+                    let obstacle = arena.obstacles.last_mut().ok_or_else(|| {
+                        format!("linha {}: 'move' precisa vir depois de um obstáculo", line)
+                    })?;
+                    obstacle.motion = Some(Motion { dx, dy, period });
+                }
+                "lane_left" => arena.lane_left = Some(parse_lane(&entry)?),
+                "lane_right" => arena.lane_right = Some(parse_lane(&entry)?),
+                "spawn" => {
+                    let [x, y] = parse_numbers(&entry)?;
+                    arena.spawns.push((x, y));
+                }
+                "goal_left" => arena.goal_left = Some(parse_goal(&entry)?),
+                "goal_right" => arena.goal_right = Some(parse_goal(&entry)?),
+                other => {
+                    return Err(format!("linha {}: chave desconhecida '{}'", line, other));
+                }
+            }
+        }
+
+        Ok(arena)
+    }
+
+    /// Carrega uma arena de um arquivo
+    ///
+    /// # Argumentos
+    ///
+    /// * `path` - Caminho do arquivo `.arena`
+    pub fn load_from_file(path: &Path) -> Result<Arena, String> {
+        let text = fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+        let mut arena = Arena::parse(&text).map_err(|e| format!("{}: {}", path.display(), e))?;
+        // O nome do arquivo identifica a arena
+        if let Some(stem) = path.file_stem().and_then(|stem| stem.to_str()) {
+            arena.id = stem.to_string();
+        }
+        Ok(arena)
+    }
+
+    /// Converte a arena para o formato do arquivo
+    pub fn to_text(&self) -> String {
+        let mut lines = vec![
+            String::from("# Arena do Pong with Rust"),
+            format!("name = {}", self.name),
+            format!("size = {} {}", self.width, self.height),
+        ];
+
+        for (key, goal) in [
+            ("goal_left", self.goal_left),
+            ("goal_right", self.goal_right),
+        ] {
+            if let Some((top, bottom)) = goal {
+                lines.push(format!("{} = {} {}", key, top, bottom));
+            }
+        }
+        for (key, lane) in [
+            ("lane_left", self.lane_left),
+            ("lane_right", self.lane_right),
+        ] {
+            if let Some(lane) = lane {
+                lines.push(format!("{} = {}", key, lane));
+            }
+        }
+        for wall in &self.walls {
+            lines.push(format!(
+                "wall = {} {} {} {}",
+                wall.x1, wall.y1, wall.x2, wall.y2
+            ));
+        }
+
+        for obstacle in &self.obstacles {
+            lines.push(match obstacle.kind {
+                ObstacleKind::Block {
+                    x,
+                    y,
+                    width,
+                    height,
+                } => format!("block = {} {} {} {}", x, y, width, height),
+                ObstacleKind::Bumper { x, y, size } => format!("bumper = {} {} {}", x, y, size),
+                ObstacleKind::Deflector(segment) => format!(
+                    "deflector = {} {} {} {}",
+                    segment.x1, segment.y1, segment.x2, segment.y2
+                ),
+                ObstacleKind::Portal {
+                    x,
+                    y,
+                    exit_x,
+                    exit_y,
+                    size,
+                } => format!("portal = {} {} {} {} {}", x, y, exit_x, exit_y, size),
+            });
+            if let Some(motion) = obstacle.motion {
+                lines.push(format!(
+                    "move = {} {} {}",
+                    motion.dx, motion.dy, motion.period
+                ));
+            }
+        }
+
+        for (x, y) in &self.spawns {
+            lines.push(format!("spawn = {} {}", x, y));
+        }
+
+        lines.join("\n") + "\n"
+    }
+
+    /// Salva a arena em um arquivo com o nome do seu identificador
+    ///
+    /// # Argumentos
+    ///
+    /// * `dir` - Diretório das arenas (criado se não existir)
+    ///
+    /// # Retorna
+    ///
+    /// O caminho do arquivo salvo
+    pub fn save(&self, dir: &Path) -> Result<PathBuf, String> {
+        fs::create_dir_all(dir).map_err(|e| format!("{}: {}", dir.display(), e))?;
+        let path = dir.join(format!("{}.{}", self.id, ARENA_EXTENSION));
+        fs::write(&path, self.to_text()).map_err(|e| format!("{}: {}", path.display(), e))?;
+        Ok(path)
+    }
+
+    /// Ajusta as coordenadas da arena ao tamanho da quadra
+    ///
+    /// # Argumentos
+    ///
+    /// * `width` - Largura da quadra
+    /// * `height` - Altura da quadra
+    pub fn fit(&mut self, width: f32, height: f32) {
+        let (sx, sy) = (width / self.width, height / self.height);
+        if sx == 1.0 && sy == 1.0 {
+            return;
+        }
+
+        for wall in &mut self.walls {
+            *wall = wall.scale(sx, sy);
+        }
+        for obstacle in &mut self.obstacles {
+            obstacle.scale(sx, sy);
+        }
+        self.lane_left = self.lane_left.map(|lane| lane * sx);
+        self.lane_right = self.lane_right.map(|lane| lane * sx);
+        for spawn in &mut self.spawns {
+            *spawn = (spawn.0 * sx, spawn.1 * sy);
+        }
+        self.goal_left = self.goal_left.map(|(top, bottom)| (top * sy, bottom * sy));
+        self.goal_right = self.goal_right.map(|(top, bottom)| (top * sy, bottom * sy));
+        self.width = width;
+        self.height = height;
+    }
+
+    /// Sorteia um dos pontos de lançamento da bola
+    ///
+    /// # Argumentos
+    ///
+    /// * `rng` - Gerador de números aleatórios da partida
+    ///
+    /// # Retorna
+    ///
+    /// A posição sorteada, ou `None` se a bola sai do centro da quadra
+    pub fn random_spawn(&self, rng: &mut GameRng) -> Option<(f32, f32)> {
+        self.spawns.choose(rng).copied()
+    }
+
+    /// Paredes laterais fora dos gols (trechos das paredes esquerda e direita que
+    /// não valem ponto)
+    fn goal_walls(&self) -> Vec<Segment> {
+        let mut walls = Vec::new();
+        for (goal, x) in [(self.goal_left, 0.0), (self.goal_right, self.width)] {
+            if let Some((top, bottom)) = goal {
+                walls.push(Segment::new(x, 0.0, x, top));
+                walls.push(Segment::new(x, bottom, x, self.height));
+            }
+        }
+        walls
+    }
+
+    /// Volta os obstáculos móveis à posição inicial (nova partida)
+    pub fn reset(&mut self) {
+        self.time = 0.0;
+    }
+
+    /// Tempo de bola em jogo que define a posição dos obstáculos móveis
+    pub fn time(&self) -> f32 {
+        self.time
+    }
+
+    /// Coloca os obstáculos móveis na posição de um tempo de bola em jogo
+    /// (usado ao continuar um jogo salvo)
+    ///
+    /// # Argumentos
+    ///
+    /// * `time` - Tempo retornado por `time`
+    pub fn set_time(&mut self, time: f32) {
+        self.time = time;
+    }
+
+    /// Move os obstáculos móveis
+    ///
+    /// # Argumentos
+    ///
+    /// * `delta_time` - Tempo decorrido desde o último frame (em segundos)
+    pub fn update(&mut self, delta_time: f32) {
+        self.time += delta_time;
+    }
+
+    /// Verifica a colisão da bola com as paredes e os obstáculos
+    ///
+    /// # Retorna
+    ///
+    /// `true` se a bola encostou em alguma parede ou obstáculo
+    pub fn collide(&self, ball: &mut Ball) -> bool {
+        let mut hit = collide_segments(ball, &self.walls);
+        hit |= collide_segments(ball, &self.goal_walls());
+        for obstacle in &self.obstacles {
+            hit |= obstacle.collide(ball, self.time);
+        }
+        hit
+    }
+
+    /// Desenha os obstáculos
+    ///
+    /// # Argumentos
+    ///
+    /// * `canvas` - Canvas SDL para desenhar
+    /// * `theme` - Tema de cores
+    pub fn render(&self, canvas: &mut Canvas<Window>, theme: &Theme) {
+        canvas.set_draw_color(theme.text_dim);
+        for wall in self.walls.iter().chain(self.goal_walls().iter()) {
+            wall.render(canvas);
+        }
+
+        for obstacle in &self.obstacles {
+            obstacle.render(canvas, theme, self.time);
+        }
+    }
+}
+
+/// Carrega as arenas embutidas e as arenas do diretório informado
+///
+/// Arquivos inválidos são ignorados e informados no terminal, para que uma
+/// arena com erro não impeça o jogo de iniciar.
+///
+/// # Argumentos
+///
+/// * `dir` - Diretório com arquivos `.arena`
+pub fn load_arenas(dir: &Path) -> Vec<Arena> {
+    let mut arenas = Arena::builtin();
+
+    let Ok(read_dir) = fs::read_dir(dir) else {
+        return arenas;
+    };
+
+    // Ordena os arquivos para que a ordem das arenas seja sempre a mesma
+    let mut paths: Vec<_> = read_dir
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == ARENA_EXTENSION))
+        .collect();
+    paths.sort();
+
+    for path in paths {
+        match Arena::load_from_file(&path) {
+            Ok(arena) => arenas.push(arena),
+            Err(e) => eprintln!("Arena ignorada: {}", e),
+        }
+    }
+
+    arenas
+}
+
+/// Lê os números (separados por espaços) do valor de uma linha
+fn parse_numbers<const N: usize>(entry: &kv::Entry) -> Result<[f32; N], String> {
+    let invalid = || {
+        format!(
+            "linha {}: esperados {} números em '{}'",
+            entry.line, N, entry.value
+        )
+    };
+
+    let numbers = entry
+        .value
+        .split_whitespace()
+        .map(|value| value.parse::<f32>().ok().filter(|n| n.is_finite()))
+        .collect::<Option<Vec<_>>>()
+        .ok_or_else(invalid)?;
+    numbers.try_into().map_err(|_| invalid())
+}
+
+/// Lê a distância de uma raquete até a sua parede
+fn parse_lane(entry: &kv::Entry) -> Result<f32, String> {
+    let [lane] = parse_numbers(entry)?;
+    if lane < 0.0 {
+        return Err(format!("linha {}: posição da raquete inválida", entry.line));
+    }
+    Ok(lane)
+}
+
+/// Lê o trecho de uma parede lateral que vale ponto
+fn parse_goal(entry: &kv::Entry) -> Result<(f32, f32), String> {
+    let [top, bottom] = parse_numbers(entry)?;
+    if top < 0.0 || bottom <= top {
+        return Err(format!(
+            "linha {}: gol inválido '{}'",
+            entry.line, entry.value
+        ));
+    }
+    Ok((top, bottom))
+}
+
+/// Verifica a colisão da bola com vários segmentos (lados de um bloco)
+pub fn collide_segments(ball: &mut Ball, segments: &[Segment]) -> bool {
+    let mut hit = false;
+    for segment in segments {
+        hit |= ball.check_segment_collision(segment);
+    }
+    hit
+}
+
+/// Retângulo quadrado centralizado em um ponto
+fn centered_rect(x: f32, y: f32, size: f32) -> Rect {
+    let half = size / 2.0;
+    Rect::new(
+        (x - half) as i32,
+        (y - half) as i32,
+        size as u32,
+        size as u32,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Confere que a leitura falha com a mensagem da linha indicada
+    fn assert_error(text: &str, line: usize, message: &str) {
+        let error = Arena::parse(text)
+            .err()
+            .expect("a arena deveria ser inválida");
+        let prefix = format!("linha {}: ", line);
+        assert!(
+            error.starts_with(&prefix),
+            "erro sem o número da linha: {}",
+            error
+        );
+        assert!(error.contains(message), "mensagem inesperada: {}", error);
+    }
+
+    #[test]
+    fn parses_a_valid_arena() {
+        let text = "# Arena de teste\n\
+                    name = Teste\n\
+                    size = 400 300\n\
+                    wall = 0 10 400 10\n\
+                    block = 180 120 40 60\n\
+                    move = 0 50 2\n\
+                    bumper = 100 150 20\n\
+                    lane_left = 30\n\
+                    spawn = 200 150\n\
+                    goal_right = 100 200\n";
+        let arena = Arena::parse(text).unwrap();
+
+        assert_eq!(arena.name, "Teste");
+        assert_eq!((arena.width, arena.height), (400.0, 300.0));
+        assert_eq!(arena.walls.len(), 1);
+        assert_eq!(arena.obstacles.len(), 2);
+        let motion = arena.obstacles[0].motion.expect("o bloco deveria se mover");
+        assert_eq!((motion.dx, motion.dy, motion.period), (0.0, 50.0, 2.0));
+        assert!(arena.obstacles[1].motion.is_none());
+        assert_eq!(arena.lane_left, Some(30.0));
+        assert_eq!(arena.spawns, vec![(200.0, 150.0)]);
+        assert_eq!(arena.goal_left, None);
+        assert_eq!(arena.goal_right, Some((100.0, 200.0)));
+
+        // O texto gravado volta a ser a mesma arena
+        let again = Arena::parse(&arena.to_text()).unwrap();
+        assert_eq!(again.to_text(), arena.to_text());
+    }
+
+    #[test]
+    fn reports_the_line_of_an_unknown_key() {
+        assert_error(
+            "name = Teste\n\nladder = 1 2\n",
+            3,
+            "chave desconhecida 'ladder'",
+        );
+    }
+
+    #[test]
+    fn reports_the_line_of_a_wrong_number_count() {
+        assert_error("size = 800 600\nwall = 0 0 800\n", 2, "esperados 4 números");
+        assert_error("bumper = 10 10 abc\n", 1, "esperados 3 números");
+    }
+
+    #[test]
+    fn reports_move_before_any_obstacle() {
+        assert_error(
+            "# comentário\nwall = 0 0 10 10\nmove = 0 10 1\n",
+            3,
+            "'move' precisa vir depois",
+        );
+    }
+
+    #[test]
+    fn reports_an_invalid_goal_range() {
+        assert_error("goal_left = 300 200\n", 1, "gol inválido");
+        assert_error("size = 800 600\ngoal_right = -10 200\n", 2, "gol inválido");
+    }
+}
@@ -1,11 +1,15 @@
 // Estrutura e lógica da bola do jogo
 
-use sdl2::rect::Rect;
+use super::arena::Segment;
+use super::random::GameRng;
+use super::snapshot::{self, Fields, Snapshot};
 use rand::Rng;
+use sdl2::rect::Rect;
 use std::f32::consts::PI;
 
 /// Representa a bola no jogo Pong
 /// A bola se move pela tela e colide com as raquetes e paredes
+#[derive(Clone)]
 pub struct Ball {
     /// Posição X da bola (centro)
     pub x: f32,
@@ -19,6 +23,16 @@ pub struct Ball {
     pub vel_y: f32,
     /// Velocidade base da bola
     base_speed: f32,
+    /// Jogador que rebateu a bola por último (é quem pega os power-ups)
+    pub owner: Option<usize>,
+    /// Fator de velocidade temporário (power-ups de bola rápida e lenta)
+    speed_scale: f32,
+    /// Tempo restante do efeito de velocidade (em segundos)
+    speed_time: f32,
+    /// Aceleração vertical do efeito de curva (pixels por segundo ao quadrado)
+    curve: f32,
+    /// Tempo restante do efeito de curva (em segundos)
+    curve_time: f32,
 }
 
 impl Ball {
@@ -38,17 +52,21 @@ impl Ball {
             vel_x: 0.0,
             vel_y: 0.0,
             base_speed: speed,
+            owner: None,
+            speed_scale: 1.0,
+            speed_time: 0.0,
+            curve: 0.0,
+            curve_time: 0.0,
         }
     }
 
-    /// Lança a bola em uma direção aleatória
-    /// A bola sempre começa indo para a esquerda ou direita com um ângulo aleatório
-    pub fn launch(&mut self) {
-        let mut rng = rand::rng();
-        
-        // Escolhe direção aleatória (esquerda ou direita)
-        let direction = if rng.random_bool(0.5) { 1.0 } else { -1.0 };
-        
+    /// Lança a bola para um dos lados com um ângulo aleatório
+    ///
+    /// # Argumentos
+    ///
+    /// * `direction` - 1.0 para a direita, -1.0 para a esquerda
+    /// * `rng` - Gerador de números aleatórios da partida
+    pub fn launch(&mut self, direction: f32, rng: &mut GameRng) {
         // Ângulo aleatório entre -45 e 45 graus
         let angle = rng.random_range(-PI / 4.0..PI / 4.0);
         
@@ -57,12 +75,50 @@ impl Ball {
         self.vel_y = self.base_speed * angle.sin();
     }
 
+    /// Lança a bola em um ângulo qualquer (usado no modo de 4 jogadores)
+    ///
+    /// # Argumentos
+    ///
+    /// * `angle` - Ângulo em radianos (0 = direita, PI / 2 = baixo)
+    pub fn launch_at(&mut self, angle: f32) {
+        self.vel_x = self.base_speed * angle.cos();
+        self.vel_y = self.base_speed * angle.sin();
+    }
+
+    /// Altera a velocidade base da bola
+    ///
+    /// Se a bola estiver em movimento, a velocidade atual é ajustada na mesma proporção.
+    ///
+    /// # Argumentos
+    ///
+    /// * `speed` - Nova velocidade base (pixels por segundo)
+    pub fn set_base_speed(&mut self, speed: f32) {
+        let ratio = speed / self.base_speed;
+        self.vel_x *= ratio;
+        self.vel_y *= ratio;
+        self.base_speed = speed;
+    }
+
     /// Atualiza a posição da bola baseado no tempo decorrido
     /// 
     /// # Argumentos
     /// 
     /// * `delta_time` - Tempo decorrido desde o último frame (em segundos)
     pub fn update(&mut self, delta_time: f32) {
+        // Efeito de curva: a bola é puxada para cima ou para baixo
+        if self.curve_time > 0.0 {
+            self.vel_y += self.curve * delta_time;
+            self.curve_time -= delta_time;
+        }
+
+        // Fim do efeito de velocidade: a bola volta ao ritmo normal
+        if self.speed_time > 0.0 {
+            self.speed_time -= delta_time;
+            if self.speed_time <= 0.0 {
+                self.apply_speed(1.0, 0.0);
+            }
+        }
+
         self.x += self.vel_x * delta_time;
         self.y += self.vel_y * delta_time;
     }
@@ -76,38 +132,156 @@ impl Ball {
         self.vel_y *= 1.05;
     }
 
-    /// Inverte a direção vertical da bola (colisão com parede superior/inferior)
-    pub fn bounce_vertical(&mut self) {
+    /// Inverte a direção vertical da bola (colisão com as raquetes de cima e de
+    /// baixo no modo de 4 jogadores)
+    pub fn bounce_off_horizontal_paddle(&mut self) {
         self.vel_y = -self.vel_y;
+
+        // Aumenta ligeiramente a velocidade, como nas raquetes laterais
+        self.vel_x *= 1.05;
+        self.vel_y *= 1.05;
+    }
+
+    /// Muda a velocidade da bola por um tempo (power-ups)
+    ///
+    /// # Argumentos
+    ///
+    /// * `scale` - Fator de velocidade (1.0 volta ao normal)
+    /// * `duration` - Duração do efeito (em segundos)
+    pub fn apply_speed(&mut self, scale: f32, duration: f32) {
+        // Desfaz o efeito anterior antes de aplicar o novo
+        let ratio = scale / self.speed_scale;
+        self.vel_x *= ratio;
+        self.vel_y *= ratio;
+        self.speed_scale = scale;
+        self.speed_time = duration;
+    }
+
+    /// Faz a bola fazer uma curva por um tempo (power-up)
+    ///
+    /// # Argumentos
+    ///
+    /// * `acceleration` - Aceleração vertical (positiva para baixo)
+    /// * `duration` - Duração do efeito (em segundos)
+    pub fn apply_curve(&mut self, acceleration: f32, duration: f32) {
+        self.curve = acceleration;
+        self.curve_time = duration;
+    }
+
+    /// Gira a direção da bola mantendo a velocidade
+    ///
+    /// # Argumentos
+    ///
+    /// * `angle` - Ângulo em radianos
+    pub fn rotate(&mut self, angle: f32) {
+        let (sin, cos) = angle.sin_cos();
+        let vel_x = self.vel_x * cos - self.vel_y * sin;
+        self.vel_y = self.vel_x * sin + self.vel_y * cos;
+        self.vel_x = vel_x;
+    }
+
+    /// Multiplica a velocidade atual da bola (modo sobrevivência)
+    ///
+    /// # Argumentos
+    ///
+    /// * `scale` - Fator de velocidade
+    pub fn accelerate(&mut self, scale: f32) {
+        self.vel_x *= scale;
+        self.vel_y *= scale;
+    }
+
+    /// Indica se a bola está sob o efeito de algum power-up
+    pub fn has_effect(&self) -> bool {
+        self.speed_time > 0.0 || self.curve_time > 0.0
     }
 
     /// Verifica colisão com as paredes superior e inferior
     /// 
     /// # Argumentos
     /// 
+    /// * `screen_width` - Largura da tela
     /// * `screen_height` - Altura da tela
     /// 
     /// # Retorna
     /// 
     /// `true` se houve colisão, `false` caso contrário
-    pub fn check_wall_collision(&mut self, screen_height: f32) -> bool {
+    pub fn check_wall_collision(&mut self, screen_width: f32, screen_height: f32) -> bool {
+        let top = Segment::new(0.0, 0.0, screen_width, 0.0);
+        let bottom = Segment::new(0.0, screen_height, screen_width, screen_height);
+
+        self.check_segment_collision(&top) || self.check_segment_collision(&bottom)
+    }
+
+    /// Verifica colisão com um segmento em qualquer ângulo (paredes e obstáculos)
+    ///
+    /// A bola é tratada como um círculo: se encostar no segmento, é empurrada para
+    /// fora e, se estiver indo na direção dele, é refletida.
+    ///
+    /// # Argumentos
+    ///
+    /// * `segment` - Segmento a verificar
+    ///
+    /// # Retorna
+    ///
+    /// `true` se a bola foi rebatida, `false` caso contrário
+    pub fn check_segment_collision(&mut self, segment: &Segment) -> bool {
         let half_size = self.size / 2.0;
+        let (dx, dy) = (segment.x2 - segment.x1, segment.y2 - segment.y1);
+        let length_sq = dx * dx + dy * dy;
         
-        // Colisão com parede superior
-        if self.y - half_size <= 0.0 {
-            self.y = half_size;
-            self.bounce_vertical();
-            return true;
+        // Ponto do segmento mais próximo do centro da bola
+        let t = if length_sq > 0.0 {
+            (((self.x - segment.x1) * dx + (self.y - segment.y1) * dy) / length_sq).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        let (closest_x, closest_y) = (segment.x1 + dx * t, segment.y1 + dy * t);
+
+        let (mut normal_x, mut normal_y) = (self.x - closest_x, self.y - closest_y);
+        let distance = normal_x.hypot(normal_y);
+        if distance >= half_size {
+            return false;
         }
         
-        // Colisão com parede inferior
-        if self.y + half_size >= screen_height {
-            self.y = screen_height - half_size;
-            self.bounce_vertical();
-            return true;
+        if distance > f32::EPSILON {
+            normal_x /= distance;
+            normal_y /= distance;
+        } else {
+            // Centro em cima do segmento: a normal aponta contra o movimento
+            let length = length_sq.sqrt().max(f32::EPSILON);
+            normal_x = -dy / length;
+            normal_y = dx / length;
+            if normal_x * self.vel_x + normal_y * self.vel_y > 0.0 {
+                normal_x = -normal_x;
+                normal_y = -normal_y;
+            }
         }
         
-        false
+        // Empurra a bola para fora do segmento
+        self.x = closest_x + normal_x * half_size;
+        self.y = closest_y + normal_y * half_size;
+
+        // Só rebate se a bola estiver indo na direção do segmento
+        let dot = self.vel_x * normal_x + self.vel_y * normal_y;
+        if dot >= 0.0 {
+            return false;
+        }
+        self.vel_x -= 2.0 * dot * normal_x;
+        self.vel_y -= 2.0 * dot * normal_y;
+        true
+    }
+
+    /// Número de passos em que o movimento do frame deve ser dividido
+    ///
+    /// Bolas rápidas andam no máximo meio tamanho por passo, para não atravessar
+    /// paredes e obstáculos finos.
+    ///
+    /// # Argumentos
+    ///
+    /// * `delta_time` - Tempo decorrido desde o último frame (em segundos)
+    pub fn substeps(&self, delta_time: f32) -> u32 {
+        let distance = self.vel_x.hypot(self.vel_y) * delta_time;
+        ((distance / (self.size / 2.0)).ceil() as u32).max(1)
     }
 
     /// Retorna um retângulo SDL para renderização
@@ -132,5 +306,71 @@ impl Ball {
         self.y = screen_height / 2.0;
         self.vel_x = 0.0;
         self.vel_y = 0.0;
+        self.owner = None;
+        self.speed_scale = 1.0;
+        self.speed_time = 0.0;
+        self.curve_time = 0.0;
+    }
+}
+
+impl Snapshot for Ball {
+    fn snapshot(&self) -> String {
+        format!(
+            "{} {} {} {} {} {} {} {} {} {} {}",
+            self.x,
+            self.y,
+            self.size,
+            self.vel_x,
+            self.vel_y,
+            self.base_speed,
+            snapshot::optional(self.owner),
+            self.speed_scale,
+            self.speed_time,
+            self.curve,
+            self.curve_time,
+        )
+    }
+
+    fn restore(fields: &mut Fields) -> Result<Ball, String> {
+        Ok(Ball {
+            x: fields.next()?,
+            y: fields.next()?,
+            size: fields.next()?,
+            vel_x: fields.next()?,
+            vel_y: fields.next()?,
+            base_speed: fields.next()?,
+            owner: fields.next_optional()?,
+            speed_scale: fields.next()?,
+            speed_time: fields.next()?,
+            curve: fields.next()?,
+            curve_time: fields.next()?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn snapshot_round_trip() {
+        let mut ball = Ball::new(120.5, 80.25, 10.0, 300.0);
+        ball.launch_at(0.3);
+        ball.owner = Some(1);
+        ball.apply_speed(1.5, 2.0);
+        ball.apply_curve(40.0, 1.0);
+        ball.update(0.1);
+
+        let text = ball.snapshot();
+        let restored: Ball = snapshot::restore(&text).unwrap();
+        assert_eq!(restored.snapshot(), text);
+        assert_eq!(restored.owner, Some(1));
+
+        // Sem dono e com um valor a menos
+        ball.owner = None;
+        let restored: Ball = snapshot::restore(&ball.snapshot()).unwrap();
+        assert_eq!(restored.owner, None);
+        let short = ball.snapshot().rsplit_once(' ').unwrap().0.to_string();
+        assert!(snapshot::restore::<Ball>(&short).is_err());
     }
 }
@@ -0,0 +1,160 @@
+// Modo breakout: cada jogador defende uma parede de tijolos atrás da raquete
+
+use super::arena::{self, Segment};
+use super::ball::Ball;
+use super::game_state::Side;
+use super::snapshot::{Fields, Snapshot};
+use super::theme::Theme;
+use sdl2::rect::Rect;
+use sdl2::render::Canvas;
+use sdl2::video::Window;
+
+/// Distância entre a parede e a raquete de cada jogador no breakout (as
+/// raquetes ficam à frente dos tijolos)
+pub const BREAKOUT_PADDLE_OFFSET: f32 = 80.0;
+
+/// Colunas de tijolos de cada jogador
+const BRICK_COLUMNS: usize = 3;
+
+/// Fileiras de tijolos de cada jogador
+const BRICK_ROWS: usize = 10;
+
+/// Largura de cada tijolo
+const BRICK_WIDTH: f32 = 16.0;
+
+/// Espaço entre os tijolos (e entre os tijolos e as bordas da tela)
+const BRICK_GAP: f32 = 4.0;
+
+/// Tijolo da parede de um jogador
+#[derive(Clone, Copy)]
+pub struct Brick {
+    /// Posição X do canto superior esquerdo
+    pub x: f32,
+    /// Posição Y do canto superior esquerdo
+    pub y: f32,
+    /// Largura
+    pub width: f32,
+    /// Altura
+    pub height: f32,
+    /// Lado do jogador que defende o tijolo
+    pub side: Side,
+}
+
+/// Tijolos da quadra e quantos cada jogador quebrou
+pub struct Breakout {
+    /// Tijolos que ainda estão de pé (dos dois lados)
+    pub bricks: Vec<Brick>,
+    /// Tijolos quebrados por cada jogador (pela última bola rebatida por ele)
+    pub broken: [u32; 2],
+}
+
+impl Breakout {
+    /// Monta as paredes de tijolos dos dois lados da quadra
+    ///
+    /// # Argumentos
+    ///
+    /// * `screen_width` - Largura da tela
+    /// * `screen_height` - Altura da tela
+    pub fn new(screen_width: f32, screen_height: f32) -> Breakout {
+        let height = (screen_height - BRICK_GAP * (BRICK_ROWS + 1) as f32) / BRICK_ROWS as f32;
+        let mut bricks = Vec::with_capacity(BRICK_COLUMNS * BRICK_ROWS * 2);
+
+        for column in 0..BRICK_COLUMNS {
+            let offset = BRICK_GAP + column as f32 * (BRICK_WIDTH + BRICK_GAP);
+            for row in 0..BRICK_ROWS {
+                let y = BRICK_GAP + row as f32 * (height + BRICK_GAP);
+                for (side, x) in [
+                    (Side::Left, offset),
+                    (Side::Right, screen_width - offset - BRICK_WIDTH),
+                ] {
+                    bricks.push(Brick {
+                        x,
+                        y,
+                        width: BRICK_WIDTH,
+                        height,
+                        side,
+                    });
+                }
+            }
+        }
+
+        Breakout {
+            bricks,
+            broken: [0, 0],
+        }
+    }
+
+    /// Número de tijolos de pé de um lado
+    pub fn remaining(&self, side: Side) -> usize {
+        self.bricks
+            .iter()
+            .filter(|brick| brick.side == side)
+            .count()
+    }
+
+    /// Rebate a bola nos tijolos e quebra os tijolos atingidos
+    ///
+    /// # Argumentos
+    ///
+    /// * `ball` - Bola do jogo
+    ///
+    /// # Retorna
+    ///
+    /// `true` se a bola quebrou algum tijolo
+    pub fn collide(&mut self, ball: &mut Ball) -> bool {
+        let before = self.bricks.len();
+        self.bricks.retain(|brick| {
+            let sides = Segment::rect(brick.x, brick.y, brick.width, brick.height);
+            !arena::collide_segments(ball, &sides)
+        });
+
+        let broken = (before - self.bricks.len()) as u32;
+        if let Some(counter) = ball.owner.and_then(|owner| self.broken.get_mut(owner)) {
+            *counter += broken;
+        }
+        broken > 0
+    }
+
+    /// Desenha os tijolos com a cor do jogador que os defende
+    ///
+    /// # Argumentos
+    ///
+    /// * `canvas` - Canvas SDL para desenhar
+    /// * `theme` - Tema de cores
+    pub fn render(&self, canvas: &mut Canvas<Window>, theme: &Theme) {
+        for brick in &self.bricks {
+            canvas.set_draw_color(theme.player_color(brick.side.player()));
+            canvas
+                .fill_rect(Rect::new(
+                    brick.x as i32,
+                    brick.y as i32,
+                    brick.width as u32,
+                    brick.height as u32,
+                ))
+                .unwrap();
+        }
+    }
+}
+
+impl Snapshot for Brick {
+    fn snapshot(&self) -> String {
+        format!(
+            "{} {} {} {} {}",
+            self.x,
+            self.y,
+            self.width,
+            self.height,
+            self.side.id()
+        )
+    }
+
+    fn restore(fields: &mut Fields) -> Result<Brick, String> {
+        Ok(Brick {
+            x: fields.next()?,
+            y: fields.next()?,
+            width: fields.next()?,
+            height: fields.next()?,
+            side: fields.next_id(&Side::ALL, Side::id)?,
+        })
+    }
+}
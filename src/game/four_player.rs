@@ -0,0 +1,394 @@
+// Modo de 4 jogadores: raquetes nas quatro paredes e vidas
+
+use super::ball::Ball;
+use super::paddle::Paddle;
+use super::random::GameRng;
+use super::theme::Theme;
+use rand::seq::IndexedRandom;
+use rand::Rng;
+use sdl2::rect::Rect;
+use sdl2::render::Canvas;
+use sdl2::video::Window;
+use std::f32::consts::PI;
+
+/// Espessura das paredes que substituem os jogadores eliminados
+const WALL_THICKNESS: u32 = 6;
+
+/// Paredes da quadra; no modo de 4 jogadores cada uma é defendida por um jogador
+#[derive(PartialEq, Clone, Copy)]
+pub enum Wall {
+    /// Jogador 1 (raquete vertical)
+    Left,
+    /// Jogador 2 (raquete vertical)
+    Right,
+    /// Jogador 3 (raquete horizontal)
+    Top,
+    /// Jogador 4 (raquete horizontal)
+    Bottom,
+}
+
+impl Wall {
+    /// Todas as paredes, na ordem dos jogadores
+    pub const ALL: [Wall; 4] = [Wall::Left, Wall::Right, Wall::Top, Wall::Bottom];
+
+    /// Índice do jogador que defende a parede (0 a 3)
+    pub fn player(self) -> usize {
+        match self {
+            Wall::Left => 0,
+            Wall::Right => 1,
+            Wall::Top => 2,
+            Wall::Bottom => 3,
+        }
+    }
+
+    /// Retorna a parede defendida por um jogador
+    ///
+    /// # Argumentos
+    ///
+    /// * `player` - Índice do jogador (0 a 3)
+    pub fn from_player(player: usize) -> Option<Wall> {
+        Wall::ALL.get(player).copied()
+    }
+
+    /// Sorteia o ângulo de saque em direção à parede (a bola sai do centro)
+    ///
+    /// # Argumentos
+    ///
+    /// * `rng` - Gerador de números aleatórios da partida
+    pub fn serve_angle(self, rng: &mut GameRng) -> f32 {
+        let base = match self {
+            Wall::Right => 0.0,
+            Wall::Bottom => PI / 2.0,
+            Wall::Left => PI,
+            Wall::Top => -PI / 2.0,
+        };
+        base + rng.random_range(-PI / 6.0..PI / 6.0)
+    }
+
+    /// Rebate a bola se ela chegou à parede (paredes de jogadores eliminados
+    /// e escudos dos power-ups)
+    ///
+    /// # Argumentos
+    ///
+    /// * `ball` - Bola do jogo
+    /// * `screen_width` - Largura da tela
+    /// * `screen_height` - Altura da tela
+    ///
+    /// # Retorna
+    ///
+    /// `true` se a bola foi rebatida
+    pub fn bounce(self, ball: &mut Ball, screen_width: f32, screen_height: f32) -> bool {
+        let half_size = ball.size / 2.0;
+        match self {
+            Wall::Left if ball.x - half_size <= 0.0 => {
+                ball.x = half_size;
+                ball.vel_x = ball.vel_x.abs();
+            }
+            Wall::Right if ball.x + half_size >= screen_width => {
+                ball.x = screen_width - half_size;
+                ball.vel_x = -ball.vel_x.abs();
+            }
+            Wall::Top if ball.y - half_size <= 0.0 => {
+                ball.y = half_size;
+                ball.vel_y = ball.vel_y.abs();
+            }
+            Wall::Bottom if ball.y + half_size >= screen_height => {
+                ball.y = screen_height - half_size;
+                ball.vel_y = -ball.vel_y.abs();
+            }
+            _ => return false,
+        }
+        true
+    }
+
+    /// Retângulo desenhado sobre a parede
+    ///
+    /// # Argumentos
+    ///
+    /// * `screen_width` - Largura da tela
+    /// * `screen_height` - Altura da tela
+    pub fn rect(self, screen_width: f32, screen_height: f32) -> Rect {
+        let width = screen_width as u32;
+        let height = screen_height as u32;
+        match self {
+            Wall::Left => Rect::new(0, 0, WALL_THICKNESS, height),
+            Wall::Right => Rect::new((width - WALL_THICKNESS) as i32, 0, WALL_THICKNESS, height),
+            Wall::Top => Rect::new(0, 0, width, WALL_THICKNESS),
+            Wall::Bottom => Rect::new(0, (height - WALL_THICKNESS) as i32, width, WALL_THICKNESS),
+        }
+    }
+}
+
+/// Parte do estado do jogo que só existe no modo de 4 jogadores
+///
+/// As raquetes esquerda e direita continuam no `GameState`; aqui ficam as
+/// raquetes de cima e de baixo e as vidas de cada jogador.
+pub struct FourPlayer {
+    /// Raquete do jogador 3 (topo)
+    pub paddle_top: Paddle,
+    /// Raquete do jogador 4 (fundo)
+    pub paddle_bottom: Paddle,
+    /// Vidas restantes de cada jogador (na ordem de `Wall::ALL`)
+    pub lives: [u32; 4],
+}
+
+impl FourPlayer {
+    /// Cria o modo de 4 jogadores com as raquetes horizontais centralizadas
+    ///
+    /// # Argumentos
+    ///
+    /// * `screen_width` - Largura da tela
+    /// * `screen_height` - Altura da tela
+    /// * `side_paddle` - Raquete vertical usada como modelo (tamanho, distância da parede e velocidade)
+    /// * `lives` - Vidas de cada jogador
+    pub fn new(
+        screen_width: f32,
+        screen_height: f32,
+        side_paddle: &Paddle,
+        lives: u32,
+    ) -> FourPlayer {
+        // As raquetes horizontais são a raquete vertical "deitada"
+        let length = side_paddle.height;
+        let thickness = side_paddle.width;
+        let offset = side_paddle.x;
+        let x = (screen_width - length) / 2.0;
+
+        FourPlayer {
+            paddle_top: Paddle::new_horizontal(x, offset, length, thickness, side_paddle.speed),
+            paddle_bottom: Paddle::new_horizontal(
+                x,
+                screen_height - offset - thickness,
+                length,
+                thickness,
+                side_paddle.speed,
+            ),
+            lives: [lives; 4],
+        }
+    }
+
+    /// Indica se o jogador da parede ainda está no jogo
+    pub fn alive(&self, wall: Wall) -> bool {
+        self.lives[wall.player()] > 0
+    }
+
+    /// Tira uma vida do jogador da parede
+    pub fn lose_life(&mut self, wall: Wall) {
+        let lives = &mut self.lives[wall.player()];
+        *lives = lives.saturating_sub(1);
+    }
+
+    /// Elimina o jogador da parede (ex: desistência)
+    pub fn eliminate(&mut self, wall: Wall) {
+        self.lives[wall.player()] = 0;
+    }
+
+    /// Retorna o vencedor quando só resta um jogador com vidas
+    pub fn winner(&self) -> Option<Wall> {
+        let mut alive = Wall::ALL.into_iter().filter(|wall| self.alive(*wall));
+        match (alive.next(), alive.next()) {
+            (Some(wall), None) => Some(wall),
+            _ => None,
+        }
+    }
+
+    /// Sorteia um jogador com vidas para receber o saque
+    ///
+    /// # Argumentos
+    ///
+    /// * `rng` - Gerador de números aleatórios da partida
+    pub fn random_target(&self, rng: &mut GameRng) -> Wall {
+        let alive: Vec<Wall> = Wall::ALL
+            .into_iter()
+            .filter(|wall| self.alive(*wall))
+            .collect();
+        alive.choose(rng).copied().unwrap_or(Wall::Left)
+    }
+
+    /// Rebate a bola nas raquetes de cima e de baixo
+    ///
+    /// # Retorna
+    ///
+    /// A parede do jogador que rebateu a bola, se ela foi rebatida
+    pub fn check_paddles(&self, ball: &mut Ball) -> Option<Wall> {
+        let half_size = ball.size / 2.0;
+
+        if self.alive(Wall::Top)
+            && ball.vel_y < 0.0
+            && self.paddle_top.check_collision(ball.x, ball.y, ball.size)
+        {
+            ball.bounce_off_horizontal_paddle();
+            ball.y = self.paddle_top.y + self.paddle_top.height + half_size;
+            return Some(Wall::Top);
+        }
+
+        if self.alive(Wall::Bottom)
+            && ball.vel_y > 0.0
+            && self
+                .paddle_bottom
+                .check_collision(ball.x, ball.y, ball.size)
+        {
+            ball.bounce_off_horizontal_paddle();
+            ball.y = self.paddle_bottom.y - half_size;
+            return Some(Wall::Bottom);
+        }
+
+        None
+    }
+
+    /// Rebate a bola nas paredes dos jogadores eliminados e verifica se ela
+    /// passou pela parede de um jogador que ainda está no jogo
+    ///
+    /// # Argumentos
+    ///
+    /// * `ball` - Bola do jogo
+    /// * `screen_width` - Largura da tela
+    /// * `screen_height` - Altura da tela
+    ///
+    /// # Retorna
+    ///
+    /// A parede por onde a bola saiu (o jogador dela perde uma vida)
+    pub fn check_walls(
+        &self,
+        ball: &mut Ball,
+        screen_width: f32,
+        screen_height: f32,
+    ) -> Option<Wall> {
+        // Paredes de jogadores eliminados rebatem a bola
+        for wall in Wall::ALL {
+            if !self.alive(wall) {
+                wall.bounce(ball, screen_width, screen_height);
+            }
+        }
+
+        // A bola saiu da tela pela parede de um jogador
+        if ball.x < 0.0 {
+            Some(Wall::Left)
+        } else if ball.x > screen_width {
+            Some(Wall::Right)
+        } else if ball.y < 0.0 {
+            Some(Wall::Top)
+        } else if ball.y > screen_height {
+            Some(Wall::Bottom)
+        } else {
+            None
+        }
+    }
+
+    /// Desenha as raquetes de cima e de baixo e as paredes dos eliminados
+    ///
+    /// # Argumentos
+    ///
+    /// * `canvas` - Canvas SDL para desenhar
+    /// * `theme` - Tema de cores
+    /// * `screen_width` - Largura da tela
+    /// * `screen_height` - Altura da tela
+    pub fn render(
+        &self,
+        canvas: &mut Canvas<Window>,
+        theme: &Theme,
+        screen_width: f32,
+        screen_height: f32,
+    ) {
+        for (wall, paddle) in [
+            (Wall::Top, &self.paddle_top),
+            (Wall::Bottom, &self.paddle_bottom),
+        ] {
+            if self.alive(wall) {
+                canvas.set_draw_color(theme.player_color(wall.player()));
+                canvas.fill_rect(paddle.get_rect()).unwrap();
+            }
+        }
+
+        canvas.set_draw_color(theme.center_line);
+        for wall in Wall::ALL {
+            if !self.alive(wall) {
+                canvas
+                    .fill_rect(wall.rect(screen_width, screen_height))
+                    .unwrap();
+            }
+        }
+    }
+
+    /// Altera o comprimento das raquetes horizontais
+    ///
+    /// # Argumentos
+    ///
+    /// * `length` - Comprimento em pixels
+    /// * `screen_width` - Largura da tela
+    pub fn set_paddle_length(&mut self, length: f32, screen_width: f32) {
+        self.paddle_top.set_width(length, screen_width);
+        self.paddle_bottom.set_width(length, screen_width);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn four_player(lives: u32) -> FourPlayer {
+        let side_paddle = Paddle::new(20.0, 250.0, 15.0, 100.0, 400.0);
+        FourPlayer::new(800.0, 600.0, &side_paddle, lives)
+    }
+
+    #[test]
+    fn losing_every_life_eliminates_the_player() {
+        let mut four_player = four_player(2);
+        four_player.lose_life(Wall::Top);
+        assert!(four_player.alive(Wall::Top));
+        four_player.lose_life(Wall::Top);
+        assert!(!four_player.alive(Wall::Top));
+
+        // Vidas não ficam negativas
+        four_player.lose_life(Wall::Top);
+        assert_eq!(four_player.lives, [2, 2, 0, 2]);
+    }
+
+    #[test]
+    fn match_ends_with_one_player_left() {
+        let mut four_player = four_player(1);
+        assert!(four_player.winner().is_none());
+
+        four_player.lose_life(Wall::Left);
+        four_player.eliminate(Wall::Bottom);
+        assert!(four_player.winner().is_none());
+
+        four_player.lose_life(Wall::Top);
+        assert!(four_player.winner() == Some(Wall::Right));
+
+        let mut rng = GameRng::from_state(7);
+        for _ in 0..20 {
+            assert!(four_player.random_target(&mut rng) == Wall::Right);
+        }
+    }
+
+    #[test]
+    fn eliminated_player_becomes_a_wall() {
+        let mut four_player = four_player(1);
+        let mut ball = Ball::new(-2.0, 300.0, 10.0, 300.0);
+        ball.vel_x = -300.0;
+
+        // Com o jogador no jogo, a bola passa e ele perde uma vida
+        assert!(four_player.check_walls(&mut ball, 800.0, 600.0) == Some(Wall::Left));
+
+        // Eliminado, a parede rebate a bola
+        four_player.eliminate(Wall::Left);
+        assert!(four_player.check_walls(&mut ball, 800.0, 600.0).is_none());
+        assert_eq!(ball.x, 5.0);
+        assert!(ball.vel_x > 0.0);
+    }
+
+    #[test]
+    fn eliminated_paddles_do_not_return_the_ball() {
+        let mut four_player = four_player(1);
+        let paddle = &four_player.paddle_top;
+        let mut ball = Ball::new(paddle.x + paddle.width / 2.0, paddle.y + 5.0, 10.0, 300.0);
+        ball.vel_y = -300.0;
+        let mut dead_ball = ball.clone();
+
+        assert!(four_player.check_paddles(&mut ball) == Some(Wall::Top));
+        assert!(ball.vel_y > 0.0);
+
+        four_player.eliminate(Wall::Top);
+        assert!(four_player.check_paddles(&mut dead_ball).is_none());
+    }
+}
@@ -1,23 +1,145 @@
 // Estado do jogo e lógica principal
 
+use super::ai::{CpuPlayer, Difficulty};
+use super::arcade::{Arcade, ArcadeMode};
+use super::arena::Arena;
 use super::ball::Ball;
+use super::breakout::{Breakout, BREAKOUT_PADDLE_OFFSET};
+use super::four_player::{FourPlayer, Wall};
+use super::handicap::Handicap;
 use super::paddle::Paddle;
-use sdl2::pixels::Color;
+use super::powerups::{self, PowerUpKind, PowerUps};
+use super::practice::{Practice, PracticeTarget};
+use super::random::GameRng;
+use super::rules::{MatchStatus, ServeRule, WinRule};
+use super::scoring::{MatchScore, MatchStats};
+use super::snapshot::{self, Fields, Snapshot};
+use super::theme::Theme;
+use crate::storage::kv;
+use rand::Rng;
 use sdl2::rect::Rect;
 use sdl2::render::Canvas;
 use sdl2::video::Window;
 
+/// Duração da contagem regressiva ao sair da pausa (em segundos)
+const RESUME_COUNTDOWN: f32 = 3.0;
+
+/// Duração da contagem regressiva antes de cada saque (em segundos)
+const SERVE_COUNTDOWN: f32 = 3.0;
+
+/// Tempo que a CPU espera antes de sacar no modo "pressionar para sacar" (em segundos)
+const CPU_SERVE_DELAY: f32 = 1.0;
+
+/// Distância padrão entre a parede e a raquete de cada jogador
+pub const PADDLE_OFFSET: f32 = 30.0;
+
+/// Velocidade normal das raquetes (pixels por segundo)
+const PADDLE_SPEED: f32 = 400.0;
+
+/// Espera entre duas bolas do lançador no modo treino (em segundos)
+const LAUNCH_DELAY: f32 = 0.8;
+
+/// Distância entre a raquete e a bola enquanto o jogador segura o saque
+const SERVE_GAP: f32 = 6.0;
+
+/// Distância entre a parede e a raquete da frente no modo de duplas
+const DOUBLES_FRONT_OFFSET: f32 = 180.0;
+
+/// Rebatidas na mesma troca de bola para uma bola extra entrar em jogo (modo multibola)
+const MULTIBALL_RALLY_HITS: u32 = 6;
+
+/// Número máximo de bolas ao mesmo tempo na quadra
+const MAX_BALLS: usize = 8;
+
 /// Estados possíveis do jogo
 #[derive(PartialEq, Clone, Copy)]
 pub enum GamePhase {
     /// Tela inicial com instruções
     Menu,
+    /// Bola parada esperando o saque
+    Serving,
     /// Jogo em andamento
     Playing,
     /// Jogo pausado
     Paused,
+    /// Contagem regressiva antes de continuar após a pausa
+    Resuming,
     /// Fim de jogo (alguém ganhou)
     GameOver,
+    /// Editor de arenas aberto (sem partida)
+    Editing,
+}
+
+impl GamePhase {
+    /// Todas as fases
+    pub const ALL: [GamePhase; 7] = [
+        GamePhase::Menu,
+        GamePhase::Serving,
+        GamePhase::Playing,
+        GamePhase::Paused,
+        GamePhase::Resuming,
+        GamePhase::GameOver,
+        GamePhase::Editing,
+    ];
+
+    /// Identificador usado nos jogos salvos
+    pub fn id(self) -> &'static str {
+        match self {
+            GamePhase::Menu => "menu",
+            GamePhase::Serving => "serving",
+            GamePhase::Playing => "playing",
+            GamePhase::Paused => "paused",
+            GamePhase::Resuming => "resuming",
+            GamePhase::GameOver => "game_over",
+            GamePhase::Editing => "editing",
+        }
+    }
+}
+
+/// Lados da quadra (cada jogador defende um lado)
+#[derive(PartialEq, Clone, Copy)]
+pub enum Side {
+    /// Jogador 1
+    Left,
+    /// Jogador 2
+    Right,
+}
+
+impl Side {
+    /// Os dois lados
+    pub const ALL: [Side; 2] = [Side::Left, Side::Right];
+
+    /// Identificador usado nos jogos salvos
+    pub fn id(self) -> &'static str {
+        match self {
+            Side::Left => "left",
+            Side::Right => "right",
+        }
+    }
+
+    /// Retorna o lado do adversário
+    pub fn opponent(self) -> Side {
+        match self {
+            Side::Left => Side::Right,
+            Side::Right => Side::Left,
+        }
+    }
+
+    /// Direção horizontal que vai deste lado para o adversário
+    pub fn direction(self) -> f32 {
+        match self {
+            Side::Left => 1.0,
+            Side::Right => -1.0,
+        }
+    }
+
+    /// Índice do jogador que defende o lado (0 ou 1)
+    pub fn player(self) -> usize {
+        match self {
+            Side::Left => 0,
+            Side::Right => 1,
+        }
+    }
 }
 
 /// Estrutura principal que gerencia todo o estado do jogo
@@ -26,22 +148,79 @@ pub struct GameState {
     pub screen_width: f32,
     /// Altura da tela
     pub screen_height: f32,
-    /// Cor de fundo da tela
-    pub background_color: Color,
+    /// Tema de cores usado para desenhar o jogo
+    pub theme: Theme,
     /// Fase atual do jogo
     pub phase: GamePhase,
-    /// Bola do jogo
-    pub ball: Ball,
+    /// Bolas em jogo (sempre há pelo menos uma; a primeira é a bola do saque)
+    pub balls: Vec<Ball>,
+    /// Bolas lançadas em cada saque (mais de uma ativa o modo multibola)
+    pub ball_count: u32,
     /// Raquete do jogador 1 (esquerda)
     pub paddle_left: Paddle,
     /// Raquete do jogador 2 (direita)
     pub paddle_right: Paddle,
-    /// Pontuação do jogador 1
+    /// Raquetes extras do time da esquerda (à frente da raquete do jogador 1;
+    /// vazio fora do modo de duplas)
+    pub partners_left: Vec<Paddle>,
+    /// Raquetes extras do time da direita (à frente da raquete do jogador 2)
+    pub partners_right: Vec<Paddle>,
+    /// CPU que controla a raquete direita (`None` quando são dois jogadores)
+    pub cpu: Option<CpuPlayer>,
+    /// Vantagens dos jogadores 1 e 2 (tamanho e velocidade da raquete, pontos
+    /// iniciais e ajuda da CPU)
+    pub handicaps: [Handicap; 2],
+    /// CPU que ajuda a mover a raquete dos jogadores 1 e 2 (vantagem "ajuda da CPU")
+    assists: [Option<CpuPlayer>; 2],
+    /// Altura configurada das raquetes (antes das vantagens e power-ups)
+    paddle_height: f32,
+    /// Raquetes de cima e de baixo e vidas (`Some` somente no modo de 4 jogadores)
+    pub four_player: Option<FourPlayer>,
+    /// Vidas de cada jogador no modo de 4 jogadores
+    pub lives: u32,
+    /// Parede ou lançador e sequências de rebatidas (`Some` somente no modo treino)
+    pub practice: Option<Practice>,
+    /// Tempo, rebatidas e pontuação dos modos arcade (`Some` somente na
+    /// sobrevivência e no modo contra o relógio)
+    pub arcade: Option<Arcade>,
+    /// Paredes de tijolos atrás das raquetes (`Some` somente no modo breakout)
+    pub breakout: Option<Breakout>,
+    /// Power-ups na quadra e escudos ativos
+    pub power_ups: PowerUps,
+    /// Arena com os obstáculos da quadra
+    pub arena: Arena,
+    /// Pontos do jogador 1 no game atual
     pub score_left: u32,
-    /// Pontuação do jogador 2
+    /// Pontos do jogador 2 no game atual
     pub score_right: u32,
-    /// Pontuação máxima para vencer
+    /// Placar de sets e games
+    pub match_score: MatchScore,
+    /// Estatísticas da partida (para o resumo final)
+    pub stats: MatchStats,
+    /// Pontuação alvo para vencer um game
     pub max_score: u32,
+    /// Regra de vitória da partida
+    pub win_rule: WinRule,
+    /// Duração da partida com tempo (em segundos)
+    pub match_time: f32,
+    /// Tempo restante da partida com tempo (em segundos)
+    pub time_left: f32,
+    /// Vencedor da partida (definido quando a partida termina)
+    pub winner: Option<Side>,
+    /// Indica que a partida terminou por desistência
+    pub forfeited: bool,
+    /// Tempo restante da contagem regressiva (em segundos)
+    pub countdown: f32,
+    /// Jogador que saca (ou vai sacar) a bola
+    pub server: Side,
+    /// Regra que define quem saca depois de cada ponto
+    pub serve_rule: ServeRule,
+    /// Se verdadeiro, quem saca lança a bola quando quiser; senão, o saque é automático
+    pub press_to_serve: bool,
+    /// Fase em que a partida estava quando foi pausada
+    paused_phase: GamePhase,
+    /// Gerador de números aleatórios da partida
+    rng: GameRng,
 }
 
 impl GameState {
@@ -55,12 +234,11 @@ impl GameState {
         // Configurações das raquetes
         let paddle_width = 15.0;
         let paddle_height = 80.0;
-        let paddle_offset = 30.0;
-        let paddle_speed = 400.0;
+        let paddle_speed = PADDLE_SPEED;
         
         // Cria raquete esquerda (jogador 1)
         let paddle_left = Paddle::new(
-            paddle_offset,
+            PADDLE_OFFSET,
             (screen_height - paddle_height) / 2.0,
             paddle_width,
             paddle_height,
@@ -69,7 +247,7 @@ impl GameState {
         
         // Cria raquete direita (jogador 2)
         let paddle_right = Paddle::new(
-            screen_width - paddle_offset - paddle_width,
+            screen_width - PADDLE_OFFSET - paddle_width,
             (screen_height - paddle_height) / 2.0,
             paddle_width,
             paddle_height,
@@ -87,77 +265,1261 @@ impl GameState {
         GameState {
             screen_width,
             screen_height,
-            background_color: Color::RGB(20, 20, 30),
+            theme: Theme::classic(),
             phase: GamePhase::Menu,
-            ball,
+            balls: vec![ball],
+            ball_count: 1,
             paddle_left,
             paddle_right,
+            partners_left: Vec::new(),
+            partners_right: Vec::new(),
+            cpu: None,
+            handicaps: [Handicap::default(); 2],
+            assists: [None, None],
+            paddle_height,
+            four_player: None,
+            lives: 3,
+            practice: None,
+            arcade: None,
+            breakout: None,
+            power_ups: PowerUps::new(),
+            arena: Arena::classic(),
             score_left: 0,
             score_right: 0,
+            match_score: MatchScore::new(1, 1),
+            stats: MatchStats::default(),
             max_score: 5,
+            win_rule: WinRule::FirstTo,
+            match_time: 180.0,
+            time_left: 180.0,
+            winner: None,
+            forfeited: false,
+            countdown: 0.0,
+            server: Side::Left,
+            serve_rule: ServeRule::LoserServes,
+            press_to_serve: false,
+            paused_phase: GamePhase::Playing,
+            rng: GameRng::from_entropy(),
+        }
+    }
+
+    /// Altera a pontuação necessária para vencer
+    ///
+    /// # Argumentos
+    ///
+    /// * `max_score` - Pontos para vencer
+    pub fn set_max_score(&mut self, max_score: u32) {
+        self.max_score = max_score;
+    }
+
+    /// Altera a regra de vitória
+    ///
+    /// # Argumentos
+    ///
+    /// * `rule` - Regra de vitória
+    /// * `match_time` - Duração da partida com tempo (em segundos)
+    pub fn set_win_rule(&mut self, rule: WinRule, match_time: f32) {
+        self.win_rule = rule;
+        self.match_time = match_time;
+    }
+
+    /// Altera o formato da partida em sets e games
+    ///
+    /// # Argumentos
+    ///
+    /// * `best_of_sets` - Número máximo de sets (melhor de N)
+    /// * `games_per_set` - Games necessários para vencer um set
+    pub fn set_match_format(&mut self, best_of_sets: u32, games_per_set: u32) {
+        self.match_score.best_of_sets = best_of_sets;
+        self.match_score.games_per_set = games_per_set;
+    }
+
+    /// Altera as vidas de cada jogador no modo de 4 jogadores
+    ///
+    /// # Argumentos
+    ///
+    /// * `lives` - Vidas por jogador
+    pub fn set_lives(&mut self, lives: u32) {
+        self.lives = lives;
+    }
+
+    /// Altera a velocidade inicial da bola
+    ///
+    /// # Argumentos
+    ///
+    /// * `speed` - Velocidade em pixels por segundo
+    pub fn set_ball_speed(&mut self, speed: f32) {
+        for ball in &mut self.balls {
+            ball.set_base_speed(speed);
+        }
+    }
+
+    /// Escolhe os power-ups que podem aparecer na quadra
+    ///
+    /// # Argumentos
+    ///
+    /// * `kinds` - Power-ups habilitados (vazio desliga os power-ups)
+    pub fn set_power_ups(&mut self, kinds: Vec<PowerUpKind>) {
+        self.power_ups.enabled = kinds;
+    }
+
+    /// Escolhe a arena (obstáculos da quadra)
+    ///
+    /// # Argumentos
+    ///
+    /// * `arena` - Arena das próximas partidas
+    pub fn set_arena(&mut self, arena: Arena) {
+        self.arena = arena;
+        self.arena.fit(self.screen_width, self.screen_height);
+        self.arena.reset();
+        self.place_paddles();
+    }
+
+    /// Coloca as raquetes laterais na posição definida pela arena
+    fn place_paddles(&mut self) {
+        self.paddle_left.x = self.arena.lane_left.unwrap_or(PADDLE_OFFSET);
+        self.paddle_right.x = self.screen_width
+            - self.arena.lane_right.unwrap_or(PADDLE_OFFSET)
+            - self.paddle_right.width;
+    }
+
+    /// Altera o número de bolas lançadas em cada saque
+    ///
+    /// Com mais de uma bola, bolas extras também entram em jogo durante
+    /// trocas de bola longas.
+    ///
+    /// # Argumentos
+    ///
+    /// * `count` - Número de bolas
+    pub fn set_ball_count(&mut self, count: u32) {
+        self.ball_count = count;
+    }
+
+    /// Altera o tamanho das raquetes (altura das laterais e largura das
+    /// raquetes de cima e de baixo)
+    ///
+    /// # Argumentos
+    ///
+    /// * `height` - Tamanho em pixels
+    pub fn set_paddle_height(&mut self, height: f32) {
+        self.paddle_height = height;
+        self.apply_handicaps();
+    }
+
+    /// Altera as vantagens dos jogadores 1 e 2
+    ///
+    /// # Argumentos
+    ///
+    /// * `handicaps` - Vantagens de cada jogador
+    pub fn set_handicaps(&mut self, handicaps: [Handicap; 2]) {
+        self.handicaps = handicaps;
+        self.apply_handicaps();
+    }
+
+    /// Aplica o tamanho configurado e as vantagens às raquetes (nas duplas, às
+    /// raquetes do time)
+    ///
+    /// Os modos arcade ignoram as vantagens, para que os resultados do ranking
+    /// sejam comparáveis.
+    fn apply_handicaps(&mut self) {
+        let handicaps = if self.arcade.is_some() {
+            [Handicap::default(); 2]
+        } else {
+            self.handicaps
+        };
+        let (height, screen_height) = (self.paddle_height, self.screen_height);
+
+        for (player, handicap) in handicaps.iter().enumerate() {
+            for paddle in self.player_paddles_mut(player) {
+                paddle.set_height(height * handicap.size_scale(), screen_height);
+                paddle.speed = PADDLE_SPEED * handicap.speed_scale();
+            }
+            self.assists[player] = handicap.ai_assist.then(|| CpuPlayer::new(Difficulty::Easy));
+        }
+
+        if let Some(four_player) = &mut self.four_player {
+            four_player.set_paddle_length(height, self.screen_width);
+        }
+    }
+
+    /// Inicia uma nova partida
+    ///
+    /// # Argumentos
+    ///
+    /// * `cpu` - CPU que controla a raquete direita, ou `None` para dois jogadores
+    pub fn start_match(&mut self, cpu: Option<CpuPlayer>) {
+        self.match_score.reset();
+        self.stats = MatchStats::default();
+        self.winner = None;
+        self.forfeited = false;
+        self.cpu = cpu;
+        self.four_player = None;
+        self.practice = None;
+        self.arcade = None;
+        self.breakout = None;
+        self.partners_left.clear();
+        self.partners_right.clear();
+        self.place_paddles();
+        self.apply_handicaps();
+        self.reset_scores();
+        self.time_left = self.match_time;
+
+        // Nenhum efeito de power-up passa de uma partida para outra
+        self.power_ups.clear();
+        self.arena.reset();
+        let (screen_width, screen_height) = (self.screen_width, self.screen_height);
+        for paddle in self.paddles_mut() {
+            paddle.clear_effects(screen_width, screen_height);
+        }
+
+        // O primeiro saque da partida é sorteado
+        let server = if self.rng.random_bool(0.5) {
+            Side::Left
+        } else {
+            Side::Right
+        };
+        self.start_serve(server);
+    }
+
+    /// Inicia uma partida de 4 jogadores (cada um com `lives` vidas)
+    pub fn start_four_player(&mut self) {
+        self.start_match(None);
+
+        // As raquetes de cima e de baixo não têm as vantagens do jogador 1
+        let model = Paddle::new(
+            self.paddle_left.x,
+            0.0,
+            self.paddle_left.width,
+            self.paddle_height,
+            PADDLE_SPEED,
+        );
+        self.four_player = Some(FourPlayer::new(
+            self.screen_width,
+            self.screen_height,
+            &model,
+            self.lives,
+        ));
+    }
+
+    /// Inicia uma partida de duplas (2 contra 2)
+    ///
+    /// Cada time tem a raquete de trás, junto à parede, e uma raquete da
+    /// frente, mais perto do meio da quadra, controlada por outro jogador.
+    pub fn start_doubles(&mut self) {
+        self.start_match(None);
+
+        // A raquete da frente tem o tamanho e a velocidade da raquete de trás
+        // do mesmo time (com as vantagens do time)
+        let (left, right) = (&self.paddle_left, &self.paddle_right);
+        self.partners_left.push(Paddle::new(
+            DOUBLES_FRONT_OFFSET,
+            (self.screen_height - left.height) / 2.0,
+            left.width,
+            left.height,
+            left.speed,
+        ));
+        self.partners_right.push(Paddle::new(
+            self.screen_width - DOUBLES_FRONT_OFFSET - right.width,
+            (self.screen_height - right.height) / 2.0,
+            right.width,
+            right.height,
+            right.speed,
+        ));
+    }
+
+    /// Inicia um treino: o jogador 1 sozinho contra uma parede ou um lançador
+    ///
+    /// # Argumentos
+    ///
+    /// * `target` - Parede ou padrão do lançador
+    pub fn start_practice(&mut self, target: PracticeTarget) {
+        self.start_match(None);
+        let practice = Practice::new(target);
+        let server = if practice.launches_balls() {
+            Side::Right
+        } else {
+            Side::Left
+        };
+        self.practice = Some(practice);
+        self.start_serve(server);
+    }
+
+    /// Inicia uma partida de arcade
+    ///
+    /// Na sobrevivência o jogador 1 joga sozinho contra uma parede; no modo
+    /// contra o relógio, contra a CPU normal (a mesma para todos os resultados
+    /// do ranking).
+    ///
+    /// # Argumentos
+    ///
+    /// * `mode` - Sobrevivência ou contra o relógio
+    pub fn start_arcade(&mut self, mode: ArcadeMode) {
+        let arcade = Arcade::new(mode);
+        if arcade.uses_cpu() {
+            self.start_match(Some(CpuPlayer::new(Difficulty::Normal)));
+        } else {
+            self.start_match(None);
+            self.start_serve(Side::Left);
+        }
+        self.arcade = Some(arcade);
+        self.apply_handicaps();
+    }
+
+    /// Inicia uma partida de breakout: cada jogador defende uma parede de
+    /// tijolos e perde quando a bola passa por ela
+    pub fn start_breakout(&mut self) {
+        self.start_match(None);
+        self.breakout = Some(Breakout::new(self.screen_width, self.screen_height));
+
+        // As raquetes ficam à frente dos tijolos
+        self.paddle_left.x = BREAKOUT_PADDLE_OFFSET;
+        self.paddle_right.x = self.screen_width - BREAKOUT_PADDLE_OFFSET - self.paddle_right.width;
+    }
+
+    /// Indica se a partida é de duplas
+    pub fn is_doubles(&self) -> bool {
+        !self.partners_left.is_empty() || !self.partners_right.is_empty()
+    }
+
+    /// Número de jogadores da partida atual
+    pub fn players(&self) -> usize {
+        if self.four_player.is_some() {
+            4
+        } else if self.practice.is_some()
+            || self
+                .arcade
+                .as_ref()
+                .is_some_and(|arcade| !arcade.uses_cpu())
+        {
+            // Treino e sobrevivência: um jogador sozinho
+            1
+        } else {
+            2 + self.partners_left.len() + self.partners_right.len()
+        }
+    }
+
+    /// Reinicia a partida atual (mesmo adversário, placar zerado)
+    pub fn restart_match(&mut self) {
+        if let Some(practice) = &self.practice {
+            self.start_practice(practice.target);
+        } else if let Some(arcade) = &self.arcade {
+            self.start_arcade(arcade.mode);
+        } else if self.breakout.is_some() {
+            self.start_breakout();
+        } else if self.four_player.is_some() {
+            self.start_four_player();
+        } else if self.is_doubles() {
+            self.start_doubles();
+        } else {
+            let cpu = self.cpu.take();
+            self.start_match(cpu);
+        }
+    }
+
+    /// Pausa a partida
+    pub fn pause(&mut self) {
+        match self.phase {
+            GamePhase::Serving => self.paused_phase = GamePhase::Serving,
+            GamePhase::Playing | GamePhase::Resuming => self.paused_phase = GamePhase::Playing,
+            _ => return,
+        }
+        self.phase = GamePhase::Paused;
+    }
+
+    /// Sai da pausa com uma contagem regressiva para os jogadores se prepararem
+    ///
+    /// Se a partida foi pausada antes do saque, o saque recomeça (ele já tem
+    /// a sua própria contagem regressiva).
+    pub fn resume(&mut self) {
+        if self.phase != GamePhase::Paused {
+            return;
+        }
+
+        if self.paused_phase == GamePhase::Serving {
+            self.start_serve(self.server);
+        } else {
+            self.countdown = RESUME_COUNTDOWN;
+            self.phase = GamePhase::Resuming;
+        }
+    }
+
+    /// Registra a desistência de um jogador
+    ///
+    /// Com dois jogadores, a vitória vai para o adversário (nas duplas, para
+    /// o time adversário). No modo de 4
+    /// jogadores, quem desistiu é eliminado e a partida continua até sobrar
+    /// um jogador.
+    ///
+    /// # Argumentos
+    ///
+    /// * `player` - Índice do jogador que desistiu (0 a 3)
+    pub fn forfeit(&mut self, player: usize) {
+        // Desistir do treino ou do arcade só encerra a partida (não há vencedor)
+        if self.practice.is_some() || self.arcade.is_some() {
+            self.forfeited = true;
+            self.phase = GamePhase::GameOver;
+            return;
+        }
+
+        if let Some(four_player) = &mut self.four_player {
+            if let Some(wall) = Wall::from_player(player) {
+                four_player.eliminate(wall);
+            }
+            if four_player.winner().is_some() {
+                self.forfeited = true;
+                self.phase = GamePhase::GameOver;
+            } else {
+                self.resume();
+            }
+            return;
         }
+
+        self.winner = Some(Self::player_side(player).opponent());
+        self.forfeited = true;
+        self.phase = GamePhase::GameOver;
+    }
+
+    /// Retorna o índice do jogador que venceu a partida (0 a 3)
+    pub fn winner_player(&self) -> Option<usize> {
+        match &self.four_player {
+            Some(four_player) => four_player.winner().map(Wall::player),
+            None => self.winner.map(Side::player),
+        }
+    }
+
+    /// Indica se o jogador ainda está na partida (sempre verdadeiro fora do
+    /// modo de 4 jogadores)
+    ///
+    /// # Argumentos
+    ///
+    /// * `player` - Índice do jogador (0 a 3)
+    pub fn player_active(&self, player: usize) -> bool {
+        match (&self.four_player, Wall::from_player(player)) {
+            (Some(four_player), Some(wall)) => four_player.alive(wall),
+            (None, _) => player < self.players(),
+            _ => false,
+        }
+    }
+
+    /// Time do jogador fora do modo de 4 jogadores (nas duplas, os jogadores
+    /// 1 e 3 formam o time da esquerda e os jogadores 2 e 4 o da direita)
+    fn player_side(player: usize) -> Side {
+        if player.is_multiple_of(2) {
+            Side::Left
+        } else {
+            Side::Right
+        }
+    }
+
+    /// Indica se a raquete do jogador se move na vertical (as raquetes de cima
+    /// e de baixo do modo de 4 jogadores se movem na horizontal)
+    pub fn paddle_vertical(&self, player: usize) -> bool {
+        self.four_player.is_none() || player < 2
     }
 
-    /// Inicia uma nova rodada
-    pub fn start_round(&mut self) {
-        self.ball.reset(self.screen_width, self.screen_height);
-        self.ball.launch();
+    /// Todas as raquetes da partida
+    fn paddles_mut(&mut self) -> Vec<&mut Paddle> {
+        let mut paddles = vec![&mut self.paddle_left, &mut self.paddle_right];
+        paddles.extend(self.partners_left.iter_mut());
+        paddles.extend(self.partners_right.iter_mut());
+        if let Some(four_player) = &mut self.four_player {
+            paddles.push(&mut four_player.paddle_top);
+            paddles.push(&mut four_player.paddle_bottom);
+        }
+        paddles
+    }
+
+    /// Raquetes controladas por um jogador (nas duplas, as raquetes do time)
+    ///
+    /// # Argumentos
+    ///
+    /// * `player` - Índice do jogador (0 a 3)
+    fn player_paddles_mut(&mut self, player: usize) -> Vec<&mut Paddle> {
+        match (&mut self.four_player, player) {
+            (Some(four_player), 2) => vec![&mut four_player.paddle_top],
+            (Some(four_player), 3) => vec![&mut four_player.paddle_bottom],
+            (_, 0) => std::iter::once(&mut self.paddle_left)
+                .chain(self.partners_left.iter_mut())
+                .collect(),
+            (_, 1) => std::iter::once(&mut self.paddle_right)
+                .chain(self.partners_right.iter_mut())
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Raquetes de um time que ainda estão em jogo (a raquete principal e as
+    /// raquetes extras das duplas)
+    fn team_paddles(&self, side: Side) -> impl Iterator<Item = &Paddle> {
+        let (paddle, partners, player) = match side {
+            Side::Left => (&self.paddle_left, &self.partners_left, 0),
+            Side::Right => (&self.paddle_right, &self.partners_right, 1),
+        };
+        let active = self.player_active(player);
+        std::iter::once(paddle)
+            .filter(move |_| active)
+            .chain(partners)
+    }
+
+    /// Move a raquete de um jogador
+    ///
+    /// # Argumentos
+    ///
+    /// * `player` - Índice do jogador (0 a 3)
+    /// * `direction` - De -1.0 (cima/esquerda) a 1.0 (baixo/direita)
+    /// * `delta_time` - Tempo decorrido desde o último frame (em segundos)
+    pub fn move_player(&mut self, player: usize, direction: f32, delta_time: f32) {
+        if direction == 0.0 || !self.player_active(player) {
+            return;
+        }
+
+        // Controles analógicos movem a raquete mais devagar quando pouco inclinados
+        let scaled_time = delta_time * direction.abs().min(1.0);
+        let screen_width = self.screen_width;
+        let screen_height = self.screen_height;
+
+        let vertical = self.paddle_vertical(player);
+
+        let paddle = match (player, &mut self.four_player) {
+            (0, _) => &mut self.paddle_left,
+            // A raquete direita não é controlada pelo jogador contra a CPU
+            (1, _) if self.cpu.is_none() => &mut self.paddle_right,
+            (1, _) => return,
+            (2, Some(four_player)) => &mut four_player.paddle_top,
+            (3, Some(four_player)) => &mut four_player.paddle_bottom,
+            // Nas duplas, os jogadores 3 em diante controlam as raquetes da frente
+            (_, None) => {
+                let partners = match Self::player_side(player) {
+                    Side::Left => &mut self.partners_left,
+                    Side::Right => &mut self.partners_right,
+                };
+                match partners.get_mut(player / 2 - 1) {
+                    Some(paddle) => paddle,
+                    None => return,
+                }
+            }
+            _ => return,
+        };
+
+        match (vertical, direction < 0.0) {
+            (true, true) => paddle.move_up(scaled_time),
+            (true, false) => paddle.move_down(scaled_time, screen_height),
+            (false, true) => paddle.move_left(scaled_time),
+            (false, false) => paddle.move_right(scaled_time, screen_width),
+        }
+    }
+
+    /// Prepara o saque de um jogador
+    ///
+    /// A bola fica parada no centro durante a contagem regressiva ou, no modo
+    /// "pressionar para sacar", presa à frente da raquete de quem saca.
+    ///
+    /// # Argumentos
+    ///
+    /// * `server` - Lado do jogador que vai sacar
+    pub fn start_serve(&mut self, server: Side) {
+        self.server = server;
+        self.clear_balls();
+        // No modo "pressionar para sacar" a contagem só é usada pela CPU
+        self.countdown = if self.launcher_serving() {
+            LAUNCH_DELAY
+        } else if self.manual_serve() {
+            CPU_SERVE_DELAY
+        } else {
+            SERVE_COUNTDOWN
+        };
+        self.phase = GamePhase::Serving;
+    }
+
+    /// Indica se o saque é feito com uma tecla (no modo de 4 jogadores o saque
+    /// é sempre automático, do centro da quadra)
+    fn manual_serve(&self) -> bool {
+        self.press_to_serve && self.four_player.is_none() && !self.launcher_serving()
+    }
+
+    /// Indica se a próxima bola sai do lançador do modo treino
+    fn launcher_serving(&self) -> bool {
+        self.server == Side::Right
+            && self
+                .practice
+                .as_ref()
+                .is_some_and(|practice| practice.launches_balls())
+    }
+
+    /// Indica se quem saca lança a bola pressionando uma tecla
+    pub fn waiting_for_serve(&self) -> bool {
+        self.phase == GamePhase::Serving && self.manual_serve() && !self.cpu_serving()
+    }
+
+    /// Lança a bola quando o jogador que saca pede (modo "pressionar para sacar")
+    ///
+    /// # Argumentos
+    ///
+    /// * `side` - Lado do jogador que pressionou a tecla de saque
+    pub fn request_serve(&mut self, side: Side) {
+        if self.waiting_for_serve() && side == self.server {
+            self.serve();
+        }
+    }
+
+    /// Indica se o saque é da CPU
+    fn cpu_serving(&self) -> bool {
+        self.server == Side::Right && self.cpu.is_some()
+    }
+
+    /// Lança a bola em direção ao adversário de quem saca (ou, no modo de 4
+    /// jogadores, a um jogador sorteado)
+    ///
+    /// No modo multibola, as bolas extras saem do centro junto com a bola do saque.
+    fn serve(&mut self) {
+        self.countdown = 0.0;
+        match (&self.four_player, &mut self.practice) {
+            (Some(four_player), _) => {
+                let angle = four_player
+                    .random_target(&mut self.rng)
+                    .serve_angle(&mut self.rng);
+                self.balls[0].launch_at(angle);
+            }
+            // No treino com lançador, a bola sai do lado direito sem dono
+            (None, Some(practice)) if self.server == Side::Right => {
+                practice.launch(
+                    &mut self.balls[0],
+                    self.screen_width,
+                    self.screen_height,
+                    &mut self.rng,
+                );
+            }
+            _ => {
+                // A bola do saque já vale como rebatida por quem sacou (para os power-ups)
+                self.balls[0].launch(self.server.direction(), &mut self.rng);
+                self.balls[0].owner = Some(self.server.player());
+            }
+        }
+        for _ in 1..self.ball_count {
+            self.spawn_ball();
+        }
         self.phase = GamePhase::Playing;
     }
 
+    /// Lança uma bola extra do centro da quadra (ou de um ponto de lançamento
+    /// da arena) para um lado sorteado
+    fn spawn_ball(&mut self) {
+        if self.balls.len() >= MAX_BALLS {
+            return;
+        }
+
+        let mut ball = self.balls[0].clone();
+        self.reset_ball(&mut ball);
+        match &self.four_player {
+            Some(four_player) => {
+                let angle = four_player
+                    .random_target(&mut self.rng)
+                    .serve_angle(&mut self.rng);
+                ball.launch_at(angle);
+            }
+            None => {
+                let side = if self.rng.random_bool(0.5) {
+                    Side::Left
+                } else {
+                    Side::Right
+                };
+                ball.launch(side.direction(), &mut self.rng);
+            }
+        }
+        self.balls.push(ball);
+    }
+
+    /// Deixa só a bola do saque, parada no centro da quadra (ou em um ponto de
+    /// lançamento da arena)
+    fn clear_balls(&mut self) {
+        self.balls.truncate(1);
+        let mut ball = self.balls[0].clone();
+        self.reset_ball(&mut ball);
+        self.balls[0] = ball;
+    }
+
+    /// Para a bola e a coloca no centro da quadra ou em um ponto de lançamento
+    /// sorteado da arena
+    fn reset_ball(&mut self, ball: &mut Ball) {
+        ball.reset(self.screen_width, self.screen_height);
+        if let Some((x, y)) = self.arena.random_spawn(&mut self.rng) {
+            ball.x = x;
+            ball.y = y;
+        }
+    }
+
+    /// Tira de jogo uma bola que saiu da quadra
+    ///
+    /// A última bola fica na quadra para o próximo saque.
+    ///
+    /// # Retorna
+    ///
+    /// `true` se era a última bola (a troca de bola terminou)
+    fn take_ball(&mut self, index: usize) -> bool {
+        if self.balls.len() > 1 {
+            self.balls.remove(index);
+            false
+        } else {
+            true
+        }
+    }
+
+    /// Mantém a bola à frente da raquete de quem vai sacar
+    fn hold_ball(&mut self) {
+        let ball = &mut self.balls[0];
+        let offset = ball.size / 2.0 + SERVE_GAP;
+        let (paddle, x) = match self.server {
+            Side::Left => (
+                &self.paddle_left,
+                self.paddle_left.x + self.paddle_left.width + offset,
+            ),
+            Side::Right => (&self.paddle_right, self.paddle_right.x - offset),
+        };
+        ball.x = x;
+        ball.y = paddle.y + paddle.height / 2.0;
+    }
+
+    /// Marca um ponto e prepara o próximo saque (ou encerra a partida)
+    ///
+    /// # Argumentos
+    ///
+    /// * `scorer` - Lado do jogador que marcou o ponto
+    /// * `rally_over` - Se a bola que marcou o ponto era a última em jogo (no
+    ///   modo multibola, o jogo segue com as outras bolas)
+    fn score_point(&mut self, scorer: Side, rally_over: bool) {
+        match scorer {
+            Side::Left => self.score_left += 1,
+            Side::Right => self.score_right += 1,
+        }
+        self.stats.record_point(scorer);
+
+        if !self.check_game_won() && rally_over {
+            self.start_serve(self.next_server(scorer));
+        }
+    }
+
+    /// Conta uma bola que saiu da quadra no treino e prepara a próxima
+    ///
+    /// # Argumentos
+    ///
+    /// * `scorer` - Lado para o qual a bola saiu (`Right` quando o jogador a perdeu)
+    /// * `rally_over` - Se era a última bola em jogo
+    fn end_practice_rally(&mut self, scorer: Side, rally_over: bool) {
+        let Some(practice) = &mut self.practice else {
+            return;
+        };
+
+        // Bolas devolvidas para o lançador não interrompem a sequência
+        if scorer == Side::Right {
+            practice.record_miss();
+        }
+        if !rally_over {
+            return;
+        }
+
+        let server = if practice.launches_balls() {
+            Side::Right
+        } else {
+            Side::Left
+        };
+        self.stats.end_rally();
+        self.start_serve(server);
+    }
+
+    /// Conta uma bola que saiu da quadra no arcade
+    ///
+    /// Na sobrevivência, a bola perdida sai de jogo e a partida termina quando
+    /// não sobra nenhuma; contra o relógio, o ponto entra na pontuação e a
+    /// partida segue até o tempo acabar.
+    ///
+    /// # Argumentos
+    ///
+    /// * `scorer` - Lado do jogador que marcou o ponto
+    /// * `rally_over` - Se era a última bola em jogo
+    fn end_arcade_rally(&mut self, scorer: Side, rally_over: bool) {
+        let Some(arcade) = &mut self.arcade else {
+            return;
+        };
+
+        if arcade.mode == ArcadeMode::Survival {
+            if rally_over {
+                self.phase = GamePhase::GameOver;
+            }
+            return;
+        }
+
+        arcade.record_point(scorer, self.stats.rally);
+        match scorer {
+            Side::Left => self.score_left += 1,
+            Side::Right => self.score_right += 1,
+        }
+        self.stats.record_point(scorer);
+        if rally_over {
+            self.start_serve(self.next_server(scorer));
+        }
+    }
+
+    /// Retorna quem saca depois de um ponto (ou game), de acordo com a regra de saque
+    ///
+    /// # Argumentos
+    ///
+    /// * `scorer` - Lado do jogador que venceu o ponto
+    fn next_server(&self, scorer: Side) -> Side {
+        match self.serve_rule {
+            ServeRule::LoserServes => scorer.opponent(),
+            ServeRule::Alternate => self.server.opponent(),
+            ServeRule::WinnerServes => scorer,
+        }
+    }
+
     /// Atualiza o estado do jogo
     /// 
     /// # Argumentos
     /// 
     /// * `delta_time` - Tempo decorrido desde o último frame (em segundos)
     pub fn update(&mut self, delta_time: f32) {
+        // Contagem regressiva após a pausa
+        if self.phase == GamePhase::Resuming {
+            self.countdown -= delta_time;
+            if self.countdown <= 0.0 {
+                self.countdown = 0.0;
+                self.phase = GamePhase::Playing;
+            }
+            return;
+        }
+
+        // Espera o saque: contagem regressiva ou tecla de quem saca
+        if self.phase == GamePhase::Serving {
+            if self.manual_serve() {
+                self.hold_ball();
+            }
+            if !self.waiting_for_serve() {
+                self.countdown -= delta_time;
+                if self.countdown <= 0.0 {
+                    self.serve();
+                }
+            }
+            return;
+        }
+
         if self.phase != GamePhase::Playing {
             return;
         }
 
-        // Atualiza posição da bola
-        self.ball.update(delta_time);
+        // Move a raquete da CPU (se houver)
+        if let Some(cpu) = &mut self.cpu {
+            cpu.update(
+                &mut self.paddle_right,
+                &self.balls,
+                self.screen_width,
+                self.screen_height,
+                delta_time,
+                &mut self.rng,
+            );
+        }
 
-        // Verifica colisão com paredes superior e inferior
-        self.ball.check_wall_collision(self.screen_height);
+        // Ajuda da CPU aos jogadores com essa vantagem (a raquete da CPU já é dela)
+        for player in 0..2 {
+            if !self.player_active(player) || (player == 1 && self.cpu.is_some()) {
+                continue;
+            }
+            let paddle = match player {
+                0 => &mut self.paddle_left,
+                _ => &mut self.paddle_right,
+            };
+            if let Some(assist) = &mut self.assists[player] {
+                assist.update(
+                    paddle,
+                    &self.balls,
+                    self.screen_width,
+                    self.screen_height,
+                    delta_time,
+                    &mut self.rng,
+                );
+            }
+        }
+
+        // Conta o tempo da partida (o relógio para no saque e na pausa)
+        if self.four_player.is_none()
+            && self.practice.is_none()
+            && self.arcade.is_none()
+            && self.breakout.is_none()
+            && self.win_rule == WinRule::Timed
+            && self.time_left > 0.0
+        {
+            self.time_left = (self.time_left - delta_time).max(0.0);
+            // Com empate, o game segue em morte súbita
+            if self.time_up() && self.check_game_won() {
+                return;
+            }
+        }
 
-        // Verifica colisão com raquete esquerda
-        if self.paddle_left.check_collision(self.ball.x, self.ball.y, self.ball.size) {
-            self.ball.bounce_horizontal();
-            // Ajusta posição para evitar que a bola fique presa
-            self.ball.x = self.paddle_left.x + self.paddle_left.width + self.ball.size / 2.0;
+        self.stats.duration += delta_time;
+
+        // Relógio do arcade: bolas mais rápidas e bolas extras na sobrevivência
+        if let Some(arcade) = &mut self.arcade {
+            let extra_ball = arcade.update(delta_time, &mut self.balls);
+            if arcade.time_up() {
+                self.phase = GamePhase::GameOver;
+                return;
+            }
+            if extra_ball {
+                self.spawn_ball();
+            }
         }
 
-        // Verifica colisão com raquete direita
-        if self.paddle_right.check_collision(self.ball.x, self.ball.y, self.ball.size) {
-            self.ball.bounce_horizontal();
-            // Ajusta posição para evitar que a bola fique presa
-            self.ball.x = self.paddle_right.x - self.ball.size / 2.0;
+        // Efeitos de power-ups e novos power-ups na quadra
+        self.power_ups.update(
+            delta_time,
+            self.screen_width,
+            self.screen_height,
+            &mut self.rng,
+        );
+        let (screen_width, screen_height) = (self.screen_width, self.screen_height);
+        for paddle in self.paddles_mut() {
+            paddle.update_effects(delta_time, screen_width, screen_height);
         }
 
-        // Verifica se a bola saiu da tela (ponto marcado)
-        if self.ball.x < 0.0 {
-            // Jogador 2 marcou ponto
-            self.score_right += 1;
-            self.check_game_over();
-            if self.phase != GamePhase::GameOver {
-                self.start_round();
+        // Atualiza posição das bolas
+        self.arena.update(delta_time);
+        for ball in &mut self.balls {
+            let steps = ball.substeps(delta_time);
+            let step_time = delta_time / steps as f32;
+            for _ in 0..steps {
+                ball.update(step_time);
+
+                // Verifica colisão com paredes superior e inferior (no modo de 4
+                // jogadores, elas têm raquetes e são tratadas mais abaixo)
+                if self.four_player.is_none() {
+                    ball.check_wall_collision(self.screen_width, self.screen_height);
+                }
+
+                // Obstáculos da arena
+                self.arena.collide(ball);
+
+                // Tijolos do breakout
+                if let Some(breakout) = &mut self.breakout {
+                    breakout.collide(ball);
+                }
+
+                // Parede do treino
+                if let Some(practice) = &self.practice {
+                    practice.bounce_wall(ball, self.screen_width, self.screen_height);
+                }
+
+                // Parede da sobrevivência
+                if let Some(arcade) = &self.arcade {
+                    arcade.bounce_wall(ball, self.screen_width, self.screen_height);
+                }
             }
-        } else if self.ball.x > self.screen_width {
-            // Jogador 1 marcou ponto
-            self.score_left += 1;
-            self.check_game_over();
-            if self.phase != GamePhase::GameOver {
-                self.start_round();
+        }
+
+        // Verifica colisão de cada bola com as raquetes
+        let mut hit = false;
+        for index in 0..self.balls.len() {
+            if self.check_team_collision(Side::Left, index) {
+                // No treino, cada rebatida aumenta a sequência
+                if let Some(practice) = &mut self.practice {
+                    practice.record_return();
+                }
+                if let Some(arcade) = &mut self.arcade {
+                    arcade.record_return();
+                }
+                hit = true;
+            }
+            hit |= self.check_team_collision(Side::Right, index);
+            if let Some(four_player) = &self.four_player {
+                if let Some(wall) = four_player.check_paddles(&mut self.balls[index]) {
+                    self.balls[index].owner = Some(wall.player());
+                    self.stats.record_hit();
+                    hit = true;
+                }
+            }
+        }
+
+        // Power-ups pegos pelas bolas
+        for index in 0..self.balls.len() {
+            let ball = &self.balls[index];
+            if let (Some(kind), Some(owner)) = (self.power_ups.claim(ball), ball.owner) {
+                self.apply_power_up(kind, owner, index);
+            }
+        }
+
+        // Escudos rebatem as bolas antes que elas saiam da quadra
+        for ball in &mut self.balls {
+            self.power_ups
+                .bounce_shields(ball, self.screen_width, self.screen_height);
+        }
+
+        // Modo multibola: trocas de bola longas colocam mais uma bola em jogo
+        if hit && self.ball_count > 1 && self.stats.rally.is_multiple_of(MULTIBALL_RALLY_HITS) {
+            self.spawn_ball();
+        }
+
+        if self.four_player.is_some() {
+            self.update_four_player();
+            return;
+        }
+
+        // Verifica se alguma bola saiu da tela (cada bola marca o seu ponto)
+        let mut index = 0;
+        while index < self.balls.len() {
+            let x = self.balls[index].x;
+            let scorer = if x < 0.0 {
+                // Jogador 2 marcou ponto
+                Side::Right
+            } else if x > self.screen_width {
+                // Jogador 1 marcou ponto
+                Side::Left
+            } else {
+                index += 1;
+                continue;
+            };
+
+            // No breakout, a bola que passou pelos tijolos decide a partida
+            if self.breakout.is_some() {
+                self.stats.record_point(scorer);
+                self.winner = Some(scorer);
+                self.phase = GamePhase::GameOver;
+                return;
+            }
+
+            let rally_over = self.take_ball(index);
+            if self.arcade.is_some() {
+                self.end_arcade_rally(scorer, rally_over);
+                if self.phase != GamePhase::Playing {
+                    return;
+                }
+                continue;
+            }
+            if self.practice.is_some() {
+                self.end_practice_rally(scorer, rally_over);
+                if rally_over {
+                    return;
+                }
+                continue;
+            }
+            self.score_point(scorer, rally_over);
+            if rally_over || self.phase != GamePhase::Playing {
+                return;
             }
         }
     }
 
-    /// Verifica se o jogo terminou (alguém atingiu a pontuação máxima)
-    fn check_game_over(&mut self) {
-        if self.score_left >= self.max_score || self.score_right >= self.max_score {
+    /// Aplica o efeito de um power-up
+    ///
+    /// # Argumentos
+    ///
+    /// * `kind` - Power-up pego
+    /// * `owner` - Jogador que pegou o power-up (nas duplas, o time)
+    /// * `index` - Índice da bola que pegou o power-up
+    fn apply_power_up(&mut self, kind: PowerUpKind, owner: usize, index: usize) {
+        let (screen_width, screen_height) = (self.screen_width, self.screen_height);
+
+        match kind {
+            PowerUpKind::Grow => {
+                for paddle in self.player_paddles_mut(owner) {
+                    paddle.apply_scale(
+                        powerups::GROW_SCALE,
+                        powerups::PADDLE_EFFECT_TIME,
+                        screen_width,
+                        screen_height,
+                    );
+                }
+            }
+            PowerUpKind::Shrink => {
+                // Diminui as raquetes de todos os adversários ainda em jogo
+                let teams = if self.four_player.is_some() { 4 } else { 2 };
+                let opponents: Vec<usize> = (0..teams)
+                    .filter(|player| *player != owner && self.player_active(*player))
+                    .collect();
+                for opponent in opponents {
+                    for paddle in self.player_paddles_mut(opponent) {
+                        paddle.apply_scale(
+                            powerups::SHRINK_SCALE,
+                            powerups::PADDLE_EFFECT_TIME,
+                            screen_width,
+                            screen_height,
+                        );
+                    }
+                }
+            }
+            PowerUpKind::SpeedBoost => {
+                self.balls[index].apply_speed(powerups::BOOST_SCALE, powerups::BALL_EFFECT_TIME);
+            }
+            PowerUpKind::SlowBall => {
+                for ball in &mut self.balls {
+                    ball.apply_speed(powerups::SLOW_SCALE, powerups::BALL_EFFECT_TIME);
+                }
+            }
+            PowerUpKind::MultiBall => {
+                // A bola se divide em três, abrindo em leque
+                for angle in [powerups::SPLIT_ANGLE, -powerups::SPLIT_ANGLE] {
+                    if self.balls.len() >= MAX_BALLS {
+                        break;
+                    }
+                    let mut ball = self.balls[index].clone();
+                    ball.rotate(angle);
+                    self.balls.push(ball);
+                }
+            }
+            PowerUpKind::Curve => {
+                let acceleration = if self.rng.random_bool(0.5) {
+                    powerups::CURVE_ACCELERATION
+                } else {
+                    -powerups::CURVE_ACCELERATION
+                };
+                self.balls[index].apply_curve(acceleration, powerups::BALL_EFFECT_TIME);
+            }
+            PowerUpKind::Shield => {
+                self.power_ups.shields[owner] = powerups::SHIELD_TIME;
+            }
+        }
+    }
+
+    /// Rebate a bola nas raquetes de um time
+    ///
+    /// Só a bola que vai em direção à parede do time é rebatida: a bola que
+    /// volta passa por trás da raquete da frente das duplas.
+    ///
+    /// # Argumentos
+    ///
+    /// * `side` - Lado do time
+    /// * `index` - Índice da bola
+    ///
+    /// # Retorna
+    ///
+    /// `true` se a bola foi rebatida
+    fn check_team_collision(&mut self, side: Side, index: usize) -> bool {
+        let ball = &self.balls[index];
+        if ball.vel_x * side.direction() > 0.0 {
+            return false;
+        }
+
+        let half_size = ball.size / 2.0;
+        let hit = self
+            .team_paddles(side)
+            .find(|paddle| paddle.check_collision(ball.x, ball.y, ball.size))
+            .map(|paddle| match side {
+                Side::Left => paddle.x + paddle.width + half_size,
+                Side::Right => paddle.x - half_size,
+            });
+
+        let Some(x) = hit else {
+            return false;
+        };
+
+        let ball = &mut self.balls[index];
+        self.stats.record_return(side, ball.vel_x.hypot(ball.vel_y));
+        ball.owner = Some(side.player());
+        ball.bounce_horizontal();
+        // Ajusta posição para evitar que a bola fique presa
+        ball.x = x;
+        self.stats.record_hit();
+        true
+    }
+
+    /// Paredes e vidas do modo de 4 jogadores
+    fn update_four_player(&mut self) {
+        let mut index = 0;
+        while index < self.balls.len() {
+            let Some(four_player) = &mut self.four_player else {
+                return;
+            };
+
+            let Some(wall) = four_player.check_walls(
+                &mut self.balls[index],
+                self.screen_width,
+                self.screen_height,
+            ) else {
+                index += 1;
+                continue;
+            };
+
+            // Quem deixou a bola passar perde uma vida
+            four_player.lose_life(wall);
+            let match_over = four_player.winner().is_some();
+            let rally_over = self.take_ball(index);
+            self.stats.end_rally();
+
+            if match_over {
+                self.phase = GamePhase::GameOver;
+                return;
+            }
+            if rally_over {
+                self.start_serve(self.server);
+                return;
+            }
+        }
+    }
+
+    /// Gerador de números aleatórios da partida (usado também para sortear
+    /// a chave dos torneios)
+    pub fn rng(&mut self) -> &mut GameRng {
+        &mut self.rng
+    }
+
+    /// Volta os pontos do game aos pontos iniciais de cada jogador
+    ///
+    /// Os pontos iniciais ficam abaixo da pontuação alvo, para que nenhum
+    /// game seja decidido pelo primeiro ponto.
+    fn reset_scores(&mut self) {
+        let limit = if self.win_rule.uses_target() {
+            self.max_score.saturating_sub(1)
+        } else {
+            u32::MAX
+        };
+        self.score_left = self.handicaps[0].start_points.min(limit);
+        self.score_right = self.handicaps[1].start_points.min(limit);
+    }
+
+    /// Indica se o tempo da partida com tempo acabou
+    fn time_up(&self) -> bool {
+        self.win_rule == WinRule::Timed && self.time_left <= 0.0
+    }
+
+    /// Retorna a situação especial da partida (iguais, vantagem, ponto de ouro
+    /// ou morte súbita), se houver
+    pub fn status(&self) -> Option<MatchStatus> {
+        if self.four_player.is_some() || self.arcade.is_some() || self.breakout.is_some() {
+            return None;
+        }
+        self.win_rule.status(
+            self.max_score,
+            self.score_left,
+            self.score_right,
+            self.time_up(),
+        )
+    }
+
+    /// Verifica se o game atual terminou de acordo com a regra de vitória
+    ///
+    /// Ao fim de um game, registra o resultado no placar de sets e começa o
+    /// próximo game, ou encerra a partida se ela foi decidida.
+    ///
+    /// # Retorna
+    ///
+    /// `true` se o game terminou
+    fn check_game_won(&mut self) -> bool {
+        let Some(game_winner) = self.win_rule.winner(
+            self.max_score,
+            self.score_left,
+            self.score_right,
+            self.time_up(),
+        ) else {
+            return false;
+        };
+
+        if let Some(match_winner) = self.match_score.record_game(game_winner) {
+            self.winner = Some(match_winner);
             self.phase = GamePhase::GameOver;
+        } else {
+            // Próximo game: pontos iniciais e relógio zerado
+            self.reset_scores();
+            self.time_left = self.match_time;
+            self.start_serve(self.next_server(game_winner));
         }
+        true
     }
 
     /// Renderiza todos os elementos do jogo
@@ -167,22 +1529,54 @@ impl GameState {
     /// * `canvas` - Canvas SDL para desenhar
     pub fn render(&self, canvas: &mut Canvas<Window>) {
         // Limpa a tela com a cor de fundo
-        canvas.set_draw_color(self.background_color);
+        canvas.set_draw_color(self.theme.background);
         canvas.clear();
 
         // Desenha linha central (estilo anos 80)
         self.draw_center_line(canvas);
 
-        // Desenha raquetes
-        canvas.set_draw_color(Color::RGB(100, 200, 255)); // Azul claro
-        canvas.fill_rect(self.paddle_left.get_rect()).unwrap();
+        // Desenha raquetes de cada time (jogadores eliminados não têm raquete)
+        canvas.set_draw_color(self.theme.player_left);
+        for paddle in self.team_paddles(Side::Left) {
+            canvas.fill_rect(paddle.get_rect()).unwrap();
+        }
 
-        canvas.set_draw_color(Color::RGB(255, 100, 100)); // Vermelho claro
-        canvas.fill_rect(self.paddle_right.get_rect()).unwrap();
+        canvas.set_draw_color(self.theme.player_right);
+        for paddle in self.team_paddles(Side::Right) {
+            canvas.fill_rect(paddle.get_rect()).unwrap();
+        }
+
+        if let Some(four_player) = &self.four_player {
+            four_player.render(canvas, &self.theme, self.screen_width, self.screen_height);
+        }
+
+        self.arena.render(canvas, &self.theme);
+
+        if let Some(practice) = &self.practice {
+            practice.render(canvas, &self.theme, self.screen_width, self.screen_height);
+        }
 
-        // Desenha bola
-        canvas.set_draw_color(Color::RGB(255, 255, 100)); // Amarelo
-        canvas.fill_rect(self.ball.get_rect()).unwrap();
+        if let Some(arcade) = &self.arcade {
+            arcade.render(canvas, &self.theme, self.screen_width, self.screen_height);
+        }
+
+        if let Some(breakout) = &self.breakout {
+            breakout.render(canvas, &self.theme);
+        }
+
+        self.power_ups
+            .render(canvas, &self.theme, self.screen_width, self.screen_height);
+
+        // Desenha as bolas (bolas sob efeito de power-up mudam de cor)
+        for ball in &self.balls {
+            let color = if ball.has_effect() {
+                self.theme.accent
+            } else {
+                self.theme.ball
+            };
+            canvas.set_draw_color(color);
+            canvas.fill_rect(ball.get_rect()).unwrap();
+        }
     }
 
     /// Desenha a linha central pontilhada
@@ -191,7 +1585,7 @@ impl GameState {
     /// 
     /// * `canvas` - Canvas SDL para desenhar
     fn draw_center_line(&self, canvas: &mut Canvas<Window>) {
-        canvas.set_draw_color(Color::RGB(80, 80, 90));
+        canvas.set_draw_color(self.theme.center_line);
         
         let center_x = (self.screen_width / 2.0) as i32;
         let dash_height = 15;
@@ -210,7 +1604,442 @@ impl GameState {
     pub fn reset(&mut self) {
         self.score_left = 0;
         self.score_right = 0;
-        self.ball.reset(self.screen_width, self.screen_height);
+        self.match_score.reset();
+        self.winner = None;
+        self.four_player = None;
+        self.partners_left.clear();
+        self.partners_right.clear();
+        self.clear_balls();
         self.phase = GamePhase::Menu;
     }
+
+    /// Abre o editor de arenas (a quadra fica vazia, sem partida)
+    pub fn start_editing(&mut self) {
+        self.reset();
+        self.phase = GamePhase::Editing;
+    }
+
+    /// Converte a partida para as linhas "chave = valores" de um jogo salvo
+    ///
+    /// Tudo o que a partida precisa para continuar de onde parou é gravado:
+    /// posições, velocidades e efeitos, placar, fase, estado do gerador de
+    /// números aleatórios, configurações da partida e a arena. O tema não é
+    /// gravado (a partida continua com o tema escolhido).
+    pub fn save_text(&self) -> String {
+        let power_ups: Vec<&str> = self
+            .power_ups
+            .enabled
+            .iter()
+            .map(|kind| kind.id())
+            .collect();
+        let mut lines = vec![
+            format!("phase = {}", self.phase.id()),
+            format!("paused_phase = {}", self.paused_phase.id()),
+            format!("rng = {}", self.rng.state()),
+            format!("server = {}", self.server.id()),
+            format!("winner = {}", snapshot::optional(self.winner.map(Side::id))),
+            format!("forfeited = {}", self.forfeited),
+            format!("countdown = {}", self.countdown),
+            format!("time_left = {}", self.time_left),
+            format!("score = {} {}", self.score_left, self.score_right),
+            format!("max_score = {}", self.max_score),
+            format!("win_rule = {}", self.win_rule.id()),
+            format!("match_time = {}", self.match_time),
+            format!("serve_rule = {}", self.serve_rule.id()),
+            format!("press_to_serve = {}", self.press_to_serve),
+            format!("ball_count = {}", self.ball_count),
+            format!("lives = {}", self.lives),
+            format!("paddle_height = {}", self.paddle_height),
+            format!("power_ups_enabled = {}", power_ups.join(" ")),
+            format!("power_ups = {}", self.power_ups.snapshot()),
+            format!("match_score = {}", self.match_score.snapshot()),
+            format!("stats = {}", self.stats.snapshot()),
+            format!("paddle_left = {}", self.paddle_left.snapshot()),
+            format!("paddle_right = {}", self.paddle_right.snapshot()),
+        ];
+
+        // Listas e modos de jogo: uma linha por item
+        for (left, right) in &self.match_score.finished_sets {
+            lines.push(format!("finished_set = {} {}", left, right));
+        }
+        for handicap in &self.handicaps {
+            lines.push(format!("handicap = {}", handicap.snapshot()));
+        }
+        for ball in &self.balls {
+            lines.push(format!("ball = {}", ball.snapshot()));
+        }
+        for partner in &self.partners_left {
+            lines.push(format!("partner_left = {}", partner.snapshot()));
+        }
+        for partner in &self.partners_right {
+            lines.push(format!("partner_right = {}", partner.snapshot()));
+        }
+        for item in &self.power_ups.items {
+            lines.push(format!("power_up = {}", item.snapshot()));
+        }
+        if let Some(cpu) = &self.cpu {
+            lines.push(format!("cpu = {}", cpu.snapshot()));
+        }
+        for (player, assist) in self.assists.iter().enumerate() {
+            if let Some(assist) = assist {
+                lines.push(format!("assist = {} {}", player, assist.snapshot()));
+            }
+        }
+        if let Some(four_player) = &self.four_player {
+            let [left, right, top, bottom] = four_player.lives;
+            lines.push(format!(
+                "four_player = {} {} {} {}",
+                left, right, top, bottom
+            ));
+            lines.push(format!(
+                "paddle_top = {}",
+                four_player.paddle_top.snapshot()
+            ));
+            lines.push(format!(
+                "paddle_bottom = {}",
+                four_player.paddle_bottom.snapshot()
+            ));
+        }
+        if let Some(practice) = &self.practice {
+            lines.push(format!("practice = {}", practice.snapshot()));
+        }
+        if let Some(arcade) = &self.arcade {
+            lines.push(format!("arcade = {}", arcade.snapshot()));
+        }
+        if let Some(breakout) = &self.breakout {
+            lines.push(format!(
+                "breakout = {} {}",
+                breakout.broken[0], breakout.broken[1]
+            ));
+            for brick in &breakout.bricks {
+                lines.push(format!("brick = {}", brick.snapshot()));
+            }
+        }
+
+        // A arena vai inteira (pode ter sido editada ou apagada depois)
+        lines.push(format!("arena_id = {}", self.arena.id));
+        lines.push(format!("arena_time = {}", self.arena.time()));
+        for line in self.arena.to_text().lines() {
+            lines.push(format!("arena = {}", line));
+        }
+
+        lines.join("\n") + "\n"
+    }
+
+    /// Recria uma partida a partir das linhas de um jogo salvo
+    ///
+    /// Chaves que faltam ficam com o valor de uma partida nova (jogos salvos
+    /// por versões anteriores do formato) e chaves desconhecidas são ignoradas.
+    ///
+    /// # Argumentos
+    ///
+    /// * `screen_width` - Largura da tela
+    /// * `screen_height` - Altura da tela
+    /// * `entries` - Linhas gravadas por `save_text`
+    pub fn restore(
+        screen_width: f32,
+        screen_height: f32,
+        entries: &[kv::Entry],
+    ) -> Result<GameState, String> {
+        let mut state = GameState::new(screen_width, screen_height);
+        state.balls.clear();
+
+        // Partes montadas depois de lidas todas as linhas
+        let mut handicaps = Vec::new();
+        let mut power_up_items = Vec::new();
+        let mut power_ups_enabled = Vec::new();
+        let mut finished_sets = Vec::new();
+        let mut four_player_lives = None;
+        let mut paddle_top: Option<Paddle> = None;
+        let mut paddle_bottom: Option<Paddle> = None;
+        let mut broken = None;
+        let mut bricks = Vec::new();
+        let mut arena_id = None;
+        let mut arena_time = 0.0;
+        let mut arena_lines = Vec::new();
+
+        for entry in entries {
+            let value = entry.value.as_str();
+            let mut read = || -> Result<(), String> {
+                match entry.key.as_str() {
+                    "phase" => {
+                        state.phase = snapshot::id_value(value, &GamePhase::ALL, GamePhase::id)?
+                    }
+                    "paused_phase" => {
+                        state.paused_phase =
+                            snapshot::id_value(value, &GamePhase::ALL, GamePhase::id)?
+                    }
+                    "rng" => state.rng = GameRng::from_state(snapshot::value(value)?),
+                    "server" => state.server = snapshot::id_value(value, &Side::ALL, Side::id)?,
+                    "winner" => {
+                        let mut fields = Fields::new(value);
+                        state.winner = match fields.next_optional::<String>()? {
+                            Some(side) => Some(snapshot::id_value(&side, &Side::ALL, Side::id)?),
+                            None => None,
+                        };
+                        fields.finish()?;
+                    }
+                    "forfeited" => state.forfeited = snapshot::value(value)?,
+                    "countdown" => state.countdown = snapshot::value(value)?,
+                    "time_left" => state.time_left = snapshot::value(value)?,
+                    "score" => {
+                        let mut fields = Fields::new(value);
+                        state.score_left = fields.next()?;
+                        state.score_right = fields.next()?;
+                        fields.finish()?;
+                    }
+                    "max_score" => state.max_score = snapshot::value(value)?,
+                    "win_rule" => {
+                        state.win_rule = snapshot::id_value(value, &WinRule::ALL, WinRule::id)?
+                    }
+                    "match_time" => state.match_time = snapshot::value(value)?,
+                    "serve_rule" => {
+                        state.serve_rule =
+                            snapshot::id_value(value, &ServeRule::ALL, ServeRule::id)?
+                    }
+                    "press_to_serve" => state.press_to_serve = snapshot::value(value)?,
+                    "ball_count" => state.ball_count = snapshot::value(value)?,
+                    "lives" => state.lives = snapshot::value(value)?,
+                    "paddle_height" => state.paddle_height = snapshot::value(value)?,
+                    "power_ups_enabled" => {
+                        power_ups_enabled = value
+                            .split_whitespace()
+                            .map(|id| snapshot::id_value(id, &PowerUpKind::ALL, PowerUpKind::id))
+                            .collect::<Result<_, _>>()?;
+                    }
+                    "power_ups" => state.power_ups = snapshot::restore(value)?,
+                    "match_score" => state.match_score = snapshot::restore(value)?,
+                    "stats" => state.stats = snapshot::restore(value)?,
+                    "paddle_left" => state.paddle_left = snapshot::restore(value)?,
+                    "paddle_right" => state.paddle_right = snapshot::restore(value)?,
+                    "finished_set" => {
+                        let mut fields = Fields::new(value);
+                        finished_sets.push((fields.next()?, fields.next()?));
+                        fields.finish()?;
+                    }
+                    "handicap" => handicaps.push(snapshot::restore::<Handicap>(value)?),
+                    "ball" => state.balls.push(snapshot::restore(value)?),
+                    "partner_left" => state.partners_left.push(snapshot::restore(value)?),
+                    "partner_right" => state.partners_right.push(snapshot::restore(value)?),
+                    "power_up" => power_up_items.push(snapshot::restore(value)?),
+                    "cpu" => state.cpu = Some(snapshot::restore(value)?),
+                    "assist" => {
+                        let mut fields = Fields::new(value);
+                        let player: usize = fields.next()?;
+                        let assist = CpuPlayer::restore(&mut fields)?;
+                        fields.finish()?;
+                        let slot = state.assists.get_mut(player).ok_or("jogador inválido")?;
+                        *slot = Some(assist);
+                    }
+                    "four_player" => {
+                        let mut fields = Fields::new(value);
+                        four_player_lives = Some([
+                            fields.next()?,
+                            fields.next()?,
+                            fields.next()?,
+                            fields.next()?,
+                        ]);
+                        fields.finish()?;
+                    }
+                    "paddle_top" => paddle_top = Some(snapshot::restore(value)?),
+                    "paddle_bottom" => paddle_bottom = Some(snapshot::restore(value)?),
+                    "practice" => state.practice = Some(snapshot::restore(value)?),
+                    "arcade" => state.arcade = Some(snapshot::restore(value)?),
+                    "breakout" => {
+                        let mut fields = Fields::new(value);
+                        broken = Some([fields.next()?, fields.next()?]);
+                        fields.finish()?;
+                    }
+                    "brick" => bricks.push(snapshot::restore(value)?),
+                    "arena_id" => arena_id = Some(value.to_string()),
+                    "arena_time" => arena_time = snapshot::value(value)?,
+                    "arena" => arena_lines.push(value),
+                    // Chaves desconhecidas (de versões mais novas) são ignoradas
+                    _ => {}
+                }
+                Ok(())
+            };
+            read().map_err(|e| format!("linha {}: {}", entry.line, e))?;
+        }
+
+        if state.balls.is_empty() {
+            return Err(String::from("nenhuma bola gravada"));
+        }
+        match <[Handicap; 2]>::try_from(handicaps) {
+            Ok(handicaps) => state.handicaps = handicaps,
+            Err(handicaps) if handicaps.is_empty() => {}
+            Err(_) => return Err(String::from("número de vantagens inválido")),
+        }
+        state.power_ups.enabled = power_ups_enabled;
+        state.power_ups.items = power_up_items;
+        state.match_score.finished_sets = finished_sets;
+
+        if let Some(lives) = four_player_lives {
+            let (Some(mut paddle_top), Some(mut paddle_bottom)) = (paddle_top, paddle_bottom)
+            else {
+                return Err(String::from("raquetes do modo de 4 jogadores ausentes"));
+            };
+            paddle_top.horizontal = true;
+            paddle_bottom.horizontal = true;
+            state.four_player = Some(FourPlayer {
+                paddle_top,
+                paddle_bottom,
+                lives,
+            });
+        }
+        if let Some(broken) = broken {
+            state.breakout = Some(Breakout { bricks, broken });
+        }
+
+        if !arena_lines.is_empty() {
+            let mut arena =
+                Arena::parse(&arena_lines.join("\n")).map_err(|e| format!("arena: {}", e))?;
+            if let Some(id) = arena_id {
+                arena.id = id;
+            }
+            arena.fit(screen_width, screen_height);
+            arena.set_time(arena_time);
+            state.arena = arena;
+        }
+
+        Ok(state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn start_points_stay_below_the_target() {
+        let mut game_state = GameState::new(800.0, 600.0);
+        game_state.set_max_score(5);
+        let handicap = Handicap {
+            start_points: 10,
+            ..Handicap::default()
+        };
+        game_state.set_handicaps([handicap, Handicap::default()]);
+        game_state.start_match(None);
+
+        assert_eq!(game_state.score_left, 4);
+        assert_eq!(game_state.score_right, 0);
+
+        // Um ponto do adversário não decide o game
+        game_state.score_right += 1;
+        assert!(!game_state.check_game_won());
+
+        game_state.set_max_score(1);
+        game_state.start_match(None);
+        assert_eq!(game_state.score_left, 0);
+    }
+
+    #[test]
+    fn survival_ends_only_when_the_last_ball_is_lost() {
+        let mut game_state = GameState::new(800.0, 600.0);
+        game_state.start_arcade(ArcadeMode::Survival);
+        game_state.phase = GamePhase::Playing;
+        let extra = game_state.balls[0].clone();
+        game_state.balls.push(extra);
+
+        let rally_over = game_state.take_ball(0);
+        game_state.end_arcade_rally(Side::Right, rally_over);
+        assert!(game_state.phase == GamePhase::Playing);
+        assert_eq!(game_state.balls.len(), 1);
+
+        let rally_over = game_state.take_ball(0);
+        game_state.end_arcade_rally(Side::Right, rally_over);
+        assert!(game_state.phase == GamePhase::GameOver);
+    }
+
+    /// Joga um pouco, pausa e confere que o jogo salvo volta igual
+    fn assert_round_trip(mut game_state: GameState) -> GameState {
+        for _ in 0..200 {
+            game_state.update(0.016);
+        }
+        game_state.pause();
+        assert!(game_state.phase == GamePhase::Paused);
+
+        let text = game_state.save_text();
+        let entries = kv::parse(&text).unwrap();
+        let restored = GameState::restore(800.0, 600.0, &entries).unwrap();
+        assert_eq!(restored.save_text(), text);
+        restored
+    }
+
+    #[test]
+    fn saved_matches_restore_unchanged() {
+        let mut game_state = GameState::new(800.0, 600.0);
+        game_state.set_power_ups(PowerUpKind::ALL.to_vec());
+        game_state.set_ball_count(2);
+        game_state.set_handicaps([
+            Handicap {
+                start_points: 2,
+                ..Handicap::default()
+            },
+            Handicap::default(),
+        ]);
+        game_state.start_match(Some(CpuPlayer::new(Difficulty::Hard)));
+        assert_round_trip(game_state);
+
+        let mut game_state = GameState::new(800.0, 600.0);
+        game_state.set_match_format(3, 2);
+        game_state.start_match(None);
+        assert_round_trip(game_state);
+    }
+
+    #[test]
+    fn saved_modes_restore_unchanged() {
+        let mut game_state = GameState::new(800.0, 600.0);
+        game_state.start_four_player();
+        let restored = assert_round_trip(game_state);
+        let four_player = restored.four_player.unwrap();
+        assert!(four_player.paddle_top.horizontal && four_player.paddle_bottom.horizontal);
+        assert!(!restored.paddle_left.horizontal);
+
+        let mut game_state = GameState::new(800.0, 600.0);
+        game_state.start_breakout();
+        assert_round_trip(game_state);
+
+        let mut game_state = GameState::new(800.0, 600.0);
+        game_state.start_practice(PracticeTarget::Alternate);
+        assert_round_trip(game_state);
+
+        for mode in ArcadeMode::ALL {
+            let mut game_state = GameState::new(800.0, 600.0);
+            game_state.start_arcade(mode);
+            assert_round_trip(game_state);
+        }
+    }
+
+    #[test]
+    fn four_player_match_ends_when_one_player_is_left() {
+        let mut game_state = GameState::new(800.0, 600.0);
+        game_state.set_lives(1);
+        game_state.start_four_player();
+
+        // A bola sai pela parede de cada jogador, um de cada vez
+        let exits = [(-20.0, 300.0), (820.0, 300.0), (400.0, -20.0)];
+        for (index, (x, y)) in exits.into_iter().enumerate() {
+            game_state.phase = GamePhase::Playing;
+            game_state.balls.truncate(1);
+            game_state.balls[0].x = x;
+            game_state.balls[0].y = y;
+            game_state.update_four_player();
+
+            assert!(!game_state.player_active(index));
+            if index < 2 {
+                assert!(game_state.phase == GamePhase::Serving);
+                assert!(game_state.winner_player().is_none());
+            }
+        }
+
+        assert!(game_state.phase == GamePhase::GameOver);
+        assert_eq!(game_state.winner_player(), Some(3));
+    }
+
+    #[test]
+    fn restore_rejects_saves_without_balls() {
+        let entries = kv::parse("phase = paused\n").unwrap();
+        assert!(GameState::restore(800.0, 600.0, &entries).is_err());
+    }
 }
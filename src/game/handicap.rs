@@ -0,0 +1,108 @@
+// Vantagens por jogador para equilibrar partidas entre jogadores de níveis diferentes
+
+use super::snapshot::{Fields, Snapshot};
+
+/// Vantagens (ou desvantagens) de um jogador
+#[derive(PartialEq, Clone, Copy)]
+pub struct Handicap {
+    /// Tamanho da raquete em relação ao tamanho configurado (em %)
+    pub paddle_size: u32,
+    /// Velocidade da raquete em relação à velocidade normal (em %)
+    pub paddle_speed: u32,
+    /// Pontos com que o jogador começa cada game
+    pub start_points: u32,
+    /// Se verdadeiro, a CPU ajuda a mover a raquete em direção à bola
+    pub ai_assist: bool,
+}
+
+impl Handicap {
+    /// Fator de tamanho da raquete
+    pub fn size_scale(&self) -> f32 {
+        self.paddle_size as f32 / 100.0
+    }
+
+    /// Fator de velocidade da raquete
+    pub fn speed_scale(&self) -> f32 {
+        self.paddle_speed as f32 / 100.0
+    }
+}
+
+impl Default for Handicap {
+    fn default() -> Handicap {
+        Handicap {
+            paddle_size: 100,
+            paddle_speed: 100,
+            start_points: 0,
+            ai_assist: false,
+        }
+    }
+}
+
+impl Snapshot for Handicap {
+    fn snapshot(&self) -> String {
+        format!(
+            "{} {} {} {}",
+            self.paddle_size, self.paddle_speed, self.start_points, self.ai_assist
+        )
+    }
+
+    fn restore(fields: &mut Fields) -> Result<Handicap, String> {
+        Ok(Handicap {
+            paddle_size: fields.next()?,
+            paddle_speed: fields.next()?,
+            start_points: fields.next()?,
+            ai_assist: fields.next()?,
+        })
+    }
+}
+
+/// Opções de vantagem que podem ser alteradas no menu
+#[derive(PartialEq, Clone, Copy)]
+pub enum HandicapOption {
+    /// Tamanho da raquete
+    PaddleSize,
+    /// Velocidade da raquete
+    PaddleSpeed,
+    /// Pontos iniciais
+    StartPoints,
+    /// Ajuda da CPU
+    AiAssist,
+}
+
+impl HandicapOption {
+    /// Todas as opções, na ordem em que aparecem no menu
+    pub const ALL: [HandicapOption; 4] = [
+        HandicapOption::PaddleSize,
+        HandicapOption::PaddleSpeed,
+        HandicapOption::StartPoints,
+        HandicapOption::AiAssist,
+    ];
+
+    /// Nome usado no arquivo de configurações (depois de `handicap<jogador>_`)
+    pub fn id(self) -> &'static str {
+        match self {
+            HandicapOption::PaddleSize => "paddle_size",
+            HandicapOption::PaddleSpeed => "paddle_speed",
+            HandicapOption::StartPoints => "start_points",
+            HandicapOption::AiAssist => "ai_assist",
+        }
+    }
+
+    /// Chave do catálogo de mensagens com o nome da opção de um jogador
+    ///
+    /// # Argumentos
+    ///
+    /// * `player` - Índice do jogador (0 ou 1)
+    pub fn label_key(self, player: usize) -> &'static str {
+        match (self, player) {
+            (HandicapOption::PaddleSize, 0) => "handicap.paddle_size1",
+            (HandicapOption::PaddleSpeed, 0) => "handicap.paddle_speed1",
+            (HandicapOption::StartPoints, 0) => "handicap.start_points1",
+            (HandicapOption::AiAssist, 0) => "handicap.ai_assist1",
+            (HandicapOption::PaddleSize, _) => "handicap.paddle_size2",
+            (HandicapOption::PaddleSpeed, _) => "handicap.paddle_speed2",
+            (HandicapOption::StartPoints, _) => "handicap.start_points2",
+            (HandicapOption::AiAssist, _) => "handicap.ai_assist2",
+        }
+    }
+}
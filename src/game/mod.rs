@@ -1,5 +1,20 @@
 // Módulo principal do jogo que contém toda a lógica de jogo
 
+pub mod ai;
+pub mod arcade;
+pub mod arena;
 pub mod ball;
-pub mod paddle;
+pub mod breakout;
+pub mod four_player;
 pub mod game_state;
+pub mod handicap;
+pub mod paddle;
+pub mod powerups;
+pub mod practice;
+pub mod random;
+pub mod rating;
+pub mod rules;
+pub mod scoring;
+pub mod snapshot;
+pub mod theme;
+pub mod tournament;
@@ -1,5 +1,6 @@
 // Estrutura e lógica das raquetes do jogo
 
+use super::snapshot::{Fields, Snapshot};
 use sdl2::rect::Rect;
 
 /// Representa uma raquete no jogo Pong
@@ -15,6 +16,13 @@ pub struct Paddle {
     pub height: f32,
     /// Velocidade de movimento da raquete (pixels por segundo)
     pub speed: f32,
+    /// Fator de tamanho temporário (power-ups de aumentar e diminuir)
+    scale: f32,
+    /// Tempo restante do efeito de tamanho (em segundos)
+    scale_time: f32,
+    /// Indica se a raquete é horizontal (raquetes de cima e de baixo do modo
+    /// de 4 jogadores): os efeitos de tamanho mudam a sua largura
+    pub horizontal: bool,
 }
 
 impl Paddle {
@@ -34,6 +42,25 @@ impl Paddle {
             width,
             height,
             speed,
+            scale: 1.0,
+            scale_time: 0.0,
+            horizontal: false,
+        }
+    }
+
+    /// Cria uma raquete horizontal (que se move para os lados)
+    ///
+    /// # Argumentos
+    ///
+    /// * `x` - Posição X inicial
+    /// * `y` - Posição Y inicial
+    /// * `width` - Largura da raquete
+    /// * `height` - Altura da raquete
+    /// * `speed` - Velocidade de movimento
+    pub fn new_horizontal(x: f32, y: f32, width: f32, height: f32, speed: f32) -> Paddle {
+        Paddle {
+            horizontal: true,
+            ..Paddle::new(x, y, width, height, speed)
         }
     }
 
@@ -66,24 +93,126 @@ impl Paddle {
         }
     }
 
+    /// Move a raquete para a esquerda (raquetes horizontais do modo de 4 jogadores)
+    ///
+    /// # Argumentos
+    ///
+    /// * `delta_time` - Tempo decorrido desde o último frame (em segundos)
+    pub fn move_left(&mut self, delta_time: f32) {
+        self.x = (self.x - self.speed * delta_time).max(0.0);
+    }
+
+    /// Move a raquete para a direita (raquetes horizontais do modo de 4 jogadores)
+    ///
+    /// # Argumentos
+    ///
+    /// * `delta_time` - Tempo decorrido desde o último frame (em segundos)
+    /// * `screen_width` - Largura da tela
+    pub fn move_right(&mut self, delta_time: f32, screen_width: f32) {
+        self.x = (self.x + self.speed * delta_time).min(screen_width - self.width);
+    }
+
     /// Move a raquete para uma posição Y específica (usado para controle por mouse)
     /// 
     /// # Argumentos
     /// 
     /// * `target_y` - Posição Y alvo (centro da raquete)
     /// * `screen_height` - Altura da tela
-    //  pub fn move_to(&mut self, target_y: f32, screen_height: f32) {
-    //      // Centraliza a raquete na posição do mouse
-    //      self.y = target_y - self.height / 2.0;
-        
-    //      // Limita a raquete para não sair da tela
-    //      if self.y < 0.0 {
-    //          self.y = 0.0;
-    //      }
-    //      if self.y + self.height > screen_height {
-    //          self.y = screen_height - self.height;
-    //      }
-    //  }
+    pub fn move_to(&mut self, target_y: f32, screen_height: f32) {
+        // Centraliza a raquete na posição do mouse
+        self.y = target_y - self.height / 2.0;
+
+        // Limita a raquete para não sair da tela
+        if self.y < 0.0 {
+            self.y = 0.0;
+        }
+        if self.y + self.height > screen_height {
+            self.y = screen_height - self.height;
+        }
+    }
+
+    /// Altera a altura da raquete mantendo o seu centro
+    ///
+    /// Um efeito de tamanho ativo continua valendo sobre a nova altura.
+    ///
+    /// # Argumentos
+    ///
+    /// * `height` - Nova altura (sem o efeito de tamanho)
+    /// * `screen_height` - Altura da tela
+    pub fn set_height(&mut self, height: f32, screen_height: f32) {
+        let center = self.y + self.height / 2.0;
+        self.height = height * self.scale;
+        self.move_to(center, screen_height);
+    }
+
+    /// Altera a largura da raquete mantendo o seu centro (raquetes horizontais)
+    ///
+    /// # Argumentos
+    ///
+    /// * `width` - Nova largura (sem o efeito de tamanho)
+    /// * `screen_width` - Largura da tela
+    pub fn set_width(&mut self, width: f32, screen_width: f32) {
+        let center = self.x + self.width / 2.0;
+        self.width = width * self.scale;
+        self.x = (center - self.width / 2.0).clamp(0.0, screen_width - self.width);
+    }
+
+    /// Muda o tamanho da raquete por um tempo (power-ups)
+    ///
+    /// # Argumentos
+    ///
+    /// * `scale` - Fator de tamanho (1.0 volta ao normal)
+    /// * `duration` - Duração do efeito (em segundos)
+    /// * `screen_width` - Largura da tela
+    /// * `screen_height` - Altura da tela
+    pub fn apply_scale(
+        &mut self,
+        scale: f32,
+        duration: f32,
+        screen_width: f32,
+        screen_height: f32,
+    ) {
+        // Comprimento da raquete sem o efeito anterior
+        let length = if self.horizontal {
+            self.width
+        } else {
+            self.height
+        } / self.scale;
+
+        self.scale = scale;
+        self.scale_time = duration;
+        if self.horizontal {
+            self.set_width(length, screen_width);
+        } else {
+            self.set_height(length, screen_height);
+        }
+    }
+
+    /// Conta o tempo dos efeitos de power-ups e desfaz os que acabaram
+    ///
+    /// # Argumentos
+    ///
+    /// * `delta_time` - Tempo decorrido desde o último frame (em segundos)
+    /// * `screen_width` - Largura da tela
+    /// * `screen_height` - Altura da tela
+    pub fn update_effects(&mut self, delta_time: f32, screen_width: f32, screen_height: f32) {
+        if self.scale_time > 0.0 {
+            self.scale_time -= delta_time;
+            if self.scale_time <= 0.0 {
+                self.apply_scale(1.0, 0.0, screen_width, screen_height);
+            }
+        }
+    }
+
+    /// Desfaz os efeitos de power-ups imediatamente
+    ///
+    /// # Argumentos
+    ///
+    /// * `screen_width` - Largura da tela
+    /// * `screen_height` - Altura da tela
+    pub fn clear_effects(&mut self, screen_width: f32, screen_height: f32) {
+        self.apply_scale(1.0, 0.0, screen_width, screen_height);
+    }
 
     /// Verifica colisão com a bola
     /// 
@@ -116,3 +245,69 @@ impl Paddle {
         )
     }
 }
+
+/// A orientação não é gravada: quem lê a raquete sabe qual raquete ela é
+impl Snapshot for Paddle {
+    fn snapshot(&self) -> String {
+        format!(
+            "{} {} {} {} {} {} {}",
+            self.x, self.y, self.width, self.height, self.speed, self.scale, self.scale_time
+        )
+    }
+
+    fn restore(fields: &mut Fields) -> Result<Paddle, String> {
+        Ok(Paddle {
+            x: fields.next()?,
+            y: fields.next()?,
+            width: fields.next()?,
+            height: fields.next()?,
+            speed: fields.next()?,
+            scale: fields.next()?,
+            scale_time: fields.next()?,
+            horizontal: false,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::snapshot;
+
+    #[test]
+    fn snapshot_round_trip() {
+        let mut paddle = Paddle::new(20.0, 250.0, 15.0, 100.0, 400.0);
+        paddle.apply_scale(1.5, 3.0, 800.0, 600.0);
+        paddle.update_effects(0.5, 800.0, 600.0);
+
+        let text = paddle.snapshot();
+        let restored: Paddle = snapshot::restore(&text).unwrap();
+        assert_eq!(restored.snapshot(), text);
+        assert!(snapshot::restore::<Paddle>(&format!("{} 1", text)).is_err());
+    }
+
+    #[test]
+    fn size_effect_expires_on_a_small_vertical_paddle() {
+        // Altura mínima com a menor vantagem de tamanho: encolhida, a raquete
+        // fica mais baixa do que larga
+        let mut paddle = Paddle::new(20.0, 250.0, 15.0, 20.0, 400.0);
+        paddle.apply_scale(0.6, 1.0, 800.0, 600.0);
+        assert!((paddle.height - 12.0).abs() < 1e-4);
+        assert_eq!(paddle.width, 15.0);
+
+        paddle.update_effects(1.5, 800.0, 600.0);
+        assert!((paddle.height - 20.0).abs() < 1e-4);
+        assert_eq!(paddle.width, 15.0);
+    }
+
+    #[test]
+    fn size_effect_changes_the_width_of_a_horizontal_paddle() {
+        let mut paddle = Paddle::new_horizontal(380.0, 20.0, 40.0, 15.0, 400.0);
+        paddle.apply_scale(1.5, 1.0, 800.0, 600.0);
+        assert!((paddle.width - 60.0).abs() < 1e-4);
+        assert_eq!(paddle.height, 15.0);
+
+        paddle.clear_effects(800.0, 600.0);
+        assert!((paddle.width - 40.0).abs() < 1e-4);
+    }
+}
@@ -0,0 +1,348 @@
+// Power-ups que aparecem no meio da quadra
+
+use super::ball::Ball;
+use super::four_player::Wall;
+use super::random::GameRng;
+use super::snapshot::{Fields, Snapshot};
+use super::theme::Theme;
+use rand::seq::IndexedRandom;
+use rand::Rng;
+use sdl2::pixels::Color;
+use sdl2::rect::Rect;
+use sdl2::render::Canvas;
+use sdl2::video::Window;
+
+/// Tempo de bola em jogo entre o surgimento de dois power-ups (em segundos)
+const SPAWN_INTERVAL: f32 = 8.0;
+
+/// Tempo que um power-up fica na quadra esperando ser pego (em segundos)
+const ITEM_LIFETIME: f32 = 12.0;
+
+/// Tamanho do quadrado que representa um power-up na quadra
+const ITEM_SIZE: f32 = 26.0;
+
+/// Número máximo de power-ups na quadra ao mesmo tempo
+const MAX_ITEMS: usize = 2;
+
+/// Duração dos efeitos nas raquetes (em segundos)
+pub const PADDLE_EFFECT_TIME: f32 = 10.0;
+
+/// Duração dos efeitos na bola (em segundos)
+pub const BALL_EFFECT_TIME: f32 = 4.0;
+
+/// Duração do escudo (em segundos)
+pub const SHIELD_TIME: f32 = 8.0;
+
+/// Fator de tamanho da raquete de quem pega "aumentar raquete"
+pub const GROW_SCALE: f32 = 1.5;
+
+/// Fator de tamanho das raquetes dos adversários com "diminuir raquete"
+pub const SHRINK_SCALE: f32 = 0.6;
+
+/// Fator de velocidade da bola com "bola rápida"
+pub const BOOST_SCALE: f32 = 1.5;
+
+/// Fator de velocidade das bolas com "bola lenta"
+pub const SLOW_SCALE: f32 = 0.6;
+
+/// Aceleração vertical da bola com "bola com curva" (pixels por segundo ao quadrado)
+pub const CURVE_ACCELERATION: f32 = 300.0;
+
+/// Ângulo entre as bolas criadas por "dividir bola" (em radianos)
+pub const SPLIT_ANGLE: f32 = 0.35;
+
+/// Tipos de power-up
+#[derive(PartialEq, Clone, Copy)]
+pub enum PowerUpKind {
+    /// Aumenta a raquete de quem pegou
+    Grow,
+    /// Diminui as raquetes dos adversários
+    Shrink,
+    /// Acelera a bola que pegou o power-up
+    SpeedBoost,
+    /// Deixa todas as bolas mais lentas
+    SlowBall,
+    /// Divide a bola que pegou o power-up em três
+    MultiBall,
+    /// Faz a bola que pegou o power-up andar em curva
+    Curve,
+    /// Coloca uma parede atrás da raquete de quem pegou
+    Shield,
+}
+
+impl PowerUpKind {
+    /// Todos os power-ups, na ordem em que aparecem no menu
+    pub const ALL: [PowerUpKind; 7] = [
+        PowerUpKind::Grow,
+        PowerUpKind::Shrink,
+        PowerUpKind::SpeedBoost,
+        PowerUpKind::SlowBall,
+        PowerUpKind::MultiBall,
+        PowerUpKind::Curve,
+        PowerUpKind::Shield,
+    ];
+
+    /// Nome usado no arquivo de configurações
+    pub fn id(self) -> &'static str {
+        match self {
+            PowerUpKind::Grow => "grow",
+            PowerUpKind::Shrink => "shrink",
+            PowerUpKind::SpeedBoost => "speed_boost",
+            PowerUpKind::SlowBall => "slow_ball",
+            PowerUpKind::MultiBall => "multi_ball",
+            PowerUpKind::Curve => "curve",
+            PowerUpKind::Shield => "shield",
+        }
+    }
+
+    /// Chave do catálogo de mensagens com o nome do power-up
+    pub fn label_key(self) -> &'static str {
+        match self {
+            PowerUpKind::Grow => "power_up.grow",
+            PowerUpKind::Shrink => "power_up.shrink",
+            PowerUpKind::SpeedBoost => "power_up.speed_boost",
+            PowerUpKind::SlowBall => "power_up.slow_ball",
+            PowerUpKind::MultiBall => "power_up.multi_ball",
+            PowerUpKind::Curve => "power_up.curve",
+            PowerUpKind::Shield => "power_up.shield",
+        }
+    }
+
+    /// Símbolo desenhado sobre o power-up na quadra (não depende só da cor)
+    pub fn symbol(self) -> &'static str {
+        match self {
+            PowerUpKind::Grow => "+",
+            PowerUpKind::Shrink => "-",
+            PowerUpKind::SpeedBoost => ">",
+            PowerUpKind::SlowBall => "<",
+            PowerUpKind::MultiBall => "3",
+            PowerUpKind::Curve => "~",
+            PowerUpKind::Shield => "#",
+        }
+    }
+
+    /// Cor do power-up na quadra
+    pub fn color(self) -> Color {
+        match self {
+            PowerUpKind::Grow => Color::RGB(90, 200, 90),
+            PowerUpKind::Shrink => Color::RGB(220, 80, 80),
+            PowerUpKind::SpeedBoost => Color::RGB(240, 150, 40),
+            PowerUpKind::SlowBall => Color::RGB(80, 160, 240),
+            PowerUpKind::MultiBall => Color::RGB(230, 220, 60),
+            PowerUpKind::Curve => Color::RGB(190, 100, 230),
+            PowerUpKind::Shield => Color::RGB(200, 200, 200),
+        }
+    }
+}
+
+/// Um power-up esperando na quadra
+pub struct PowerUp {
+    /// Tipo do power-up
+    pub kind: PowerUpKind,
+    /// Posição X (centro)
+    pub x: f32,
+    /// Posição Y (centro)
+    pub y: f32,
+    /// Tempo restante até sumir (em segundos)
+    time_left: f32,
+}
+
+impl PowerUp {
+    /// Retorna um retângulo SDL para renderização
+    pub fn get_rect(&self) -> Rect {
+        let half_size = ITEM_SIZE / 2.0;
+        Rect::new(
+            (self.x - half_size) as i32,
+            (self.y - half_size) as i32,
+            ITEM_SIZE as u32,
+            ITEM_SIZE as u32,
+        )
+    }
+
+    /// Verifica se a bola encostou no power-up
+    fn touches(&self, ball: &Ball) -> bool {
+        let reach = (ITEM_SIZE + ball.size) / 2.0;
+        (ball.x - self.x).abs() <= reach && (ball.y - self.y).abs() <= reach
+    }
+}
+
+impl Snapshot for PowerUp {
+    fn snapshot(&self) -> String {
+        format!(
+            "{} {} {} {}",
+            self.kind.id(),
+            self.x,
+            self.y,
+            self.time_left
+        )
+    }
+
+    fn restore(fields: &mut Fields) -> Result<PowerUp, String> {
+        Ok(PowerUp {
+            kind: fields.next_id(&PowerUpKind::ALL, PowerUpKind::id)?,
+            x: fields.next()?,
+            y: fields.next()?,
+            time_left: fields.next()?,
+        })
+    }
+}
+
+/// Power-ups da partida: os que estão na quadra e os escudos ativos
+pub struct PowerUps {
+    /// Power-ups que podem aparecer (vazio = power-ups desligados)
+    pub enabled: Vec<PowerUpKind>,
+    /// Power-ups esperando na quadra
+    pub items: Vec<PowerUp>,
+    /// Tempo restante do escudo de cada jogador (na ordem de `Wall::ALL`)
+    pub shields: [f32; 4],
+    /// Tempo até o próximo power-up aparecer (em segundos)
+    spawn_timer: f32,
+}
+
+impl PowerUps {
+    /// Cria o controle de power-ups sem nenhum power-up habilitado
+    pub fn new() -> PowerUps {
+        PowerUps {
+            enabled: Vec::new(),
+            items: Vec::new(),
+            shields: [0.0; 4],
+            spawn_timer: SPAWN_INTERVAL,
+        }
+    }
+
+    /// Tira os power-ups da quadra e desliga os escudos (nova partida)
+    pub fn clear(&mut self) {
+        self.items.clear();
+        self.shields = [0.0; 4];
+        self.spawn_timer = SPAWN_INTERVAL;
+    }
+
+    /// Conta o tempo dos power-ups e dos escudos e faz surgir novos power-ups
+    ///
+    /// # Argumentos
+    ///
+    /// * `delta_time` - Tempo decorrido desde o último frame (em segundos)
+    /// * `screen_width` - Largura da tela
+    /// * `screen_height` - Altura da tela
+    /// * `rng` - Gerador de números aleatórios da partida
+    pub fn update(
+        &mut self,
+        delta_time: f32,
+        screen_width: f32,
+        screen_height: f32,
+        rng: &mut GameRng,
+    ) {
+        for shield in &mut self.shields {
+            *shield = (*shield - delta_time).max(0.0);
+        }
+
+        for item in &mut self.items {
+            item.time_left -= delta_time;
+        }
+        self.items.retain(|item| item.time_left > 0.0);
+
+        self.spawn_timer -= delta_time;
+        if self.spawn_timer > 0.0 {
+            return;
+        }
+        self.spawn_timer = SPAWN_INTERVAL;
+
+        let Some(kind) = self.enabled.choose(rng).copied() else {
+            return;
+        };
+        if self.items.len() >= MAX_ITEMS {
+            return;
+        }
+
+        // Os power-ups aparecem no meio da quadra, longe das raquetes
+        self.items.push(PowerUp {
+            kind,
+            x: rng.random_range(screen_width * 0.3..screen_width * 0.7),
+            y: rng.random_range(screen_height * 0.15..screen_height * 0.85),
+            time_left: ITEM_LIFETIME,
+        });
+    }
+
+    /// Verifica se a bola pegou um power-up
+    ///
+    /// Só bolas que já foram rebatidas por alguém pegam power-ups.
+    ///
+    /// # Retorna
+    ///
+    /// O tipo do power-up pego (ele sai da quadra)
+    pub fn claim(&mut self, ball: &Ball) -> Option<PowerUpKind> {
+        // Bolas que ninguém rebateu ainda não pegam power-ups
+        ball.owner?;
+        let index = self.items.iter().position(|item| item.touches(ball))?;
+        Some(self.items.remove(index).kind)
+    }
+
+    /// Rebate a bola nos escudos ativos
+    ///
+    /// # Argumentos
+    ///
+    /// * `ball` - Bola do jogo
+    /// * `screen_width` - Largura da tela
+    /// * `screen_height` - Altura da tela
+    pub fn bounce_shields(&self, ball: &mut Ball, screen_width: f32, screen_height: f32) {
+        for wall in Wall::ALL {
+            if self.shields[wall.player()] > 0.0 {
+                wall.bounce(ball, screen_width, screen_height);
+            }
+        }
+    }
+
+    /// Desenha os power-ups da quadra e os escudos ativos
+    ///
+    /// # Argumentos
+    ///
+    /// * `canvas` - Canvas SDL para desenhar
+    /// * `theme` - Tema de cores
+    /// * `screen_width` - Largura da tela
+    /// * `screen_height` - Altura da tela
+    pub fn render(
+        &self,
+        canvas: &mut Canvas<Window>,
+        theme: &Theme,
+        screen_width: f32,
+        screen_height: f32,
+    ) {
+        for item in &self.items {
+            let rect = item.get_rect();
+            canvas.set_draw_color(item.kind.color());
+            canvas.fill_rect(rect).unwrap();
+            canvas.set_draw_color(theme.ball);
+            canvas.draw_rect(rect).unwrap();
+        }
+
+        // O escudo é uma parede na cor do jogador que o pegou
+        for wall in Wall::ALL {
+            if self.shields[wall.player()] > 0.0 {
+                canvas.set_draw_color(theme.player_color(wall.player()));
+                canvas
+                    .fill_rect(wall.rect(screen_width, screen_height))
+                    .unwrap();
+            }
+        }
+    }
+}
+
+/// Gravação dos escudos e do tempo até o próximo power-up (os power-ups da
+/// quadra e os habilitados são gravados à parte, um por linha)
+impl Snapshot for PowerUps {
+    fn snapshot(&self) -> String {
+        format!(
+            "{} {} {} {} {}",
+            self.shields[0], self.shields[1], self.shields[2], self.shields[3], self.spawn_timer
+        )
+    }
+
+    fn restore(fields: &mut Fields) -> Result<PowerUps, String> {
+        let mut power_ups = PowerUps::new();
+        for shield in &mut power_ups.shields {
+            *shield = fields.next()?;
+        }
+        power_ups.spawn_timer = fields.next()?;
+        Ok(power_ups)
+    }
+}
@@ -0,0 +1,233 @@
+// Modo treino: um jogador sozinho contra uma parede ou um lançador de bolas
+
+use super::ball::Ball;
+use super::four_player::Wall;
+use super::random::GameRng;
+use super::snapshot::{self, Fields, Snapshot};
+use super::theme::Theme;
+use rand::Rng;
+use sdl2::rect::Rect;
+use sdl2::render::Canvas;
+use sdl2::video::Window;
+use std::f32::consts::PI;
+
+/// Distância entre a parede direita e o ponto de onde o lançador atira a bola
+const LAUNCHER_OFFSET: f32 = 40.0;
+
+/// Tamanho do lançador desenhado na parede direita
+const LAUNCHER_SIZE: u32 = 30;
+
+/// Ângulo dos lançamentos alternados (em radianos)
+const ALTERNATE_ANGLE: f32 = 0.35;
+
+/// O que fica do lado direito da quadra no treino
+#[derive(PartialEq, Clone, Copy)]
+pub enum PracticeTarget {
+    /// Parede que devolve todas as bolas
+    Wall,
+    /// Lançador que atira a bola reto, do meio da quadra
+    Straight,
+    /// Lançador que atira uma bola para cima e a seguinte para baixo
+    Alternate,
+    /// Lançador que atira de alturas e ângulos sorteados
+    Random,
+}
+
+impl PracticeTarget {
+    /// Todos os alvos, na ordem em que aparecem no menu
+    pub const ALL: [PracticeTarget; 4] = [
+        PracticeTarget::Wall,
+        PracticeTarget::Straight,
+        PracticeTarget::Alternate,
+        PracticeTarget::Random,
+    ];
+
+    /// Nome usado no arquivo de configurações
+    pub fn id(self) -> &'static str {
+        match self {
+            PracticeTarget::Wall => "wall",
+            PracticeTarget::Straight => "straight",
+            PracticeTarget::Alternate => "alternate",
+            PracticeTarget::Random => "random",
+        }
+    }
+
+    /// Chave do catálogo de mensagens com o nome do alvo
+    pub fn label_key(self) -> &'static str {
+        match self {
+            PracticeTarget::Wall => "practice.wall",
+            PracticeTarget::Straight => "practice.straight",
+            PracticeTarget::Alternate => "practice.alternate",
+            PracticeTarget::Random => "practice.random",
+        }
+    }
+}
+
+/// Estado do treino: alvo escolhido e sequências de rebatidas
+pub struct Practice {
+    /// Parede ou padrão do lançador
+    pub target: PracticeTarget,
+    /// Rebatidas seguidas desde a última bola perdida
+    pub streak: u32,
+    /// Maior sequência do treino
+    pub best_streak: u32,
+    /// Total de rebatidas do treino
+    pub returns: u32,
+    /// Bolas perdidas no treino
+    pub misses: u32,
+    /// Bolas atiradas pelo lançador (alterna o padrão "alternado")
+    launches: u32,
+    /// Altura de onde o lançador atirou a última bola
+    launcher_y: Option<f32>,
+}
+
+impl Practice {
+    /// Começa um treino
+    ///
+    /// # Argumentos
+    ///
+    /// * `target` - Parede ou padrão do lançador
+    pub fn new(target: PracticeTarget) -> Practice {
+        Practice {
+            target,
+            streak: 0,
+            best_streak: 0,
+            returns: 0,
+            misses: 0,
+            launches: 0,
+            launcher_y: None,
+        }
+    }
+
+    /// Indica se as bolas saem de um lançador (e não do saque do jogador)
+    pub fn launches_balls(&self) -> bool {
+        self.target != PracticeTarget::Wall
+    }
+
+    /// Conta uma rebatida do jogador
+    pub fn record_return(&mut self) {
+        self.streak += 1;
+        self.returns += 1;
+        self.best_streak = self.best_streak.max(self.streak);
+    }
+
+    /// Conta uma bola perdida (a sequência recomeça)
+    pub fn record_miss(&mut self) {
+        self.streak = 0;
+        self.misses += 1;
+    }
+
+    /// Atira a bola do lançador em direção ao jogador
+    ///
+    /// # Argumentos
+    ///
+    /// * `ball` - Bola do saque
+    /// * `screen_width` - Largura da tela
+    /// * `screen_height` - Altura da tela
+    /// * `rng` - Gerador de números aleatórios da partida
+    pub fn launch(
+        &mut self,
+        ball: &mut Ball,
+        screen_width: f32,
+        screen_height: f32,
+        rng: &mut GameRng,
+    ) {
+        let center_y = screen_height / 2.0;
+        let (y, angle) = match self.target {
+            PracticeTarget::Wall | PracticeTarget::Straight => (center_y, 0.0),
+            // Bolas pares vão para cima e ímpares para baixo
+            PracticeTarget::Alternate => {
+                if self.launches.is_multiple_of(2) {
+                    (center_y, -ALTERNATE_ANGLE)
+                } else {
+                    (center_y, ALTERNATE_ANGLE)
+                }
+            }
+            PracticeTarget::Random => (
+                rng.random_range(screen_height * 0.2..screen_height * 0.8),
+                rng.random_range(-PI / 5.0..PI / 5.0),
+            ),
+        };
+
+        ball.x = screen_width - LAUNCHER_OFFSET;
+        ball.y = y;
+        // Ângulo medido a partir da direção esquerda (PI), positivo para baixo
+        ball.launch_at(PI - angle);
+        self.launches += 1;
+        self.launcher_y = Some(y);
+    }
+
+    /// Rebate a bola na parede direita (somente no treino contra a parede)
+    ///
+    /// # Argumentos
+    ///
+    /// * `ball` - Bola do jogo
+    /// * `screen_width` - Largura da tela
+    /// * `screen_height` - Altura da tela
+    pub fn bounce_wall(&self, ball: &mut Ball, screen_width: f32, screen_height: f32) {
+        if !self.launches_balls() {
+            Wall::Right.bounce(ball, screen_width, screen_height);
+        }
+    }
+
+    /// Desenha a parede ou o lançador no lado direito da quadra
+    ///
+    /// # Argumentos
+    ///
+    /// * `canvas` - Canvas SDL para desenhar
+    /// * `theme` - Tema de cores
+    /// * `screen_width` - Largura da tela
+    /// * `screen_height` - Altura da tela
+    pub fn render(
+        &self,
+        canvas: &mut Canvas<Window>,
+        theme: &Theme,
+        screen_width: f32,
+        screen_height: f32,
+    ) {
+        canvas.set_draw_color(theme.player_right);
+        if !self.launches_balls() {
+            canvas
+                .fill_rect(Wall::Right.rect(screen_width, screen_height))
+                .unwrap();
+            return;
+        }
+
+        let y = self.launcher_y.unwrap_or(screen_height / 2.0);
+        canvas
+            .fill_rect(Rect::new(
+                screen_width as i32 - LAUNCHER_SIZE as i32 / 2,
+                y as i32 - LAUNCHER_SIZE as i32 / 2,
+                LAUNCHER_SIZE,
+                LAUNCHER_SIZE,
+            ))
+            .unwrap();
+    }
+}
+
+impl Snapshot for Practice {
+    fn snapshot(&self) -> String {
+        format!(
+            "{} {} {} {} {} {} {}",
+            self.target.id(),
+            self.streak,
+            self.best_streak,
+            self.returns,
+            self.misses,
+            self.launches,
+            snapshot::optional(self.launcher_y)
+        )
+    }
+
+    fn restore(fields: &mut Fields) -> Result<Practice, String> {
+        Ok(Practice {
+            target: fields.next_id(&PracticeTarget::ALL, PracticeTarget::id)?,
+            streak: fields.next()?,
+            best_streak: fields.next()?,
+            returns: fields.next()?,
+            misses: fields.next()?,
+            launches: fields.next()?,
+            launcher_y: fields.next_optional()?,
+        })
+    }
+}
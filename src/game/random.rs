@@ -0,0 +1,58 @@
+// Gerador de números aleatórios da partida
+
+use rand::{Rng, RngCore};
+
+/// Gerador de números aleatórios da partida (SplitMix64)
+///
+/// Todo sorteio da partida (ângulos de saque, power-ups, erro de mira da CPU)
+/// passa por este gerador. O estado é um único número, gravado nos jogos
+/// salvos para que a partida continue com os mesmos sorteios.
+#[derive(Clone)]
+pub struct GameRng {
+    /// Estado atual do gerador
+    state: u64,
+}
+
+impl GameRng {
+    /// Cria um gerador com uma semente sorteada pelo sistema
+    pub fn from_entropy() -> GameRng {
+        GameRng {
+            state: rand::rng().random(),
+        }
+    }
+
+    /// Recria um gerador a partir de um estado gravado
+    ///
+    /// # Argumentos
+    ///
+    /// * `state` - Estado retornado por `state`
+    pub fn from_state(state: u64) -> GameRng {
+        GameRng { state }
+    }
+
+    /// Retorna o estado atual do gerador
+    pub fn state(&self) -> u64 {
+        self.state
+    }
+}
+
+impl RngCore for GameRng {
+    fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for chunk in dest.chunks_mut(8) {
+            let bytes = self.next_u64().to_le_bytes();
+            chunk.copy_from_slice(&bytes[..chunk.len()]);
+        }
+    }
+}
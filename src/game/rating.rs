@@ -0,0 +1,109 @@
+// Rating de habilidade (Elo) dos perfis de jogadores
+
+use super::ai::Difficulty;
+
+/// Rating de um perfil novo
+pub const INITIAL_RATING: f32 = 1500.0;
+
+/// Ratings guardados no histórico de cada perfil
+pub const RATING_HISTORY_SIZE: usize = 20;
+
+/// Partidas em que o rating de um perfil ainda é provisório
+const PROVISIONAL_MATCHES: u32 = 10;
+
+/// Variação máxima do rating em uma partida (rating provisório)
+const PROVISIONAL_K_FACTOR: f32 = 40.0;
+
+/// Variação máxima do rating em uma partida (rating estabelecido)
+const K_FACTOR: f32 = 24.0;
+
+/// Chance de vitória esperada contra um adversário (de 0 a 1)
+///
+/// # Argumentos
+///
+/// * `rating` - Rating do jogador
+/// * `opponent` - Rating do adversário
+pub fn expected_score(rating: f32, opponent: f32) -> f32 {
+    1.0 / (1.0 + 10f32.powf((opponent - rating) / 400.0))
+}
+
+/// Calcula o rating depois de uma partida
+///
+/// Nas primeiras partidas o rating muda mais rápido, para que um perfil novo
+/// chegue logo perto do seu nível.
+///
+/// # Argumentos
+///
+/// * `rating` - Rating antes da partida
+/// * `opponent` - Rating do adversário antes da partida
+/// * `score` - Resultado (1 = vitória, 0.5 = empate, 0 = derrota)
+/// * `matches` - Partidas com rating já jogadas pelo perfil
+///
+/// # Retorna
+///
+/// O novo rating
+pub fn updated(rating: f32, opponent: f32, score: f32, matches: u32) -> f32 {
+    let k_factor = if matches < PROVISIONAL_MATCHES {
+        PROVISIONAL_K_FACTOR
+    } else {
+        K_FACTOR
+    };
+    rating + k_factor * (score - expected_score(rating, opponent))
+}
+
+/// Dificuldade da CPU com o rating mais próximo do rating do jogador (a
+/// partida mais equilibrada)
+///
+/// # Argumentos
+///
+/// * `rating` - Rating do jogador
+pub fn suggested_difficulty(rating: f32) -> Difficulty {
+    Difficulty::ALL
+        .into_iter()
+        .min_by(|a, b| {
+            (a.rating() - rating)
+                .abs()
+                .total_cmp(&(b.rating() - rating).abs())
+        })
+        .unwrap_or(Difficulty::Normal)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn equal_ratings_move_by_half_the_k_factor() {
+        assert_eq!(
+            updated(1500.0, 1500.0, 1.0, 0),
+            1500.0 + PROVISIONAL_K_FACTOR / 2.0
+        );
+        assert_eq!(
+            updated(1500.0, 1500.0, 0.0, 0),
+            1500.0 - PROVISIONAL_K_FACTOR / 2.0
+        );
+        assert_eq!(updated(1500.0, 1500.0, 0.5, 0), 1500.0);
+        assert_eq!(updated(1500.0, 1500.0, 1.0, 20), 1500.0 + K_FACTOR / 2.0);
+    }
+
+    #[test]
+    fn k_factor_drops_after_the_provisional_matches() {
+        let provisional = updated(1500.0, 1500.0, 1.0, PROVISIONAL_MATCHES - 1) - 1500.0;
+        let established = updated(1500.0, 1500.0, 1.0, PROVISIONAL_MATCHES) - 1500.0;
+        assert_eq!(provisional, PROVISIONAL_K_FACTOR / 2.0);
+        assert_eq!(established, K_FACTOR / 2.0);
+
+        // Vencer um adversário mais forte vale mais
+        assert!(updated(1500.0, 1800.0, 1.0, 20) - 1500.0 > K_FACTOR / 2.0);
+    }
+
+    #[test]
+    fn suggested_difficulty_is_the_closest_rating() {
+        assert!(suggested_difficulty(900.0) == Difficulty::Easy);
+        assert!(suggested_difficulty(1340.0) == Difficulty::Easy);
+        assert!(suggested_difficulty(1360.0) == Difficulty::Normal);
+        assert!(suggested_difficulty(1500.0) == Difficulty::Normal);
+        assert!(suggested_difficulty(1700.0) == Difficulty::Hard);
+        assert!(suggested_difficulty(2400.0) == Difficulty::Hard);
+    }
+}
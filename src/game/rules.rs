@@ -0,0 +1,234 @@
+// Regras configuráveis da partida
+
+use super::game_state::Side;
+use std::cmp::Ordering;
+
+/// Quem saca depois de cada ponto
+#[derive(PartialEq, Clone, Copy)]
+pub enum ServeRule {
+    /// Quem perdeu o ponto saca
+    LoserServes,
+    /// Os jogadores se alternam no saque
+    Alternate,
+    /// Quem ganhou o ponto saca
+    WinnerServes,
+}
+
+impl ServeRule {
+    /// Todas as regras, na ordem em que aparecem no menu
+    pub const ALL: [ServeRule; 3] = [
+        ServeRule::LoserServes,
+        ServeRule::Alternate,
+        ServeRule::WinnerServes,
+    ];
+
+    /// Nome usado no arquivo de configurações
+    pub fn id(self) -> &'static str {
+        match self {
+            ServeRule::LoserServes => "loser",
+            ServeRule::Alternate => "alternate",
+            ServeRule::WinnerServes => "winner",
+        }
+    }
+
+    /// Chave do catálogo de mensagens com o nome da regra
+    pub fn label_key(self) -> &'static str {
+        match self {
+            ServeRule::LoserServes => "serve_rule.loser",
+            ServeRule::Alternate => "serve_rule.alternate",
+            ServeRule::WinnerServes => "serve_rule.winner",
+        }
+    }
+}
+
+/// Como a partida é vencida
+#[derive(PartialEq, Clone, Copy)]
+pub enum WinRule {
+    /// Vence quem fizer primeiro a pontuação alvo
+    FirstTo,
+    /// Precisa de 2 pontos de vantagem a partir da pontuação alvo (com iguais)
+    WinByTwo,
+    /// Como a vantagem de 2 pontos, mas um novo empate na pontuação alvo é
+    /// decidido por um único ponto (ponto de ouro)
+    GoldenPoint,
+    /// Partida com tempo limitado; empate no fim do tempo vai para morte súbita
+    Timed,
+}
+
+/// Situações especiais da partida mostradas no placar
+#[derive(PartialEq, Clone, Copy)]
+pub enum MatchStatus {
+    /// Empate em que ninguém pode vencer com o próximo ponto
+    Deuce,
+    /// Um jogador vence se marcar o próximo ponto
+    Advantage(Side),
+    /// O próximo ponto decide a partida
+    GoldenPoint,
+    /// O tempo acabou empatado; o próximo ponto decide a partida
+    SuddenDeath,
+}
+
+impl WinRule {
+    /// Todas as regras, na ordem em que aparecem no menu
+    pub const ALL: [WinRule; 4] = [
+        WinRule::FirstTo,
+        WinRule::WinByTwo,
+        WinRule::GoldenPoint,
+        WinRule::Timed,
+    ];
+
+    /// Nome usado no arquivo de configurações
+    pub fn id(self) -> &'static str {
+        match self {
+            WinRule::FirstTo => "first_to",
+            WinRule::WinByTwo => "win_by_two",
+            WinRule::GoldenPoint => "golden_point",
+            WinRule::Timed => "timed",
+        }
+    }
+
+    /// Chave do catálogo de mensagens com o nome da regra
+    pub fn label_key(self) -> &'static str {
+        match self {
+            WinRule::FirstTo => "win_rule.first_to",
+            WinRule::WinByTwo => "win_rule.win_by_two",
+            WinRule::GoldenPoint => "win_rule.golden_point",
+            WinRule::Timed => "win_rule.timed",
+        }
+    }
+
+    /// Chave do catálogo de mensagens com a explicação da regra
+    pub fn help_key(self) -> &'static str {
+        match self {
+            WinRule::FirstTo => "win_rule.first_to_help",
+            WinRule::WinByTwo => "win_rule.win_by_two_help",
+            WinRule::GoldenPoint => "win_rule.golden_point_help",
+            WinRule::Timed => "win_rule.timed_help",
+        }
+    }
+
+    /// Indica se a regra usa a pontuação alvo
+    pub fn uses_target(self) -> bool {
+        self != WinRule::Timed
+    }
+
+    /// Retorna o vencedor da partida, se ela já estiver decidida
+    ///
+    /// # Argumentos
+    ///
+    /// * `target` - Pontuação alvo
+    /// * `left` - Pontos do jogador 1
+    /// * `right` - Pontos do jogador 2
+    /// * `time_up` - Se o tempo da partida acabou (somente na partida com tempo)
+    pub fn winner(self, target: u32, left: u32, right: u32, time_up: bool) -> Option<Side> {
+        let (leader, high, low) = match left.cmp(&right) {
+            Ordering::Greater => (Side::Left, left, right),
+            Ordering::Less => (Side::Right, right, left),
+            Ordering::Equal => return None,
+        };
+        let lead = high - low;
+
+        let decided = match self {
+            WinRule::FirstTo => high >= target,
+            WinRule::WinByTwo => high >= target && lead >= 2,
+            WinRule::GoldenPoint => high >= target && (lead >= 2 || high > target),
+            WinRule::Timed => time_up,
+        };
+        decided.then_some(leader)
+    }
+
+    /// Retorna a situação especial da partida (iguais, vantagem, ponto de ouro
+    /// ou morte súbita), se houver
+    ///
+    /// # Argumentos
+    ///
+    /// * `target` - Pontuação alvo
+    /// * `left` - Pontos do jogador 1
+    /// * `right` - Pontos do jogador 2
+    /// * `time_up` - Se o tempo da partida acabou (somente na partida com tempo)
+    pub fn status(self, target: u32, left: u32, right: u32, time_up: bool) -> Option<MatchStatus> {
+        if self == WinRule::Timed {
+            return (time_up && left == right).then_some(MatchStatus::SuddenDeath);
+        }
+
+        // Iguais só existem quando os dois estão a um ponto (ou menos) do alvo
+        // e alguém já marcou (com alvo 1, o 0 a 0 ainda é o início do game)
+        let in_deuce =
+            self != WinRule::FirstTo && left.min(right) + 1 >= target && left.max(right) > 0;
+        if !in_deuce {
+            return None;
+        }
+
+        match left.cmp(&right) {
+            Ordering::Equal if self == WinRule::GoldenPoint && left >= target => {
+                Some(MatchStatus::GoldenPoint)
+            }
+            Ordering::Equal => Some(MatchStatus::Deuce),
+            Ordering::Greater => Some(MatchStatus::Advantage(Side::Left)),
+            Ordering::Less => Some(MatchStatus::Advantage(Side::Right)),
+        }
+    }
+}
+
+impl MatchStatus {
+    /// Chave do catálogo de mensagens com o nome da situação
+    pub fn label_key(self) -> &'static str {
+        match self {
+            MatchStatus::Deuce => "status.deuce",
+            MatchStatus::Advantage(Side::Left) => "status.advantage_player1",
+            MatchStatus::Advantage(Side::Right) => "status.advantage_player2",
+            MatchStatus::GoldenPoint => "status.golden_point",
+            MatchStatus::SuddenDeath => "status.sudden_death",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn first_to_is_decided_by_the_target() {
+        assert!(WinRule::FirstTo.winner(5, 5, 4, false) == Some(Side::Left));
+        assert!(WinRule::FirstTo.winner(5, 4, 4, false).is_none());
+        assert!(WinRule::FirstTo.winner(1, 0, 1, false) == Some(Side::Right));
+        assert!(WinRule::FirstTo.status(5, 4, 4, false).is_none());
+    }
+
+    #[test]
+    fn win_by_two_needs_a_two_point_lead() {
+        assert!(WinRule::WinByTwo.winner(5, 5, 4, false).is_none());
+        assert!(WinRule::WinByTwo.winner(5, 7, 5, false) == Some(Side::Left));
+        assert!(WinRule::WinByTwo.status(5, 3, 3, false).is_none());
+        assert!(WinRule::WinByTwo.status(5, 4, 4, false) == Some(MatchStatus::Deuce));
+        assert!(
+            WinRule::WinByTwo.status(5, 5, 6, false) == Some(MatchStatus::Advantage(Side::Right))
+        );
+    }
+
+    #[test]
+    fn golden_point_decides_a_second_tie() {
+        assert!(WinRule::GoldenPoint.status(5, 4, 4, false) == Some(MatchStatus::Deuce));
+        assert!(WinRule::GoldenPoint.status(5, 5, 5, false) == Some(MatchStatus::GoldenPoint));
+        assert!(WinRule::GoldenPoint.winner(5, 6, 5, false) == Some(Side::Left));
+    }
+
+    #[test]
+    fn target_one_has_no_deuce_before_the_first_point() {
+        for rule in [WinRule::WinByTwo, WinRule::GoldenPoint] {
+            assert!(rule.status(1, 0, 0, false).is_none());
+            assert!(rule.status(1, 1, 0, false) == Some(MatchStatus::Advantage(Side::Left)));
+            assert!(rule.winner(1, 1, 0, false).is_none());
+        }
+        assert!(WinRule::WinByTwo.status(1, 1, 1, false) == Some(MatchStatus::Deuce));
+        assert!(WinRule::GoldenPoint.status(1, 1, 1, false) == Some(MatchStatus::GoldenPoint));
+    }
+
+    #[test]
+    fn timed_match_goes_to_sudden_death_when_tied() {
+        assert!(WinRule::Timed.winner(5, 9, 3, false).is_none());
+        assert!(WinRule::Timed.winner(5, 2, 3, true) == Some(Side::Right));
+        assert!(WinRule::Timed.status(5, 3, 3, false).is_none());
+        assert!(WinRule::Timed.status(5, 3, 3, true) == Some(MatchStatus::SuddenDeath));
+    }
+}
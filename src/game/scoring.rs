@@ -0,0 +1,248 @@
+// Placar da partida em sets e games, e estatísticas para o resumo final
+
+use super::game_state::Side;
+use super::snapshot::{Fields, Snapshot};
+
+/// Placar de sets e games (os pontos do game atual ficam no `GameState`)
+///
+/// Cada game é decidido pela regra de vitória; quem vence `games_per_set`
+/// games leva o set, e quem vence a maioria dos sets leva a partida.
+pub struct MatchScore {
+    /// Número máximo de sets da partida (melhor de N)
+    pub best_of_sets: u32,
+    /// Games necessários para vencer um set
+    pub games_per_set: u32,
+    /// Games do jogador 1 no set atual
+    pub games_left: u32,
+    /// Games do jogador 2 no set atual
+    pub games_right: u32,
+    /// Sets vencidos pelo jogador 1
+    pub sets_left: u32,
+    /// Sets vencidos pelo jogador 2
+    pub sets_right: u32,
+    /// Games de cada jogador nos sets já terminados
+    pub finished_sets: Vec<(u32, u32)>,
+}
+
+impl MatchScore {
+    /// Cria um placar zerado
+    ///
+    /// # Argumentos
+    ///
+    /// * `best_of_sets` - Número máximo de sets (melhor de N)
+    /// * `games_per_set` - Games necessários para vencer um set
+    pub fn new(best_of_sets: u32, games_per_set: u32) -> MatchScore {
+        MatchScore {
+            best_of_sets,
+            games_per_set,
+            games_left: 0,
+            games_right: 0,
+            sets_left: 0,
+            sets_right: 0,
+            finished_sets: Vec::new(),
+        }
+    }
+
+    /// Zera o placar mantendo o formato da partida
+    pub fn reset(&mut self) {
+        *self = MatchScore::new(self.best_of_sets, self.games_per_set);
+    }
+
+    /// Sets necessários para vencer a partida
+    pub fn sets_to_win(&self) -> u32 {
+        self.best_of_sets / 2 + 1
+    }
+
+    /// Indica se a partida tem mais de um game (e o placar de sets e games
+    /// precisa aparecer na tela)
+    pub fn has_games(&self) -> bool {
+        self.best_of_sets > 1 || self.games_per_set > 1
+    }
+
+    /// Registra o fim de um game
+    ///
+    /// # Argumentos
+    ///
+    /// * `winner` - Lado do jogador que venceu o game
+    ///
+    /// # Retorna
+    ///
+    /// O vencedor da partida, se este game a decidiu
+    pub fn record_game(&mut self, winner: Side) -> Option<Side> {
+        match winner {
+            Side::Left => self.games_left += 1,
+            Side::Right => self.games_right += 1,
+        }
+
+        if self.games_left.max(self.games_right) < self.games_per_set {
+            return None;
+        }
+
+        // Fim do set
+        self.finished_sets.push((self.games_left, self.games_right));
+        self.games_left = 0;
+        self.games_right = 0;
+        match winner {
+            Side::Left => self.sets_left += 1,
+            Side::Right => self.sets_right += 1,
+        }
+
+        let sets_to_win = self.sets_to_win();
+        if self.sets_left >= sets_to_win || self.sets_right >= sets_to_win {
+            Some(winner)
+        } else {
+            None
+        }
+    }
+}
+
+/// Gravação do placar do set atual e dos sets vencidos (os sets terminados
+/// são gravados à parte, um por linha)
+impl Snapshot for MatchScore {
+    fn snapshot(&self) -> String {
+        format!(
+            "{} {} {} {} {} {}",
+            self.best_of_sets,
+            self.games_per_set,
+            self.games_left,
+            self.games_right,
+            self.sets_left,
+            self.sets_right
+        )
+    }
+
+    fn restore(fields: &mut Fields) -> Result<MatchScore, String> {
+        Ok(MatchScore {
+            best_of_sets: fields.next()?,
+            games_per_set: fields.next()?,
+            games_left: fields.next()?,
+            games_right: fields.next()?,
+            sets_left: fields.next()?,
+            sets_right: fields.next()?,
+            finished_sets: Vec::new(),
+        })
+    }
+}
+
+/// Estatísticas da partida mostradas no resumo final
+#[derive(Default)]
+pub struct MatchStats {
+    /// Total de pontos marcados pelo jogador 1
+    pub points_left: u32,
+    /// Total de pontos marcados pelo jogador 2
+    pub points_right: u32,
+    /// Rebatidas na troca de bola atual
+    pub rally: u32,
+    /// Maior número de rebatidas em uma troca de bola
+    pub longest_rally: u32,
+    /// Tempo de bola em jogo (em segundos)
+    pub duration: f32,
+    /// Velocidade da bola mais rápida rebatida por cada lado (pixels por segundo)
+    pub fastest_return: [f32; 2],
+}
+
+impl MatchStats {
+    /// Registra uma rebatida
+    pub fn record_hit(&mut self) {
+        self.rally += 1;
+        self.longest_rally = self.longest_rally.max(self.rally);
+    }
+
+    /// Registra a velocidade de uma bola rebatida por um lado
+    ///
+    /// # Argumentos
+    ///
+    /// * `side` - Lado do jogador que rebateu a bola
+    /// * `speed` - Velocidade da bola ao chegar na raquete (pixels por segundo)
+    pub fn record_return(&mut self, side: Side, speed: f32) {
+        let fastest = &mut self.fastest_return[side.player()];
+        *fastest = fastest.max(speed);
+    }
+
+    /// Registra um ponto e encerra a troca de bola
+    ///
+    /// # Argumentos
+    ///
+    /// * `scorer` - Lado do jogador que marcou o ponto
+    pub fn record_point(&mut self, scorer: Side) {
+        match scorer {
+            Side::Left => self.points_left += 1,
+            Side::Right => self.points_right += 1,
+        }
+        self.end_rally();
+    }
+
+    /// Encerra a troca de bola atual
+    pub fn end_rally(&mut self) {
+        self.rally = 0;
+    }
+}
+
+impl Snapshot for MatchStats {
+    fn snapshot(&self) -> String {
+        format!(
+            "{} {} {} {} {} {} {}",
+            self.points_left,
+            self.points_right,
+            self.rally,
+            self.longest_rally,
+            self.duration,
+            self.fastest_return[0],
+            self.fastest_return[1]
+        )
+    }
+
+    fn restore(fields: &mut Fields) -> Result<MatchStats, String> {
+        Ok(MatchStats {
+            points_left: fields.next()?,
+            points_right: fields.next()?,
+            rally: fields.next()?,
+            longest_rally: fields.next()?,
+            duration: fields.next()?,
+            fastest_return: [fields.next()?, fields.next()?],
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn single_game_match_ends_with_the_first_game() {
+        let mut score = MatchScore::new(1, 1);
+
+        assert!(!score.has_games());
+        assert!(score.record_game(Side::Right) == Some(Side::Right));
+        assert_eq!(score.finished_sets, vec![(0, 1)]);
+    }
+
+    #[test]
+    fn set_ends_when_a_player_reaches_the_games_per_set() {
+        let mut score = MatchScore::new(3, 2);
+
+        assert_eq!(score.sets_to_win(), 2);
+        assert!(score.record_game(Side::Left).is_none());
+        assert!(score.record_game(Side::Right).is_none());
+        assert!(score.record_game(Side::Left).is_none());
+        assert_eq!(
+            (score.sets_left, score.games_left, score.games_right),
+            (1, 0, 0)
+        );
+        assert_eq!(score.finished_sets, vec![(2, 1)]);
+    }
+
+    #[test]
+    fn match_ends_when_a_player_wins_most_sets() {
+        let mut score = MatchScore::new(3, 1);
+
+        assert!(score.record_game(Side::Left).is_none());
+        assert!(score.record_game(Side::Right).is_none());
+        assert!(score.record_game(Side::Right) == Some(Side::Right));
+        assert_eq!((score.sets_left, score.sets_right), (1, 2));
+
+        score.reset();
+        assert_eq!((score.best_of_sets, score.games_per_set), (3, 1));
+        assert!(score.finished_sets.is_empty());
+    }
+}
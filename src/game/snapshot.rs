@@ -0,0 +1,132 @@
+// Conversão dos objetos da partida para texto e de volta (jogos salvos)
+
+use std::fmt::Display;
+use std::str::{FromStr, SplitWhitespace};
+
+/// Objetos da partida que podem ser gravados em uma linha de texto
+pub trait Snapshot: Sized {
+    /// Valores do objeto, separados por espaços
+    fn snapshot(&self) -> String;
+
+    /// Recria o objeto a partir dos valores gravados por `snapshot`
+    ///
+    /// # Argumentos
+    ///
+    /// * `fields` - Valores da linha, lidos em ordem
+    fn restore(fields: &mut Fields) -> Result<Self, String>;
+}
+
+/// Valores de uma linha de um jogo salvo, lidos em ordem
+pub struct Fields<'a> {
+    /// Valores ainda não lidos
+    values: SplitWhitespace<'a>,
+}
+
+impl<'a> Fields<'a> {
+    /// Separa os valores de uma linha
+    ///
+    /// # Argumentos
+    ///
+    /// * `text` - Valores separados por espaços
+    pub fn new(text: &'a str) -> Fields<'a> {
+        Fields {
+            values: text.split_whitespace(),
+        }
+    }
+
+    /// Lê o próximo valor
+    pub fn next<T: FromStr>(&mut self) -> Result<T, String> {
+        let value = self.values.next().ok_or("faltam valores")?;
+        value
+            .parse()
+            .map_err(|_| format!("valor inválido '{}'", value))
+    }
+
+    /// Lê um valor gravado com `optional` (`-` = sem valor)
+    pub fn next_optional<T: FromStr>(&mut self) -> Result<Option<T>, String> {
+        match self.values.clone().next() {
+            Some("-") => {
+                self.values.next();
+                Ok(None)
+            }
+            _ => self.next().map(Some),
+        }
+    }
+
+    /// Lê o identificador de uma opção
+    ///
+    /// # Argumentos
+    ///
+    /// * `options` - Opções possíveis
+    /// * `id` - Identificador de cada opção
+    pub fn next_id<T: Copy>(
+        &mut self,
+        options: &[T],
+        id: fn(T) -> &'static str,
+    ) -> Result<T, String> {
+        let value = self.values.next().ok_or("faltam valores")?;
+        options
+            .iter()
+            .copied()
+            .find(|option| id(*option) == value)
+            .ok_or_else(|| format!("valor desconhecido '{}'", value))
+    }
+
+    /// Confere que todos os valores da linha foram lidos
+    pub fn finish(mut self) -> Result<(), String> {
+        match self.values.next() {
+            Some(value) => Err(format!("valor a mais '{}'", value)),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Grava um valor opcional (`-` = sem valor), lido com `Fields::next_optional`
+pub fn optional<T: Display>(value: Option<T>) -> String {
+    match value {
+        Some(value) => value.to_string(),
+        None => String::from("-"),
+    }
+}
+
+/// Lê uma linha com um único valor
+///
+/// # Argumentos
+///
+/// * `text` - Valor da linha
+pub fn value<T: FromStr>(text: &str) -> Result<T, String> {
+    let mut fields = Fields::new(text);
+    let value = fields.next()?;
+    fields.finish()?;
+    Ok(value)
+}
+
+/// Lê uma linha com o identificador de uma opção
+///
+/// # Argumentos
+///
+/// * `text` - Identificador da opção
+/// * `options` - Opções possíveis
+/// * `id` - Identificador de cada opção
+pub fn id_value<T: Copy>(
+    text: &str,
+    options: &[T],
+    id: fn(T) -> &'static str,
+) -> Result<T, String> {
+    let mut fields = Fields::new(text);
+    let value = fields.next_id(options, id)?;
+    fields.finish()?;
+    Ok(value)
+}
+
+/// Lê uma linha inteira com `Snapshot::restore`
+///
+/// # Argumentos
+///
+/// * `text` - Valores da linha
+pub fn restore<T: Snapshot>(text: &str) -> Result<T, String> {
+    let mut fields = Fields::new(text);
+    let value = T::restore(&mut fields)?;
+    fields.finish()?;
+    Ok(value)
+}
@@ -0,0 +1,250 @@
+// Temas de cores do jogo (paletas para fundo, raquetes, bola e textos)
+
+use crate::storage::kv;
+use sdl2::pixels::Color;
+use std::fs;
+use std::path::Path;
+
+/// Extensão dos arquivos de tema em `assets/themes`
+const THEME_EXTENSION: &str = "theme";
+
+/// Paleta de cores usada para desenhar o jogo e a interface
+#[derive(Clone)]
+pub struct Theme {
+    /// Identificador do tema (usado para traduzir o nome e salvar a escolha)
+    pub id: String,
+    /// Nome exibido no menu
+    pub name: String,
+    /// Cor de fundo da tela
+    pub background: Color,
+    /// Cor da linha central pontilhada
+    pub center_line: Color,
+    /// Cor do jogador 1 (raquete esquerda, placar e mensagens)
+    pub player_left: Color,
+    /// Cor do jogador 2 (raquete direita, placar e mensagens)
+    pub player_right: Color,
+    /// Cor do jogador 3 (raquete de cima no modo de 4 jogadores)
+    pub player_top: Color,
+    /// Cor do jogador 4 (raquete de baixo no modo de 4 jogadores)
+    pub player_bottom: Color,
+    /// Cor da bola
+    pub ball: Color,
+    /// Cor do texto comum da interface
+    pub text: Color,
+    /// Cor do texto secundário (dicas, "ESC PARA SAIR")
+    pub text_dim: Color,
+    /// Cor de destaque (regras, "PAUSADO")
+    pub highlight: Color,
+    /// Cor de ação principal ("PRESSIONE ESPAÇO")
+    pub accent: Color,
+}
+
+impl Theme {
+    /// Tema clássico (cores originais do jogo)
+    pub fn classic() -> Theme {
+        Theme {
+            id: String::from("classic"),
+            name: String::from("Clássico"),
+            background: Color::RGB(20, 20, 30),
+            center_line: Color::RGB(80, 80, 90),
+            player_left: Color::RGB(100, 200, 255),
+            player_right: Color::RGB(255, 100, 100),
+            player_top: Color::RGB(100, 255, 100),
+            player_bottom: Color::RGB(255, 100, 255),
+            ball: Color::RGB(255, 255, 100),
+            text: Color::RGB(200, 200, 200),
+            text_dim: Color::RGB(150, 150, 150),
+            highlight: Color::RGB(255, 255, 100),
+            accent: Color::RGB(100, 255, 100),
+        }
+    }
+
+    /// Tema seguro para deuteranopia (paleta Okabe-Ito: azul e laranja)
+    pub fn deuteranopia() -> Theme {
+        Theme {
+            id: String::from("deuteranopia"),
+            name: String::from("Deuteranopia"),
+            background: Color::RGB(20, 20, 30),
+            center_line: Color::RGB(90, 90, 100),
+            player_left: Color::RGB(86, 180, 233),
+            player_right: Color::RGB(230, 159, 0),
+            player_top: Color::RGB(0, 158, 115),
+            player_bottom: Color::RGB(204, 121, 167),
+            ball: Color::RGB(240, 240, 240),
+            text: Color::RGB(210, 210, 210),
+            text_dim: Color::RGB(150, 150, 150),
+            highlight: Color::RGB(240, 228, 66),
+            accent: Color::RGB(86, 180, 233),
+        }
+    }
+
+    /// Tema seguro para protanopia (azul e amarelo, sem vermelho)
+    pub fn protanopia() -> Theme {
+        Theme {
+            id: String::from("protanopia"),
+            name: String::from("Protanopia"),
+            background: Color::RGB(15, 15, 25),
+            center_line: Color::RGB(90, 90, 100),
+            player_left: Color::RGB(100, 143, 255),
+            player_right: Color::RGB(255, 176, 0),
+            player_top: Color::RGB(120, 94, 240),
+            player_bottom: Color::RGB(220, 38, 127),
+            ball: Color::RGB(240, 240, 240),
+            text: Color::RGB(210, 210, 210),
+            text_dim: Color::RGB(150, 150, 150),
+            highlight: Color::RGB(255, 176, 0),
+            accent: Color::RGB(100, 143, 255),
+        }
+    }
+
+    /// Tema de alto contraste (fundo preto e cores saturadas)
+    pub fn high_contrast() -> Theme {
+        Theme {
+            id: String::from("high_contrast"),
+            name: String::from("Alto Contraste"),
+            background: Color::RGB(0, 0, 0),
+            center_line: Color::RGB(255, 255, 255),
+            player_left: Color::RGB(0, 255, 255),
+            player_right: Color::RGB(255, 255, 0),
+            player_top: Color::RGB(0, 255, 0),
+            player_bottom: Color::RGB(255, 0, 255),
+            ball: Color::RGB(255, 255, 255),
+            text: Color::RGB(255, 255, 255),
+            text_dim: Color::RGB(220, 220, 220),
+            highlight: Color::RGB(255, 255, 0),
+            accent: Color::RGB(0, 255, 255),
+        }
+    }
+
+    /// Retorna a cor de um jogador
+    ///
+    /// # Argumentos
+    ///
+    /// * `player` - Índice do jogador (0 a 3)
+    pub fn player_color(&self, player: usize) -> Color {
+        match player {
+            0 => self.player_left,
+            1 => self.player_right,
+            2 => self.player_top,
+            _ => self.player_bottom,
+        }
+    }
+
+    /// Retorna todos os temas embutidos no jogo
+    pub fn builtin() -> Vec<Theme> {
+        vec![
+            Theme::classic(),
+            Theme::deuteranopia(),
+            Theme::protanopia(),
+            Theme::high_contrast(),
+        ]
+    }
+
+    /// Lê um tema a partir do texto de um arquivo de tema
+    ///
+    /// Cores que não aparecem no arquivo mantêm o valor do tema clássico.
+    ///
+    /// # Argumentos
+    ///
+    /// * `text` - Conteúdo no formato "chave = #RRGGBB"
+    pub fn parse(text: &str) -> Result<Theme, String> {
+        let mut theme = Theme::classic();
+        theme.id = String::from("custom");
+        theme.name = String::from("Personalizado");
+
+        for entry in kv::parse(text)? {
+            if entry.key == "name" {
+                theme.name = entry.value;
+                continue;
+            }
+
+            let color = parse_color(&entry.value)
+                .ok_or_else(|| format!("linha {}: cor inválida '{}'", entry.line, entry.value))?;
+
+            match entry.key.as_str() {
+                "background" => theme.background = color,
+                "center_line" => theme.center_line = color,
+                "player_left" => theme.player_left = color,
+                "player_right" => theme.player_right = color,
+                "player_top" => theme.player_top = color,
+                "player_bottom" => theme.player_bottom = color,
+                "ball" => theme.ball = color,
+                "text" => theme.text = color,
+                "text_dim" => theme.text_dim = color,
+                "highlight" => theme.highlight = color,
+                "accent" => theme.accent = color,
+                other => {
+                    return Err(format!(
+                        "linha {}: chave desconhecida '{}'",
+                        entry.line, other
+                    ));
+                }
+            }
+        }
+
+        Ok(theme)
+    }
+
+    /// Carrega um tema de um arquivo
+    ///
+    /// # Argumentos
+    ///
+    /// * `path` - Caminho do arquivo `.theme`
+    pub fn load_from_file(path: &Path) -> Result<Theme, String> {
+        let text = fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+        let mut theme = Theme::parse(&text).map_err(|e| format!("{}: {}", path.display(), e))?;
+
+        // O nome do arquivo identifica o tema
+        if let Some(stem) = path.file_stem().and_then(|stem| stem.to_str()) {
+            theme.id = stem.to_string();
+        }
+
+        Ok(theme)
+    }
+}
+
+/// Carrega os temas embutidos e os temas do diretório informado
+///
+/// Arquivos inválidos são ignorados e informados no terminal, para que um
+/// tema com erro não impeça o jogo de iniciar.
+///
+/// # Argumentos
+///
+/// * `dir` - Diretório com arquivos `.theme`
+pub fn load_themes(dir: &Path) -> Vec<Theme> {
+    let mut themes = Theme::builtin();
+
+    let Ok(read_dir) = fs::read_dir(dir) else {
+        return themes;
+    };
+
+    // Ordena os arquivos para que a ordem dos temas seja sempre a mesma
+    let mut paths: Vec<_> = read_dir
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == THEME_EXTENSION))
+        .collect();
+    paths.sort();
+
+    for path in paths {
+        match Theme::load_from_file(&path) {
+            Ok(theme) => themes.push(theme),
+            Err(e) => eprintln!("Tema ignorado: {}", e),
+        }
+    }
+
+    themes
+}
+
+/// Converte um texto "#RRGGBB" em cor
+fn parse_color(value: &str) -> Option<Color> {
+    let hex = value.strip_prefix('#')?;
+    if hex.len() != 6 {
+        return None;
+    }
+
+    let r = u8::from_str_radix(hex.get(0..2)?, 16).ok()?;
+    let g = u8::from_str_radix(hex.get(2..4)?, 16).ok()?;
+    let b = u8::from_str_radix(hex.get(4..6)?, 16).ok()?;
+
+    Some(Color::RGB(r, g, b))
+}
@@ -0,0 +1,736 @@
+// Torneios: eliminatória simples, eliminatória dupla e todos contra todos
+
+use super::ai::Difficulty;
+use super::game_state::Side;
+use super::random::GameRng;
+use rand::seq::SliceRandom;
+
+/// Número mínimo de jogadores inscritos
+pub const TOURNAMENT_MIN_PLAYERS: usize = 4;
+
+/// Número máximo de jogadores inscritos
+pub const TOURNAMENT_MAX_PLAYERS: usize = 32;
+
+/// Dificuldade da CPU que completa um número ímpar de jogadores
+const FILL_IN_DIFFICULTY: Difficulty = Difficulty::Normal;
+
+/// Confere se um nome pode ser inscrito no torneio
+///
+/// # Argumentos
+///
+/// * `players` - Nomes já inscritos
+/// * `name` - Nome a ser inscrito
+///
+/// # Retorna
+///
+/// A chave do catálogo de mensagens com o problema do nome, ou `None` se
+/// ele pode ser inscrito
+pub fn player_name_error(players: &[String], name: &str) -> Option<&'static str> {
+    if players.len() >= TOURNAMENT_MAX_PLAYERS {
+        Some("tournament.full")
+    } else if name.trim().is_empty() {
+        Some("tournament.name_empty")
+    } else if players
+        .iter()
+        .any(|player| player.eq_ignore_ascii_case(name.trim()))
+    {
+        Some("tournament.name_taken")
+    } else {
+        None
+    }
+}
+
+/// Formatos de torneio
+#[derive(PartialEq, Clone, Copy)]
+pub enum TournamentFormat {
+    /// Quem perde uma partida está fora
+    SingleElimination,
+    /// Quem perde duas partidas está fora (chave dos vencedores e dos perdedores)
+    DoubleElimination,
+    /// Todos jogam contra todos; vence quem tiver mais vitórias
+    RoundRobin,
+}
+
+impl TournamentFormat {
+    /// Todos os formatos, na ordem em que aparecem no menu
+    pub const ALL: [TournamentFormat; 3] = [
+        TournamentFormat::SingleElimination,
+        TournamentFormat::DoubleElimination,
+        TournamentFormat::RoundRobin,
+    ];
+
+    /// Chave do catálogo de mensagens com o nome do formato
+    pub fn label_key(self) -> &'static str {
+        match self {
+            TournamentFormat::SingleElimination => "tournament.single_elimination",
+            TournamentFormat::DoubleElimination => "tournament.double_elimination",
+            TournamentFormat::RoundRobin => "tournament.round_robin",
+        }
+    }
+
+    /// Derrotas que eliminam um jogador (`None` no todos contra todos)
+    fn elimination_losses(self) -> Option<u32> {
+        match self {
+            TournamentFormat::SingleElimination => Some(1),
+            TournamentFormat::DoubleElimination => Some(2),
+            TournamentFormat::RoundRobin => None,
+        }
+    }
+}
+
+/// Participante do torneio
+pub struct Entrant {
+    /// Nome do jogador
+    pub name: String,
+    /// Dificuldade, quando o participante é a CPU que completa a chave
+    pub cpu: Option<Difficulty>,
+    /// Partidas vencidas
+    pub wins: u32,
+    /// Partidas perdidas
+    pub losses: u32,
+    /// Pontos marcados
+    pub points_for: u32,
+    /// Pontos sofridos
+    pub points_against: u32,
+}
+
+/// Chave de uma partida na eliminatória dupla
+#[derive(PartialEq, Clone, Copy)]
+pub enum Bracket {
+    /// Chave dos vencedores (ou a única chave dos outros formatos)
+    Winners,
+    /// Chave dos perdedores (jogadores com uma derrota)
+    Losers,
+    /// Final (na eliminatória dupla, entre o último invicto e o vencedor da
+    /// chave dos perdedores, e a nova final quando o invicto perde)
+    Final,
+}
+
+/// Uma partida de uma rodada
+pub struct Pairing {
+    /// Jogador da esquerda (índice em `entrants`)
+    pub left: usize,
+    /// Jogador da direita (`None` = folga: o jogador da esquerda avança)
+    pub right: Option<usize>,
+    /// Chave da partida
+    pub bracket: Bracket,
+    /// Vencedor (`None` enquanto a partida não foi jogada)
+    pub winner: Option<usize>,
+    /// Placar (esquerda, direita); `None` enquanto a partida não foi jogada
+    pub score: Option<(u32, u32)>,
+}
+
+/// Jogadores de uma partida de torneio, já nos lados da quadra
+pub struct TournamentMatch {
+    /// Jogador da raquete esquerda (sempre uma pessoa)
+    pub left: String,
+    /// Jogador da raquete direita
+    pub right: String,
+    /// Dificuldade da CPU da direita (`None` se a direita também é uma pessoa)
+    pub cpu: Option<Difficulty>,
+}
+
+/// Torneio em andamento
+pub struct Tournament {
+    /// Formato do torneio
+    pub format: TournamentFormat,
+    /// Participantes (pessoas e CPUs)
+    pub entrants: Vec<Entrant>,
+    /// Rodadas já sorteadas (no todos contra todos, todas desde o início)
+    pub rounds: Vec<Vec<Pairing>>,
+    /// Índice da rodada atual
+    pub round: usize,
+    /// Campeão (definido quando o torneio termina)
+    pub champion: Option<usize>,
+    /// Partida em jogo (índice na rodada atual) e se os lados foram trocados
+    /// (a pessoa sempre joga na esquerda contra a CPU)
+    playing: Option<(usize, bool)>,
+}
+
+impl Tournament {
+    /// Inscreve os jogadores e sorteia a primeira rodada
+    ///
+    /// A ordem dos jogadores é sorteada e uma CPU entra quando o número de
+    /// jogadores é ímpar. Nas eliminatórias, os primeiros da chave folgam na
+    /// primeira rodada até que restem 4, 8, 16 ou 32 jogadores na segunda.
+    ///
+    /// # Argumentos
+    ///
+    /// * `format` - Formato do torneio
+    /// * `names` - Nomes dos jogadores inscritos
+    /// * `rng` - Gerador de números aleatórios da partida (sorteio da chave)
+    pub fn new(format: TournamentFormat, names: &[String], rng: &mut GameRng) -> Tournament {
+        let mut entrants: Vec<Entrant> = names
+            .iter()
+            .map(|name| Entrant::new(name.clone(), None))
+            .collect();
+        if names.len() % 2 == 1 {
+            entrants.push(Entrant::new(String::from("CPU"), Some(FILL_IN_DIFFICULTY)));
+        }
+        entrants.shuffle(rng);
+
+        let mut tournament = Tournament {
+            format,
+            entrants,
+            rounds: Vec::new(),
+            round: 0,
+            champion: None,
+            playing: None,
+        };
+        match format {
+            TournamentFormat::RoundRobin => tournament.schedule_round_robin(),
+            _ => tournament.schedule_first_round(),
+        }
+        tournament.advance();
+        tournament
+    }
+
+    /// Partidas da rodada atual
+    pub fn current_round(&self) -> &[Pairing] {
+        self.rounds
+            .get(self.round)
+            .map(Vec::as_slice)
+            .unwrap_or(&[])
+    }
+
+    /// Índice (na rodada atual) da próxima partida a ser jogada
+    pub fn next_pairing(&self) -> Option<usize> {
+        self.current_round()
+            .iter()
+            .position(|pairing| pairing.winner.is_none())
+    }
+
+    /// Participantes ainda na disputa (todos, no todos contra todos)
+    pub fn remaining(&self) -> usize {
+        match self.format.elimination_losses() {
+            Some(limit) => self
+                .entrants
+                .iter()
+                .filter(|entrant| entrant.losses < limit)
+                .count(),
+            None => self.entrants.len(),
+        }
+    }
+
+    /// Participantes do maior para o menor número de vitórias (desempate pelo
+    /// saldo de pontos)
+    pub fn standings(&self) -> Vec<usize> {
+        let mut order: Vec<usize> = (0..self.entrants.len()).collect();
+        order.sort_by_key(|index| {
+            let entrant = &self.entrants[*index];
+            let balance = entrant.points_for as i64 - entrant.points_against as i64;
+            (std::cmp::Reverse(entrant.wins), std::cmp::Reverse(balance))
+        });
+        order
+    }
+
+    /// Começa a próxima partida da rodada
+    ///
+    /// # Retorna
+    ///
+    /// Os jogadores da partida, ou `None` se o torneio terminou
+    pub fn start_next(&mut self) -> Option<TournamentMatch> {
+        let index = self.next_pairing()?;
+        let pairing = &self.rounds[self.round][index];
+        let right = pairing.right?;
+
+        // Contra a CPU, a pessoa joga na esquerda
+        let swapped = self.entrants[pairing.left].cpu.is_some();
+        let (left, right) = if swapped {
+            (right, pairing.left)
+        } else {
+            (pairing.left, right)
+        };
+        self.playing = Some((index, swapped));
+
+        Some(TournamentMatch {
+            left: self.entrants[left].name.clone(),
+            right: self.entrants[right].name.clone(),
+            cpu: self.entrants[right].cpu,
+        })
+    }
+
+    /// Registra o resultado da partida começada por `start_next` e avança o
+    /// torneio (sorteia a próxima rodada ou define o campeão)
+    ///
+    /// # Argumentos
+    ///
+    /// * `winner` - Lado da quadra que venceu
+    /// * `left_points` - Pontos do jogador da esquerda
+    /// * `right_points` - Pontos do jogador da direita
+    pub fn record_result(&mut self, winner: Side, left_points: u32, right_points: u32) {
+        let Some((index, swapped)) = self.playing.take() else {
+            return;
+        };
+
+        // Converte os lados da quadra para os lados da chave
+        let (winner, score) = if swapped {
+            (winner.opponent(), (right_points, left_points))
+        } else {
+            (winner, (left_points, right_points))
+        };
+        self.finish_pairing(index, winner, score);
+        self.advance();
+    }
+
+    /// Sorteia as rodadas do todos contra todos (método do círculo: o
+    /// primeiro jogador fica parado e os outros giram a cada rodada)
+    fn schedule_round_robin(&mut self) {
+        let count = self.entrants.len();
+        let mut circle: Vec<usize> = (0..count).collect();
+
+        for _ in 0..count - 1 {
+            let round = (0..count / 2)
+                .map(|index| {
+                    Pairing::new(
+                        circle[index],
+                        Some(circle[count - 1 - index]),
+                        Bracket::Winners,
+                    )
+                })
+                .collect();
+            self.rounds.push(round);
+            circle[1..].rotate_right(1);
+        }
+    }
+
+    /// Monta a primeira rodada de eliminatória
+    ///
+    /// Os primeiros da chave folgam para que o número de jogadores da segunda
+    /// rodada seja uma potência de 2 (com 18 jogadores, 14 folgam e os outros
+    /// 4 jogam as 2 partidas que completam os 16).
+    fn schedule_first_round(&mut self) {
+        let count = self.entrants.len();
+        let byes = count.next_power_of_two() - count;
+
+        let mut round: Vec<Pairing> = (0..byes)
+            .map(|index| Pairing::new(index, None, Bracket::Winners))
+            .collect();
+        for left in (byes..count).step_by(2) {
+            round.push(Pairing::new(left, Some(left + 1), Bracket::Winners));
+        }
+        self.rounds.push(round);
+    }
+
+    /// Sorteia uma rodada de eliminatória
+    ///
+    /// Os jogadores com o mesmo número de derrotas se enfrentam na ordem da
+    /// chave e quem sobra folga na rodada. Quando restam só o último invicto e
+    /// o último da chave dos perdedores, os dois fazem a final.
+    ///
+    /// # Argumentos
+    ///
+    /// * `order` - Jogadores na ordem da chave
+    fn schedule_elimination_round(&mut self, order: &[usize]) {
+        let Some(limit) = self.format.elimination_losses() else {
+            return;
+        };
+
+        let groups: Vec<Vec<usize>> = (0..limit)
+            .map(|losses| {
+                order
+                    .iter()
+                    .copied()
+                    .filter(|index| self.entrants[*index].losses == losses)
+                    .collect()
+            })
+            .collect();
+
+        let mut round = Vec::new();
+        let mut leftovers = Vec::new();
+        for (losses, group) in groups.iter().enumerate() {
+            let bracket = if losses == 0 {
+                Bracket::Winners
+            } else {
+                Bracket::Losers
+            };
+            for pair in group.chunks(2) {
+                match pair {
+                    [left, right] => round.push(Pairing::new(*left, Some(*right), bracket)),
+                    [single] => leftovers.push((*single, bracket)),
+                    _ => {}
+                }
+            }
+        }
+
+        // Sobrando só um jogador em cada chave, os dois fazem a final
+        match leftovers[..] {
+            [(left, _), (right, _)] if self.remaining() == 2 => {
+                round.push(Pairing::new(left, Some(right), Bracket::Final));
+            }
+            _ => {
+                for (single, bracket) in leftovers {
+                    round.push(Pairing::new(single, None, bracket));
+                }
+            }
+        }
+
+        // Com dois jogadores na disputa, a única partida é a final
+        if let [pairing] = &mut round[..] {
+            if self.remaining() == 2 {
+                pairing.bracket = Bracket::Final;
+            }
+        }
+        self.rounds.push(round);
+    }
+
+    /// Resolve as folgas e passa para a próxima rodada enquanto não houver
+    /// uma partida a ser jogada
+    fn advance(&mut self) {
+        loop {
+            for pairing in &mut self.rounds[self.round] {
+                if pairing.right.is_none() {
+                    pairing.winner = Some(pairing.left);
+                }
+            }
+
+            if self.next_pairing().is_some() {
+                return;
+            }
+
+            // Rodada completa: próxima rodada ou fim do torneio
+            match self.format.elimination_losses() {
+                Some(_) if self.remaining() <= 1 => break,
+                Some(_) => {
+                    let order = self.bracket_order();
+                    self.schedule_elimination_round(&order);
+                }
+                None if self.round + 1 >= self.rounds.len() => break,
+                None => {}
+            }
+            self.round += 1;
+        }
+
+        self.champion = match self.format.elimination_losses() {
+            Some(limit) => self
+                .entrants
+                .iter()
+                .position(|entrant| entrant.losses < limit),
+            None => self.standings().first().copied(),
+        };
+    }
+
+    /// Ordem da chave para a próxima rodada: vencedores das partidas da
+    /// rodada atual, em ordem, seguidos dos perdedores
+    fn bracket_order(&self) -> Vec<usize> {
+        let round = self.current_round();
+        let winners = round.iter().filter_map(|pairing| pairing.winner);
+        let losers = round.iter().filter_map(|pairing| {
+            let winner = pairing.winner?;
+            let right = pairing.right?;
+            Some(if winner == pairing.left {
+                right
+            } else {
+                pairing.left
+            })
+        });
+        winners.chain(losers).collect()
+    }
+
+    /// Encerra uma partida da rodada atual
+    ///
+    /// # Argumentos
+    ///
+    /// * `index` - Índice da partida na rodada
+    /// * `winner` - Lado vencedor na chave
+    /// * `score` - Placar (esquerda, direita)
+    fn finish_pairing(&mut self, index: usize, winner: Side, score: (u32, u32)) {
+        let pairing = &mut self.rounds[self.round][index];
+        let Some(right) = pairing.right else {
+            return;
+        };
+        let (winner, loser) = match winner {
+            Side::Left => (pairing.left, right),
+            Side::Right => (right, pairing.left),
+        };
+        pairing.winner = Some(winner);
+        pairing.score = Some(score);
+
+        self.entrants[winner].wins += 1;
+        self.entrants[loser].losses += 1;
+        let (left_points, right_points) = score;
+        let left = pairing.left;
+        for (entrant, points_for, points_against) in [
+            (left, left_points, right_points),
+            (right, right_points, left_points),
+        ] {
+            self.entrants[entrant].points_for += points_for;
+            self.entrants[entrant].points_against += points_against;
+        }
+    }
+}
+
+impl Entrant {
+    /// Inscreve um participante sem partidas
+    fn new(name: String, cpu: Option<Difficulty>) -> Entrant {
+        Entrant {
+            name,
+            cpu,
+            wins: 0,
+            losses: 0,
+            points_for: 0,
+            points_against: 0,
+        }
+    }
+}
+
+impl Pairing {
+    /// Cria uma partida ainda não jogada
+    fn new(left: usize, right: Option<usize>, bracket: Bracket) -> Pairing {
+        Pairing {
+            left,
+            right,
+            bracket,
+            winner: None,
+            score: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Nomes "P1", "P2", ... (o número menor é o jogador mais forte)
+    fn names(count: usize) -> Vec<String> {
+        (1..=count).map(|number| format!("P{}", number)).collect()
+    }
+
+    /// Força de um participante (a CPU perde de todos)
+    fn strength(name: &str) -> usize {
+        name.strip_prefix('P')
+            .and_then(|number| number.parse().ok())
+            .unwrap_or(usize::MAX)
+    }
+
+    /// Joga o torneio até o fim
+    ///
+    /// # Argumentos
+    ///
+    /// * `tournament` - Torneio recém-criado
+    /// * `pick` - Lado vencedor de cada partida, dados o torneio e a partida
+    fn play_out(tournament: &mut Tournament, pick: impl Fn(&Tournament, &TournamentMatch) -> Side) {
+        let mut matches = 0;
+        while let Some(next) = tournament.start_next() {
+            let winner = pick(tournament, &next);
+            let (left_points, right_points) = match winner {
+                Side::Left => (5, 3),
+                Side::Right => (3, 5),
+            };
+            tournament.record_result(winner, left_points, right_points);
+            matches += 1;
+            assert!(matches <= 1000, "o torneio não terminou");
+        }
+    }
+
+    /// O mais forte sempre vence
+    fn strongest(_: &Tournament, next: &TournamentMatch) -> Side {
+        if strength(&next.left) < strength(&next.right) {
+            Side::Left
+        } else {
+            Side::Right
+        }
+    }
+
+    #[test]
+    fn odd_counts_get_a_single_cpu() {
+        for format in TournamentFormat::ALL {
+            for count in [5, 17] {
+                let mut rng = GameRng::from_state(count as u64);
+                let tournament = Tournament::new(format, &names(count), &mut rng);
+                let cpus = tournament
+                    .entrants
+                    .iter()
+                    .filter(|e| e.cpu.is_some())
+                    .count();
+                assert_eq!(tournament.entrants.len(), count + 1);
+                assert_eq!(cpus, 1);
+            }
+
+            let mut rng = GameRng::from_state(0);
+            let tournament = Tournament::new(format, &names(6), &mut rng);
+            assert!(tournament
+                .entrants
+                .iter()
+                .all(|entrant| entrant.cpu.is_none()));
+        }
+    }
+
+    #[test]
+    fn elimination_byes_complete_a_power_of_two() {
+        let mut rng = GameRng::from_state(7);
+        let mut tournament =
+            Tournament::new(TournamentFormat::SingleElimination, &names(17), &mut rng);
+
+        // 18 participantes: 14 folgas e 2 partidas levam a 16 na segunda rodada
+        let first = tournament.current_round();
+        assert_eq!(
+            first
+                .iter()
+                .filter(|pairing| pairing.right.is_none())
+                .count(),
+            14
+        );
+        assert_eq!(
+            first
+                .iter()
+                .filter(|pairing| pairing.right.is_some())
+                .count(),
+            2
+        );
+
+        for _ in 0..2 {
+            let next = tournament.start_next().unwrap();
+            let winner = strongest(&tournament, &next);
+            tournament.record_result(winner, 5, 0);
+        }
+        assert_eq!(tournament.round, 1);
+        assert_eq!(tournament.current_round().len(), 8);
+        assert!(tournament
+            .current_round()
+            .iter()
+            .all(|pairing| pairing.right.is_some()));
+    }
+
+    #[test]
+    fn the_same_seed_draws_the_same_bracket() {
+        let draw = |seed| {
+            let mut rng = GameRng::from_state(seed);
+            let tournament =
+                Tournament::new(TournamentFormat::SingleElimination, &names(12), &mut rng);
+            tournament
+                .entrants
+                .iter()
+                .map(|entrant| entrant.name.clone())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(draw(42), draw(42));
+    }
+
+    #[test]
+    fn elimination_ends_with_the_strongest_player() {
+        for format in [
+            TournamentFormat::SingleElimination,
+            TournamentFormat::DoubleElimination,
+        ] {
+            let limit = format.elimination_losses().unwrap();
+            for count in TOURNAMENT_MIN_PLAYERS..=TOURNAMENT_MAX_PLAYERS {
+                let mut rng = GameRng::from_state(count as u64);
+                let mut tournament = Tournament::new(format, &names(count), &mut rng);
+                play_out(&mut tournament, strongest);
+
+                let champion = tournament
+                    .champion
+                    .expect("o torneio deveria ter um campeão");
+                assert_eq!(tournament.entrants[champion].name, "P1");
+                assert_eq!(tournament.remaining(), 1);
+                for (index, entrant) in tournament.entrants.iter().enumerate() {
+                    if index != champion {
+                        assert_eq!(
+                            entrant.losses, limit,
+                            "{} com {} jogadores",
+                            entrant.name, count
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn double_elimination_plays_a_second_final_when_the_unbeaten_player_loses() {
+        let mut rng = GameRng::from_state(3);
+        let mut tournament =
+            Tournament::new(TournamentFormat::DoubleElimination, &names(4), &mut rng);
+
+        // Na final, quem veio da chave dos perdedores vence
+        play_out(&mut tournament, |tournament, next| {
+            let pairing = &tournament.current_round()[tournament.next_pairing().unwrap()];
+            if pairing.bracket == Bracket::Final {
+                let losses = |name: &str| {
+                    tournament
+                        .entrants
+                        .iter()
+                        .find(|entrant| entrant.name == name)
+                        .unwrap()
+                        .losses
+                };
+                if losses(&next.left) > losses(&next.right) {
+                    Side::Left
+                } else {
+                    Side::Right
+                }
+            } else {
+                strongest(tournament, next)
+            }
+        });
+
+        let finals: Vec<&Pairing> = tournament
+            .rounds
+            .iter()
+            .flatten()
+            .filter(|pairing| pairing.bracket == Bracket::Final)
+            .collect();
+        assert_eq!(finals.len(), 2);
+        let champion = tournament.champion.unwrap();
+        assert_eq!(tournament.entrants[champion].losses, 1);
+        assert_eq!(finals[1].winner, Some(champion));
+    }
+
+    #[test]
+    fn round_robin_pairs_everyone_once() {
+        for count in [4, 5, 8] {
+            let mut rng = GameRng::from_state(count as u64);
+            let tournament = Tournament::new(TournamentFormat::RoundRobin, &names(count), &mut rng);
+            let entrants = tournament.entrants.len();
+
+            assert_eq!(tournament.rounds.len(), entrants - 1);
+            let mut pairs = Vec::new();
+            for round in &tournament.rounds {
+                let mut playing: Vec<usize> = round
+                    .iter()
+                    .flat_map(|pairing| [pairing.left, pairing.right.unwrap()])
+                    .collect();
+                playing.sort();
+                assert_eq!(playing, (0..entrants).collect::<Vec<_>>());
+                for pairing in round {
+                    let right = pairing.right.unwrap();
+                    pairs.push((pairing.left.min(right), pairing.left.max(right)));
+                }
+            }
+            pairs.sort();
+            pairs.dedup();
+            assert_eq!(pairs.len(), entrants * (entrants - 1) / 2);
+        }
+    }
+
+    #[test]
+    fn round_robin_ends_with_the_most_wins() {
+        let mut rng = GameRng::from_state(9);
+        let mut tournament = Tournament::new(TournamentFormat::RoundRobin, &names(6), &mut rng);
+        play_out(&mut tournament, strongest);
+
+        let champion = tournament.champion.unwrap();
+        assert_eq!(tournament.entrants[champion].name, "P1");
+        assert_eq!(tournament.entrants[champion].wins, 5);
+        assert!(tournament.next_pairing().is_none());
+    }
+
+    #[test]
+    fn standings_break_ties_by_point_balance() {
+        let mut rng = GameRng::from_state(1);
+        let mut tournament = Tournament::new(TournamentFormat::RoundRobin, &names(4), &mut rng);
+        for (entrant, (wins, points_for, points_against)) in
+            tournament
+                .entrants
+                .iter_mut()
+                .zip([(1, 10, 12), (2, 9, 8), (1, 15, 11), (2, 20, 10)])
+        {
+            entrant.wins = wins;
+            entrant.points_for = points_for;
+            entrant.points_against = points_against;
+        }
+
+        assert_eq!(tournament.standings(), vec![3, 1, 2, 0]);
+    }
+}
@@ -0,0 +1,270 @@
+// Localização: catálogos de mensagens e seleção de idioma
+
+use crate::storage::kv;
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::fs;
+use std::path::Path;
+
+/// Código do idioma padrão, usado quando uma mensagem não existe no idioma atual
+pub const DEFAULT_LANGUAGE: &str = "pt-BR";
+
+/// Extensão dos arquivos de catálogo em `assets/locales`
+const CATALOG_EXTENSION: &str = "lang";
+
+/// Catálogos embutidos no executável (o jogo funciona mesmo sem a pasta assets/locales)
+const BUILTIN_CATALOGS: [(&str, &str); 2] = [
+    (
+        DEFAULT_LANGUAGE,
+        include_str!("../../assets/locales/pt-BR.lang"),
+    ),
+    ("en", include_str!("../../assets/locales/en.lang")),
+];
+
+/// Catálogo de mensagens de um idioma
+pub struct Language {
+    /// Código do idioma (ex: "pt-BR", "en")
+    pub code: String,
+    /// Nome do idioma exibido no menu
+    pub name: String,
+    /// Mensagens indexadas pela chave
+    messages: HashMap<String, String>,
+}
+
+impl Language {
+    /// Lê um catálogo a partir do texto de um arquivo `.lang`
+    ///
+    /// # Argumentos
+    ///
+    /// * `code` - Código do idioma
+    /// * `text` - Conteúdo no formato "chave = mensagem"
+    pub fn parse(code: &str, text: &str) -> Result<Language, String> {
+        let mut messages = HashMap::new();
+        for entry in kv::parse(text)? {
+            messages.insert(entry.key, entry.value);
+        }
+
+        let name = messages
+            .get("language.name")
+            .cloned()
+            .unwrap_or_else(|| code.to_string());
+
+        Ok(Language {
+            code: code.to_string(),
+            name,
+            messages,
+        })
+    }
+
+    /// Acrescenta ao catálogo as mensagens de outro catálogo do mesmo idioma
+    ///
+    /// As mensagens de `other` substituem as existentes; as chaves que ele não
+    /// define continuam com as mensagens atuais.
+    ///
+    /// # Argumentos
+    ///
+    /// * `other` - Catálogo com as mensagens a acrescentar
+    pub fn merge(&mut self, other: Language) {
+        if other.messages.contains_key("language.name") {
+            self.name = other.name;
+        }
+        self.messages.extend(other.messages);
+    }
+}
+
+/// Conjunto de idiomas disponíveis e o idioma selecionado
+pub struct Locale {
+    /// Idiomas carregados (o primeiro é sempre o idioma padrão)
+    languages: Vec<Language>,
+    /// Índice do idioma selecionado
+    current: usize,
+}
+
+impl Locale {
+    /// Carrega os catálogos embutidos e os catálogos do diretório informado
+    ///
+    /// Um arquivo com o mesmo código de um catálogo embutido é mesclado a ele
+    /// (as mensagens do arquivo substituem as embutidas e as que faltarem
+    /// continuam valendo), permitindo corrigir traduções sem recompilar o jogo.
+    ///
+    /// # Argumentos
+    ///
+    /// * `dir` - Diretório com arquivos `.lang`
+    pub fn load(dir: &Path) -> Locale {
+        let mut languages: Vec<Language> = BUILTIN_CATALOGS
+            .iter()
+            .map(|(code, text)| Language::parse(code, text).expect("catálogo embutido inválido"))
+            .collect();
+
+        if let Ok(read_dir) = fs::read_dir(dir) {
+            // Ordena os arquivos para que a ordem dos idiomas seja sempre a mesma
+            let mut paths: Vec<_> = read_dir
+                .filter_map(|entry| entry.ok().map(|e| e.path()))
+                .filter(|path| path.extension().is_some_and(|ext| ext == CATALOG_EXTENSION))
+                .collect();
+            paths.sort();
+
+            for path in paths {
+                let Some(code) = path.file_stem().and_then(|stem| stem.to_str()) else {
+                    continue;
+                };
+
+                let language = fs::read_to_string(&path)
+                    .map_err(|e| e.to_string())
+                    .and_then(|text| Language::parse(code, &text));
+
+                match language {
+                    Ok(language) => match languages.iter().position(|l| l.code == language.code) {
+                        Some(index) => languages[index].merge(language),
+                        None => languages.push(language),
+                    },
+                    Err(e) => eprintln!("Idioma ignorado: {}: {}", path.display(), e),
+                }
+            }
+        }
+
+        Locale {
+            languages,
+            current: 0,
+        }
+    }
+
+    /// Retorna o idioma selecionado
+    pub fn language(&self) -> &Language {
+        &self.languages[self.current]
+    }
+
+    /// Seleciona um idioma pelo código
+    ///
+    /// Aceita códigos completos ("pt-BR", "pt_BR") ou só o idioma ("pt"),
+    /// escolhendo a primeira variante disponível.
+    ///
+    /// # Retorna
+    ///
+    /// `true` se algum idioma compatível foi encontrado
+    pub fn set_language(&mut self, code: &str) -> bool {
+        let code = code.replace('_', "-");
+        let base = code.split('-').next().unwrap_or_default();
+
+        let found = self
+            .languages
+            .iter()
+            .position(|l| l.code.eq_ignore_ascii_case(&code))
+            .or_else(|| {
+                self.languages.iter().position(|l| {
+                    l.code
+                        .split('-')
+                        .next()
+                        .unwrap_or_default()
+                        .eq_ignore_ascii_case(base)
+                })
+            });
+
+        match found {
+            Some(index) => {
+                self.current = index;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Seleciona o primeiro idioma disponível da lista de preferências do sistema
+    pub fn select_preferred<I>(&mut self, codes: I)
+    where
+        I: IntoIterator<Item = String>,
+    {
+        for code in codes {
+            if self.set_language(&code) {
+                return;
+            }
+        }
+    }
+
+    /// Troca para o idioma seguinte (ou anterior) da lista
+    ///
+    /// # Argumentos
+    ///
+    /// * `direction` - 1 para o próximo idioma, -1 para o anterior
+    pub fn cycle_language(&mut self, direction: i32) {
+        let count = self.languages.len() as i32;
+        self.current = (self.current as i32 + direction).rem_euclid(count) as usize;
+    }
+
+    /// Retorna a mensagem de uma chave no idioma atual
+    ///
+    /// Se a mensagem não existir, usa o idioma padrão e, por último, a própria chave.
+    pub fn get<'a>(&'a self, key: &'a str) -> &'a str {
+        self.lookup(key).unwrap_or(key)
+    }
+
+    /// Procura a mensagem de uma chave no idioma atual e no idioma padrão
+    pub fn lookup(&self, key: &str) -> Option<&str> {
+        self.language()
+            .messages
+            .get(key)
+            .or_else(|| self.languages[0].messages.get(key))
+            .map(String::as_str)
+    }
+
+    /// Retorna a mensagem de uma chave substituindo os parâmetros `{nome}`
+    ///
+    /// # Argumentos
+    ///
+    /// * `key` - Chave da mensagem
+    /// * `args` - Pares (nome, valor) dos parâmetros
+    pub fn format(&self, key: &str, args: &[(&str, &dyn Display)]) -> String {
+        let mut text = self.get(key).to_string();
+        for (name, value) in args {
+            text = text.replace(&format!("{{{}}}", name), &value.to_string());
+        }
+        text
+    }
+}
+
+/// Retorna um caractere substituto para letras acentuadas
+///
+/// A fonte do jogo desenha as letras acentuadas do português e das outras
+/// línguas latinas (Latin-1); este substituto só é usado com fontes que não
+/// possuem o glifo de um caractere, para que o texto continue legível
+/// ("ESPAÇO" vira "ESPACO" em vez de mostrar um quadrado).
+pub fn fallback_glyph(ch: char) -> char {
+    match ch {
+        'À' | 'Á' | 'Â' | 'Ã' | 'Ä' | 'Å' => 'A',
+        'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' => 'a',
+        'È' | 'É' | 'Ê' | 'Ë' => 'E',
+        'è' | 'é' | 'ê' | 'ë' => 'e',
+        'Ì' | 'Í' | 'Î' | 'Ï' => 'I',
+        'ì' | 'í' | 'î' | 'ï' => 'i',
+        'Ò' | 'Ó' | 'Ô' | 'Õ' | 'Ö' => 'O',
+        'ò' | 'ó' | 'ô' | 'õ' | 'ö' => 'o',
+        'Ù' | 'Ú' | 'Û' | 'Ü' => 'U',
+        'ù' | 'ú' | 'û' | 'ü' => 'u',
+        'Ç' => 'C',
+        'ç' => 'c',
+        'Ñ' => 'N',
+        'ñ' => 'n',
+        _ => '?',
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn merge_keeps_messages_missing_from_the_override() {
+        let mut builtin = Language::parse(
+            "pt-BR",
+            "language.name = PORTUGUÊS\npause.title = PAUSADO\nmenu.quit = SAIR\n",
+        )
+        .unwrap();
+        let custom = Language::parse("pt-BR", "pause.title = PAUSA\n").unwrap();
+
+        builtin.merge(custom);
+
+        assert_eq!(builtin.name, "PORTUGUÊS");
+        assert_eq!(builtin.messages["pause.title"], "PAUSA");
+        assert_eq!(builtin.messages["menu.quit"], "SAIR");
+    }
+}
@@ -1,28 +1,58 @@
 // Pong em Rust - Jogo educacional
 // Um jogo clássico de Pong para 2 jogadores
 
+mod app;
 mod game;
+mod locale;
+mod storage;
+mod ui;
 
+use app::App;
+use game::arena;
 use game::game_state::{GamePhase, GameState};
-use sdl2::event::Event;
-use sdl2::keyboard::Keycode;
-use sdl2::mixer::{InitFlag, AUDIO_S16LSB, DEFAULT_CHANNELS};
-use sdl2::pixels::Color;
-use sdl2::rect::Rect;
-use sdl2::render::{Canvas, TextureCreator};
-use sdl2::ttf::Font;
-use sdl2::video::{Window, WindowContext};
+use game::theme;
+use locale::Locale;
+use sdl2::controller::{Axis, Button, GameController};
+use sdl2::event::{Event, WindowEvent};
+use sdl2::keyboard::Scancode;
+use sdl2::mixer::{Channel, InitFlag, Music, AUDIO_S16LSB, DEFAULT_CHANNELS, MAX_VOLUME};
+use sdl2::mouse::MouseButton;
+use sdl2::render::Canvas;
+use sdl2::video::{FullscreenType, Window};
+use std::path::{Path, PathBuf};
 use std::time::Duration;
+use storage::settings::{ControlScheme, DisplayMode, Settings};
+use ui::screens::{
+    render_arcade_results, render_countdown, render_editor, render_game_over, render_menu,
+    render_name_entry, render_score, render_serve, render_tournament_result,
+};
+use ui::Fonts;
 
 /// Constantes do jogo
 const SCREEN_WIDTH: u32 = 800;
 const SCREEN_HEIGHT: u32 = 600;
 const WINDOW_TITLE: &str = "Pong with Rust";
+const FONT_PATH: &str = "assets/fonts/retro.ttf";
+const THEMES_DIR: &str = "assets/themes";
+const ARENAS_DIR: &str = "assets/arenas";
+const LOCALES_DIR: &str = "assets/locales";
+
+/// Organização e nome do jogo, usados para encontrar o diretório de dados do usuário
+const ORG_NAME: &str = "MatheusFQueiroz";
+const APP_NAME: &str = "pong-rust";
+const SETTINGS_FILE: &str = "settings.cfg";
+const LEADERBOARDS_FILE: &str = "leaderboards.dat";
+const PROFILES_FILE: &str = "profiles.dat";
+const SAVEGAME_FILE: &str = "savegame.dat";
+
+/// Inclinação mínima do analógico (de 0 a 1) para mover a raquete
+const STICK_DEAD_ZONE: f32 = 0.25;
 
 fn main() -> Result<(), String> {
     // Inicializa o SDL2 (biblioteca para gráficos, áudio e entrada)
     let sdl_context = sdl2::init()?;
     let video_subsystem = sdl_context.video()?;
+    let controller_subsystem = sdl_context.game_controller()?;
     let ttf_context = sdl2::ttf::init().map_err(|e| e.to_string())?;
 
     // Inicializa o mixer de áudio
@@ -46,13 +76,59 @@ fn main() -> Result<(), String> {
         .build()
         .map_err(|e| e.to_string())?;
 
+    // Mantém a resolução lógica do jogo mesmo em tela cheia
+    canvas
+        .set_logical_size(SCREEN_WIDTH, SCREEN_HEIGHT)
+        .map_err(|e| e.to_string())?;
+
     // Cria o estado do jogo
-    let mut game_state = GameState::new(SCREEN_WIDTH as f32, SCREEN_HEIGHT as f32);
+    let game_state = GameState::new(SCREEN_WIDTH as f32, SCREEN_HEIGHT as f32);
+
+    // Carrega os temas embutidos e os temas personalizados
+    let themes = theme::load_themes(Path::new(THEMES_DIR));
+
+    // Carrega as arenas embutidas e as arenas personalizadas
+    let arenas = arena::load_arenas(Path::new(ARENAS_DIR));
+
+    // Carrega os idiomas e seleciona o idioma preferido do sistema
+    let mut locale = Locale::load(Path::new(LOCALES_DIR));
+    locale.select_preferred(sdl2::locale::get_preferred_locales().map(|l| l.to_string()));
+
+    // Carrega as configurações do diretório de dados do usuário
+    let data_dir = sdl2::filesystem::pref_path(ORG_NAME, APP_NAME)
+        .ok()
+        .map(PathBuf::from);
+    let settings_path = data_dir.as_ref().map(|dir| dir.join(SETTINGS_FILE));
+    let settings = settings_path
+        .as_deref()
+        .map(Settings::load)
+        .unwrap_or_default();
+
+    let mut app = App::new(
+        game_state,
+        themes,
+        arenas,
+        PathBuf::from(ARENAS_DIR),
+        locale,
+        settings,
+        settings_path,
+    );
+    app.load_leaderboards(data_dir.as_ref().map(|dir| dir.join(LEADERBOARDS_FILE)));
+    app.load_profiles(data_dir.as_ref().map(|dir| dir.join(PROFILES_FILE)));
+    app.set_savegame_path(data_dir.map(|dir| dir.join(SAVEGAME_FILE)));
+
+    // Configurações aplicadas à janela e ao áudio (reaplicadas quando mudam)
+    let mut applied_display_mode = None;
+    let mut applied_volume = None;
+
+    // Carrega as fontes para texto
+    let fonts = Fonts {
+        large: ttf_context.load_font(FONT_PATH, 32)?,
+        small: ttf_context.load_font(FONT_PATH, 18)?,
+    };
 
-    // Carrega a fonte para texto
-    let font = ttf_context
-        .load_font("assets/fonts/retro.ttf", 32)
-        .map_err(|e| e.to_string())?;
+    // Controles (gamepads) conectados; precisam ficar abertos para gerar eventos
+    let mut controllers: Vec<GameController> = Vec::new();
 
     // Gerenciador de eventos (teclado, mouse, etc)
     let mut event_pump = sdl_context.event_pump()?;
@@ -67,67 +143,166 @@ fn main() -> Result<(), String> {
         let delta_time = current_time.duration_since(last_time).as_secs_f32();
         last_time = current_time;
 
-        // Processa eventos (teclado, mouse, fechar janela)
+        // Processa eventos (teclado, mouse, controles, fechar janela)
         for event in event_pump.poll_iter() {
             match event {
                 // Evento de fechar janela
-                Event::Quit { .. } => break 'running,
+                Event::Quit { .. } => app.request_quit(),
+
+                // Pausa automaticamente quando a janela perde o foco
+                Event::Window {
+                    win_event: WindowEvent::FocusLost,
+                    ..
+                } => {
+                    app.handle_focus_lost();
+                }
 
                 // Eventos de teclado
                 Event::KeyDown {
                     keycode: Some(keycode),
                     ..
                 } => {
-                    handle_keydown(&mut game_state, keycode);
+                    app.handle_keydown(keycode);
+                }
+                Event::TextInput { text, .. } => {
+                    app.handle_text_input(&text);
+                }
+
+                // Eventos de mouse
+                Event::MouseMotion { x, y, .. } => {
+                    app.handle_mouse_motion(x, y);
+                }
+                Event::MouseButtonDown {
+                    mouse_btn: MouseButton::Left,
+                    x,
+                    y,
+                    ..
+                } => {
+                    app.handle_mouse_click(x, y);
+                }
+                Event::MouseButtonDown {
+                    mouse_btn: MouseButton::Right,
+                    x,
+                    y,
+                    ..
+                } => {
+                    app.handle_right_click(x, y);
+                }
+                Event::MouseButtonUp {
+                    mouse_btn: MouseButton::Left,
+                    ..
+                } => {
+                    app.handle_mouse_release();
+                }
+
+                // Eventos de controle (gamepad)
+                Event::ControllerDeviceAdded { which, .. } => {
+                    if let Ok(controller) = controller_subsystem.open(which) {
+                        controllers.push(controller);
+                    }
+                }
+                Event::ControllerDeviceRemoved { which, .. } => {
+                    controllers.retain(|controller| controller.instance_id() != which);
+                }
+                Event::ControllerButtonDown { button, .. } => {
+                    app.handle_controller_button(button);
                 }
 
                 _ => {}
             }
         }
 
-        // Obtém o estado atual do teclado para controle contínuo
-        let keyboard_state = event_pump.keyboard_state();
+        // Sai do loop para executar a sequência de encerramento
+        if app.should_quit() {
+            break 'running;
+        }
 
-        // Controla raquete esquerda (Jogador 1) com W e S
-        if keyboard_state.is_scancode_pressed(sdl2::keyboard::Scancode::W) {
-            game_state.paddle_left.move_up(delta_time);
+        // Aplica mudanças de tela cheia e volume feitas nas configurações
+        if applied_display_mode != Some(app.settings.display_mode) {
+            apply_display_mode(&mut canvas, app.settings.display_mode)?;
+            applied_display_mode = Some(app.settings.display_mode);
         }
-        if keyboard_state.is_scancode_pressed(sdl2::keyboard::Scancode::S) {
-            game_state
-                .paddle_left
-                .move_down(delta_time, game_state.screen_height);
+        if applied_volume != Some(app.settings.volume) {
+            apply_volume(app.settings.volume);
+            applied_volume = Some(app.settings.volume);
         }
 
-        // Controla raquete direita (Jogador 2) com setas para cima e para baixo
-        if keyboard_state.is_scancode_pressed(sdl2::keyboard::Scancode::Up) {
-            game_state.paddle_right.move_up(delta_time);
-        }
-        if keyboard_state.is_scancode_pressed(sdl2::keyboard::Scancode::Down) {
-            game_state
-                .paddle_right
-                .move_down(delta_time, game_state.screen_height);
+        let controls = app.settings.controls;
+        let mouse_y = app.mouse_y;
+        let game_state = &mut app.game_state;
+
+        // Os jogadores podem se posicionar durante o saque e a contagem regressiva
+        if matches!(
+            game_state.phase,
+            GamePhase::Serving | GamePhase::Playing | GamePhase::Resuming
+        ) {
+            // Obtém o estado atual do teclado para controle contínuo
+            let keyboard_state = event_pump.keyboard_state();
+
+            for player in 0..game_state.players() {
+                let vertical = game_state.paddle_vertical(player);
+                let (back_key, forward_key) = player_keys(controls, player, vertical);
+
+                let mut direction = 0.0;
+                if keyboard_state.is_scancode_pressed(back_key) {
+                    direction -= 1.0;
+                }
+                if keyboard_state.is_scancode_pressed(forward_key) {
+                    direction += 1.0;
+                }
+
+                // Cada controle conectado comanda um jogador, na ordem de conexão
+                if let Some(controller) = controllers.get(player) {
+                    direction += controller_direction(controller, vertical);
+                }
+
+                game_state.move_player(player, f32::clamp(direction, -1.0, 1.0), delta_time);
+            }
+
+            // No esquema de mouse, a raquete esquerda (Jogador 1) segue o cursor
+            if controls == ControlScheme::Mouse {
+                game_state
+                    .paddle_left
+                    .move_to(mouse_y as f32, game_state.screen_height);
+            }
         }
 
         // Atualiza o estado do jogo
-        game_state.update(delta_time);
+        app.update(delta_time);
 
         // Renderiza o jogo baseado na fase atual
-        match game_state.phase {
+        match app.game_state.phase {
             GamePhase::Menu => {
-                render_menu(&mut canvas, &font, &game_state)?;
+                if app.name_entry.is_some() {
+                    render_name_entry(&mut canvas, &fonts, &app)?;
+                } else {
+                    render_menu(&mut canvas, &fonts, &app)?;
+                }
             }
-            GamePhase::Playing | GamePhase::Paused => {
-                game_state.render(&mut canvas);
-                render_score(&mut canvas, &font, &game_state)?;
-
-                if game_state.phase == GamePhase::Paused {
-                    render_pause(&mut canvas, &font)?;
+            GamePhase::Serving | GamePhase::Playing | GamePhase::Paused | GamePhase::Resuming => {
+                app.game_state.render(&mut canvas);
+                render_score(&mut canvas, &fonts, &app.game_state, &app.locale)?;
+
+                match app.game_state.phase {
+                    GamePhase::Serving => render_serve(&mut canvas, &fonts, &app)?,
+                    GamePhase::Paused => render_menu(&mut canvas, &fonts, &app)?,
+                    GamePhase::Resuming => render_countdown(&mut canvas, &fonts, &app.game_state)?,
+                    _ => {}
                 }
             }
             GamePhase::GameOver => {
-                game_state.render(&mut canvas);
-                render_score(&mut canvas, &font, &game_state)?;
-                render_game_over(&mut canvas, &font, &game_state)?;
+                app.game_state.render(&mut canvas);
+                // Partidas de arcade terminam na tela de resultado com o ranking
+                if app.game_state.arcade.is_some() {
+                    render_arcade_results(&mut canvas, &fonts, &app)?;
+                } else {
+                    render_score(&mut canvas, &fonts, &app.game_state, &app.locale)?;
+                    render_game_over(&mut canvas, &fonts, &app.game_state, &app.locale)?;
+                    render_tournament_result(&mut canvas, &fonts, &app)?;
+                }
+            }
+            GamePhase::Editing => {
+                render_editor(&mut canvas, &fonts, &app)?;
             }
         }
 
@@ -138,303 +313,88 @@ fn main() -> Result<(), String> {
         std::thread::sleep(Duration::from_millis(1));
     }
 
+    // Salva o estado do jogo antes de fechar
+    app.shutdown();
+
     Ok(())
 }
 
-/// Trata eventos de teclas pressionadas
+/// Teclas de um jogador (para cima/esquerda, para baixo/direita)
 ///
-/// # Argumentos
-///
-/// * `game_state` - Estado atual do jogo
-/// * `keycode` - Código da tecla pressionada
-fn handle_keydown(game_state: &mut GameState, keycode: Keycode) {
-    match keycode {
-        // ESC para sair ou voltar ao menu
-        Keycode::Escape => {
-            if game_state.phase == GamePhase::Menu {
-                std::process::exit(0);
-            } else {
-                game_state.reset();
-            }
-        }
-
-        // ESPAÇO para iniciar/pausar/reiniciar
-        Keycode::Space => match game_state.phase {
-            GamePhase::Menu => {
-                game_state.start_round();
-            }
-            GamePhase::Playing => {
-                game_state.phase = GamePhase::Paused;
-            }
-            GamePhase::Paused => {
-                game_state.phase = GamePhase::Playing;
-            }
-            GamePhase::GameOver => {
-                game_state.reset();
-            }
-        },
-
-        _ => {}
-    }
-}
-
-/// Renderiza o menu inicial
+/// Os jogadores 1 e 2 seguem o esquema de controle. Os jogadores 3 e 4 usam
+/// J / L e 4 / 6 do teclado numérico nas raquetes horizontais do modo de 4
+/// jogadores, e I / K e 8 / 5 do teclado numérico nas raquetes da frente das
+/// duplas.
 ///
 /// # Argumentos
 ///
-/// * `canvas` - Canvas SDL para desenhar
-/// * `font` - Fonte para renderizar texto
-/// * `game_state` - Estado atual do jogo
-fn render_menu(
-    canvas: &mut Canvas<Window>,
-    font: &Font,
-    game_state: &GameState,
-) -> Result<(), String> {
-    // Limpa a tela
-    canvas.set_draw_color(game_state.background_color);
-    canvas.clear();
-
-    let texture_creator = canvas.texture_creator();
-
-    // Título do jogo
-    render_text(
-        canvas,
-        &texture_creator,
-        font,
-        "PONG WITH RUST",
-        SCREEN_WIDTH as i32 / 2,
-        100,
-        Color::RGB(100, 200, 255),
-    )?;
-
-    // Instruções
-    render_text(
-        canvas,
-        &texture_creator,
-        font,
-        "JOGADOR 1: W / S",
-        SCREEN_WIDTH as i32 / 2,
-        250,
-        Color::RGB(200, 200, 200),
-    )?;
-
-    render_text(
-        canvas,
-        &texture_creator,
-        font,
-        "JOGADOR 2: SETAS",
-        SCREEN_WIDTH as i32 / 2,
-        300,
-        Color::RGB(200, 200, 200),
-    )?;
-
-    render_text(
-        canvas,
-        &texture_creator,
-        font,
-        "PRIMEIRO A 5 PONTOS VENCE",
-        SCREEN_WIDTH as i32 / 2,
-        370,
-        Color::RGB(255, 255, 100),
-    )?;
-
-    render_text(
-        canvas,
-        &texture_creator,
-        font,
-        "PRESSIONE ESPACO PARA INICIAR",
-        SCREEN_WIDTH as i32 / 2,
-        450,
-        Color::RGB(100, 255, 100),
-    )?;
-
-    render_text(
-        canvas,
-        &texture_creator,
-        font,
-        "ESC PARA SAIR",
-        SCREEN_WIDTH as i32 / 2,
-        500,
-        Color::RGB(150, 150, 150),
-    )?;
-
-    Ok(())
+/// * `controls` - Esquema de controle escolhido
+/// * `player` - Índice do jogador (0 a 3)
+/// * `vertical` - Se a raquete do jogador se move na vertical
+fn player_keys(controls: ControlScheme, player: usize, vertical: bool) -> (Scancode, Scancode) {
+    let swapped = controls == ControlScheme::Swapped;
+    match (player, vertical) {
+        (0, _) if swapped => (Scancode::Up, Scancode::Down),
+        (0, _) => (Scancode::W, Scancode::S),
+        (1, _) if swapped => (Scancode::W, Scancode::S),
+        (1, _) => (Scancode::Up, Scancode::Down),
+        (2, true) => (Scancode::I, Scancode::K),
+        (2, false) => (Scancode::J, Scancode::L),
+        (_, true) => (Scancode::Kp8, Scancode::Kp5),
+        (_, false) => (Scancode::Kp4, Scancode::Kp6),
+    }
 }
 
-/// Renderiza a pontuação
+/// Direção pedida por um controle (gamepad), de -1.0 a 1.0
 ///
 /// # Argumentos
 ///
-/// * `canvas` - Canvas SDL para desenhar
-/// * `font` - Fonte para renderizar texto
-/// * `game_state` - Estado atual do jogo
-fn render_score(
-    canvas: &mut Canvas<Window>,
-    font: &Font,
-    game_state: &GameState,
-) -> Result<(), String> {
-    let texture_creator = canvas.texture_creator();
-
-    // Pontuação do jogador 1 (esquerda)
-    let score_left_text = format!("{}", game_state.score_left);
-    render_text(
-        canvas,
-        &texture_creator,
-        font,
-        &score_left_text,
-        SCREEN_WIDTH as i32 / 4,
-        30,
-        Color::RGB(100, 200, 255),
-    )?;
-
-    // Pontuação do jogador 2 (direita)
-    let score_right_text = format!("{}", game_state.score_right);
-    render_text(
-        canvas,
-        &texture_creator,
-        font,
-        &score_right_text,
-        3 * SCREEN_WIDTH as i32 / 4,
-        30,
-        Color::RGB(255, 100, 100),
-    )?;
-
-    Ok(())
-}
+/// * `controller` - Controle do jogador
+/// * `vertical` - `true` para raquetes laterais (cima/baixo), `false` para as
+///   raquetes de cima e de baixo (esquerda/direita)
+fn controller_direction(controller: &GameController, vertical: bool) -> f32 {
+    let (axis, back, forward) = if vertical {
+        (Axis::LeftY, Button::DPadUp, Button::DPadDown)
+    } else {
+        (Axis::LeftX, Button::DPadLeft, Button::DPadRight)
+    };
 
-/// Renderiza a tela de pausa
-///
-/// # Argumentos
-///
-/// * `canvas` - Canvas SDL para desenhar
-/// * `font` - Fonte para renderizar texto
-fn render_pause(canvas: &mut Canvas<Window>, font: &Font) -> Result<(), String> {
-    let texture_creator = canvas.texture_creator();
-
-    render_text(
-        canvas,
-        &texture_creator,
-        font,
-        "PAUSADO",
-        SCREEN_WIDTH as i32 / 2,
-        SCREEN_HEIGHT as i32 / 2 - 20,
-        Color::RGB(255, 255, 100),
-    )?;
-
-    render_text(
-        canvas,
-        &texture_creator,
-        font,
-        "ESPACO PARA CONTINUAR",
-        SCREEN_WIDTH as i32 / 2,
-        SCREEN_HEIGHT as i32 / 2 + 30,
-        Color::RGB(200, 200, 200),
-    )?;
+    if controller.button(back) {
+        return -1.0;
+    }
+    if controller.button(forward) {
+        return 1.0;
+    }
 
-    Ok(())
+    let value = controller.axis(axis) as f32 / i16::MAX as f32;
+    if value.abs() < STICK_DEAD_ZONE {
+        0.0
+    } else {
+        value
+    }
 }
 
-/// Renderiza a tela de fim de jogo
+/// Alterna entre janela e tela cheia
 ///
 /// # Argumentos
 ///
-/// * `canvas` - Canvas SDL para desenhar
-/// * `font` - Fonte para renderizar texto
-/// * `game_state` - Estado atual do jogo
-fn render_game_over(
-    canvas: &mut Canvas<Window>,
-    font: &Font,
-    game_state: &GameState,
-) -> Result<(), String> {
-    let texture_creator = canvas.texture_creator();
-
-    // Determina o vencedor
-    let winner_text = if game_state.score_left >= game_state.max_score {
-        "JOGADOR 1 VENCEU!"
-    } else {
-        "JOGADOR 2 VENCEU!"
+/// * `canvas` - Canvas SDL da janela do jogo
+/// * `mode` - Modo de exibição desejado
+fn apply_display_mode(canvas: &mut Canvas<Window>, mode: DisplayMode) -> Result<(), String> {
+    let fullscreen = match mode {
+        DisplayMode::Windowed => FullscreenType::Off,
+        DisplayMode::Fullscreen => FullscreenType::Desktop,
     };
-
-    let winner_color = if game_state.score_left >= game_state.max_score {
-        Color::RGB(100, 200, 255)
-    } else {
-        Color::RGB(255, 100, 100)
-    };
-
-    render_text(
-        canvas,
-        &texture_creator,
-        font,
-        winner_text,
-        SCREEN_WIDTH as i32 / 2,
-        SCREEN_HEIGHT as i32 / 2 - 40,
-        winner_color,
-    )?;
-
-    render_text(
-        canvas,
-        &texture_creator,
-        font,
-        "ESPACO PARA NOVO JOGO",
-        SCREEN_WIDTH as i32 / 2,
-        SCREEN_HEIGHT as i32 / 2 + 20,
-        Color::RGB(200, 200, 200),
-    )?;
-
-    render_text(
-        canvas,
-        &texture_creator,
-        font,
-        "ESC PARA MENU",
-        SCREEN_WIDTH as i32 / 2,
-        SCREEN_HEIGHT as i32 / 2 + 60,
-        Color::RGB(150, 150, 150),
-    )?;
-
-    Ok(())
+    canvas.window_mut().set_fullscreen(fullscreen)
 }
 
-/// Função auxiliar para renderizar texto centralizado
+/// Ajusta o volume de todos os canais de áudio e da música
 ///
 /// # Argumentos
 ///
-/// * `canvas` - Canvas SDL para desenhar
-/// * `texture_creator` - Criador de texturas SDL
-/// * `font` - Fonte para renderizar texto
-/// * `text` - Texto a ser renderizado
-/// * `x` - Posição X do centro do texto
-/// * `y` - Posição Y do topo do texto
-/// * `color` - Cor do texto
-fn render_text(
-    canvas: &mut Canvas<Window>,
-    texture_creator: &TextureCreator<WindowContext>,
-    font: &Font,
-    text: &str,
-    x: i32,
-    y: i32,
-    color: Color,
-) -> Result<(), String> {
-    // Cria uma superfície com o texto renderizado
-    let surface = font
-        .render(text)
-        .blended(color)
-        .map_err(|e| e.to_string())?;
-
-    // Converte a superfície em textura
-    let texture = texture_creator
-        .create_texture_from_surface(&surface)
-        .map_err(|e| e.to_string())?;
-
-    // Obtém as dimensões do texto
-    let text_query = texture.query();
-    let text_width = text_query.width;
-    let text_height = text_query.height;
-
-    // Calcula a posição para centralizar o texto
-    let target = Rect::new(x - text_width as i32 / 2, y, text_width, text_height);
-
-    // Desenha o texto na tela
-    canvas.copy(&texture, None, Some(target))?;
-
-    Ok(())
+/// * `volume` - Volume de 0 a 100
+fn apply_volume(volume: u32) {
+    let volume = (volume * MAX_VOLUME as u32 / 100) as i32;
+    Channel::all().set_volume(volume);
+    Music::set_volume(volume);
 }
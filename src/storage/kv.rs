@@ -0,0 +1,64 @@
+// Formato de texto simples "chave = valor" usado pelos arquivos do jogo
+
+/// Uma linha "chave = valor" lida de um arquivo
+pub struct Entry {
+    /// Número da linha no arquivo (começando em 1)
+    pub line: usize,
+    /// Chave (texto antes do `=`)
+    pub key: String,
+    /// Valor (texto depois do `=`)
+    pub value: String,
+}
+
+/// Lê um texto no formato "chave = valor"
+///
+/// Linhas vazias e linhas que começam com `#` (comentários) são ignoradas.
+///
+/// # Argumentos
+///
+/// * `text` - Conteúdo do arquivo
+///
+/// # Retorna
+///
+/// A lista de entradas na ordem em que aparecem, ou uma mensagem de erro
+/// indicando a linha inválida
+pub fn parse(text: &str) -> Result<Vec<Entry>, String> {
+    parse_lines(text).collect()
+}
+
+/// Lê um texto no formato "chave = valor" linha a linha
+///
+/// Ao contrário de `parse`, uma linha inválida não impede a leitura das
+/// seguintes: cada linha traz o seu próprio resultado.
+///
+/// # Argumentos
+///
+/// * `text` - Conteúdo do arquivo
+pub fn parse_lines(text: &str) -> impl Iterator<Item = Result<Entry, String>> + '_ {
+    text.lines().enumerate().filter_map(|(index, raw_line)| {
+        let line = raw_line.trim();
+
+        // Ignora linhas vazias e comentários
+        if line.is_empty() || line.starts_with('#') {
+            return None;
+        }
+
+        let Some((key, value)) = line.split_once('=') else {
+            return Some(Err(format!(
+                "linha {}: esperado 'chave = valor'",
+                index + 1
+            )));
+        };
+
+        let key = key.trim();
+        if key.is_empty() {
+            return Some(Err(format!("linha {}: chave vazia", index + 1)));
+        }
+
+        Some(Ok(Entry {
+            line: index + 1,
+            key: key.to_string(),
+            value: value.trim().to_string(),
+        }))
+    })
+}
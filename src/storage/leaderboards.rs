@@ -0,0 +1,339 @@
+// Recordes locais (rankings de cada modo) salvos em disco
+
+use super::kv;
+use crate::game::ai::Difficulty;
+use crate::game::arcade::ArcadeMode;
+use std::path::Path;
+
+/// Versão do formato do arquivo de recordes
+const FORMAT_VERSION: u32 = 1;
+
+/// Resultados guardados em cada ranking
+pub const LEADERBOARD_SIZE: usize = 5;
+
+/// Rankings disponíveis
+#[derive(PartialEq, Clone, Copy)]
+pub enum Board {
+    /// Maior troca de bola em uma partida
+    LongestRally,
+    /// Vitória mais rápida (tempo de bola em jogo)
+    FastestWin,
+    /// Pontuação no modo sobrevivência
+    Survival,
+    /// Pontuação no modo contra o relógio
+    TimeAttack,
+    /// Pontos marcados em uma partida contra a CPU de cada dificuldade
+    VsCpu(Difficulty),
+}
+
+impl Board {
+    /// Todos os rankings, na ordem em que aparecem na tela de recordes
+    pub const ALL: [Board; 7] = [
+        Board::LongestRally,
+        Board::FastestWin,
+        Board::Survival,
+        Board::TimeAttack,
+        Board::VsCpu(Difficulty::Easy),
+        Board::VsCpu(Difficulty::Normal),
+        Board::VsCpu(Difficulty::Hard),
+    ];
+
+    /// Ranking de um modo arcade
+    pub fn arcade(mode: ArcadeMode) -> Board {
+        match mode {
+            ArcadeMode::Survival => Board::Survival,
+            ArcadeMode::TimeAttack => Board::TimeAttack,
+        }
+    }
+
+    /// Nome usado no arquivo de recordes
+    pub fn id(self) -> &'static str {
+        match self {
+            Board::LongestRally => "longest_rally",
+            Board::FastestWin => "fastest_win",
+            Board::Survival => "survival",
+            Board::TimeAttack => "time_attack",
+            Board::VsCpu(Difficulty::Easy) => "vs_cpu_easy",
+            Board::VsCpu(Difficulty::Normal) => "vs_cpu_normal",
+            Board::VsCpu(Difficulty::Hard) => "vs_cpu_hard",
+        }
+    }
+
+    /// Chave do catálogo de mensagens com o nome do ranking
+    pub fn label_key(self) -> &'static str {
+        match self {
+            Board::LongestRally => "leaderboard.longest_rally",
+            Board::FastestWin => "leaderboard.fastest_win",
+            Board::Survival => "leaderboard.survival",
+            Board::TimeAttack => "leaderboard.time_attack",
+            Board::VsCpu(Difficulty::Easy) => "leaderboard.vs_cpu_easy",
+            Board::VsCpu(Difficulty::Normal) => "leaderboard.vs_cpu_normal",
+            Board::VsCpu(Difficulty::Hard) => "leaderboard.vs_cpu_hard",
+        }
+    }
+
+    /// Chave do catálogo de mensagens com o formato de uma linha do ranking
+    pub fn entry_key(self) -> &'static str {
+        match self {
+            Board::LongestRally => "leaderboard.entry_rally",
+            Board::FastestWin => "leaderboard.entry_time",
+            Board::Survival => "leaderboard.entry_survival",
+            Board::TimeAttack => "leaderboard.entry_score",
+            Board::VsCpu(_) => "leaderboard.entry_vs_cpu",
+        }
+    }
+
+    /// Indica se o resultado `a` é melhor que `b` (na vitória mais rápida,
+    /// vale o menor tempo)
+    fn better(self, a: &Record, b: &Record) -> bool {
+        match self {
+            Board::FastestWin => a.value < b.value,
+            _ => a.value > b.value,
+        }
+    }
+}
+
+/// Um resultado do ranking
+#[derive(Clone)]
+pub struct Record {
+    /// Valor comparado no ranking (pontuação, rebatidas ou segundos)
+    pub value: u32,
+    /// Tempo de bola em jogo (em segundos)
+    pub time: f32,
+    /// Rebatidas do jogador
+    pub returns: u32,
+    /// Pontos marcados
+    pub points_won: u32,
+    /// Pontos sofridos
+    pub points_lost: u32,
+}
+
+/// Melhores resultados de cada ranking
+pub struct Leaderboards {
+    /// Resultados de cada ranking, do melhor para o pior
+    boards: Vec<(Board, Vec<Record>)>,
+}
+
+impl Leaderboards {
+    /// Retorna os resultados de um ranking, do melhor para o pior
+    pub fn entries(&self, board: Board) -> &[Record] {
+        self.boards
+            .iter()
+            .find(|(other, _)| *other == board)
+            .map(|(_, records)| records.as_slice())
+            .unwrap_or(&[])
+    }
+
+    /// Registra um resultado em um ranking
+    ///
+    /// Em caso de empate, o resultado mais antigo fica à frente.
+    ///
+    /// # Argumentos
+    ///
+    /// * `board` - Ranking
+    /// * `record` - Resultado da partida
+    ///
+    /// # Retorna
+    ///
+    /// A posição do resultado no ranking (0 é o primeiro lugar), ou `None`
+    /// se ele não entrou no ranking
+    pub fn submit(&mut self, board: Board, record: Record) -> Option<usize> {
+        let (_, records) = self.boards.iter_mut().find(|(other, _)| *other == board)?;
+
+        let rank = records
+            .iter()
+            .position(|other| board.better(&record, other))
+            .unwrap_or(records.len());
+        if rank >= LEADERBOARD_SIZE {
+            return None;
+        }
+
+        records.insert(rank, record);
+        records.truncate(LEADERBOARD_SIZE);
+        Some(rank)
+    }
+
+    /// Lê os recordes de um arquivo
+    ///
+    /// Um arquivo corrompido é renomeado para `<arquivo>.corrupt` e os
+    /// recordes são recuperados da cópia de segurança da última gravação; se
+    /// ela também não puder ser lida, os rankings começam vazios.
+    ///
+    /// # Argumentos
+    ///
+    /// * `path` - Caminho do arquivo de recordes
+    pub fn load(path: &Path) -> Leaderboards {
+        super::load_recovering(path, Leaderboards::parse).unwrap_or_default()
+    }
+
+    /// Lê os recordes a partir do texto de um arquivo
+    ///
+    /// A última linha traz a soma de verificação do restante do arquivo, para
+    /// que arquivos truncados ou alterados sejam detectados.
+    pub fn parse(text: &str) -> Result<Leaderboards, String> {
+        let body = super::unseal(text)?;
+
+        let mut leaderboards = Leaderboards::default();
+        for entry in kv::parse(body)? {
+            let invalid = || format!("linha {}: valor inválido '{}'", entry.line, entry.value);
+
+            if entry.key == "version" {
+                let version: u32 = entry.value.parse().map_err(|_| invalid())?;
+                if version > FORMAT_VERSION {
+                    return Err(format!("versão {} não suportada", version));
+                }
+                continue;
+            }
+
+            // Rankings desconhecidos (de versões mais novas) são ignorados
+            let Some(board) = Board::ALL.into_iter().find(|board| board.id() == entry.key) else {
+                continue;
+            };
+
+            let fields: Vec<&str> = entry.value.split_whitespace().collect();
+            let [value, time, returns, won, lost] = fields[..] else {
+                return Err(invalid());
+            };
+            let record = Record {
+                value: value.parse().map_err(|_| invalid())?,
+                time: time.parse().map_err(|_| invalid())?,
+                returns: returns.parse().map_err(|_| invalid())?,
+                points_won: won.parse().map_err(|_| invalid())?,
+                points_lost: lost.parse().map_err(|_| invalid())?,
+            };
+            leaderboards.submit(board, record);
+        }
+
+        Ok(leaderboards)
+    }
+
+    /// Converte os recordes para o formato do arquivo
+    pub fn to_text(&self) -> String {
+        let mut body = format!(
+            "# Recordes do Pong with Rust\n\
+             # <ranking> = <valor> <tempo> <rebatidas> <pontos a favor> <pontos contra>\n\
+             version = {}\n",
+            FORMAT_VERSION
+        );
+        for (board, records) in &self.boards {
+            for record in records {
+                body.push_str(&format!(
+                    "{} = {} {:.1} {} {} {}\n",
+                    board.id(),
+                    record.value,
+                    record.time,
+                    record.returns,
+                    record.points_won,
+                    record.points_lost,
+                ));
+            }
+        }
+
+        super::seal(&body)
+    }
+
+    /// Salva os recordes em um arquivo (gravação atômica)
+    ///
+    /// # Argumentos
+    ///
+    /// * `path` - Caminho do arquivo de recordes
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        super::write_atomic(path, &self.to_text())
+    }
+}
+
+impl Default for Leaderboards {
+    fn default() -> Leaderboards {
+        Leaderboards {
+            boards: Board::ALL
+                .into_iter()
+                .map(|board| (board, Vec::new()))
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::seal;
+
+    fn record(value: u32, returns: u32) -> Record {
+        Record {
+            value,
+            time: 12.5,
+            returns,
+            points_won: 5,
+            points_lost: 2,
+        }
+    }
+
+    #[test]
+    fn submit_ranks_and_truncates() {
+        let mut leaderboards = Leaderboards::default();
+        let board = Board::LongestRally;
+
+        assert_eq!(leaderboards.submit(board, record(10, 0)), Some(0));
+        assert_eq!(leaderboards.submit(board, record(20, 0)), Some(0));
+        assert_eq!(leaderboards.submit(board, record(15, 0)), Some(1));
+
+        // Empate: o resultado mais antigo fica à frente
+        assert_eq!(leaderboards.submit(board, record(15, 1)), Some(2));
+        assert_eq!(leaderboards.entries(board)[1].returns, 0);
+        assert_eq!(leaderboards.entries(board)[2].returns, 1);
+
+        assert_eq!(leaderboards.submit(board, record(5, 0)), Some(4));
+        assert_eq!(leaderboards.submit(board, record(5, 0)), None);
+        assert_eq!(leaderboards.submit(board, record(30, 0)), Some(0));
+
+        let values: Vec<u32> = leaderboards
+            .entries(board)
+            .iter()
+            .map(|r| r.value)
+            .collect();
+        assert_eq!(values.len(), LEADERBOARD_SIZE);
+        assert_eq!(values, [30, 20, 15, 15, 10]);
+        assert!(leaderboards.entries(Board::Survival).is_empty());
+    }
+
+    #[test]
+    fn fastest_win_ranks_the_shortest_time_first() {
+        let mut leaderboards = Leaderboards::default();
+        let board = Board::FastestWin;
+
+        leaderboards.submit(board, record(90, 0));
+        assert_eq!(leaderboards.submit(board, record(60, 0)), Some(0));
+        assert_eq!(leaderboards.submit(board, record(120, 0)), Some(2));
+
+        let values: Vec<u32> = leaderboards
+            .entries(board)
+            .iter()
+            .map(|r| r.value)
+            .collect();
+        assert_eq!(values, [60, 90, 120]);
+    }
+
+    #[test]
+    fn text_round_trip() {
+        let mut leaderboards = Leaderboards::default();
+        leaderboards.submit(Board::TimeAttack, record(14, 30));
+        leaderboards.submit(Board::TimeAttack, record(9, 21));
+        leaderboards.submit(Board::VsCpu(Difficulty::Hard), record(3, 40));
+
+        let text = leaderboards.to_text();
+        let parsed = Leaderboards::parse(&text).unwrap();
+        assert_eq!(parsed.to_text(), text);
+        assert_eq!(parsed.entries(Board::TimeAttack)[1].returns, 21);
+        assert_eq!(parsed.entries(Board::VsCpu(Difficulty::Hard))[0].time, 12.5);
+    }
+
+    #[test]
+    fn unknown_boards_are_ignored_and_newer_versions_rejected() {
+        let text = seal("version = 1\nfuture_board = 1 2.0 3 4 5\nsurvival = 7 2.0 3 4 5\n");
+        let parsed = Leaderboards::parse(&text).unwrap();
+        assert_eq!(parsed.entries(Board::Survival)[0].value, 7);
+
+        let text = seal("version = 2\n");
+        assert!(Leaderboards::parse(&text).is_err());
+    }
+}
@@ -0,0 +1,216 @@
+// Módulo de armazenamento: leitura e escrita de arquivos de dados do jogo
+
+pub mod kv;
+pub mod leaderboards;
+pub mod profiles;
+pub mod savegame;
+pub mod settings;
+
+use std::fs::{self, File};
+use std::io::{ErrorKind, Write};
+use std::path::{Path, PathBuf};
+
+/// Grava um arquivo de forma atômica
+///
+/// O texto é gravado em um arquivo temporário ao lado do destino, que só
+/// substitui o arquivo anterior depois de gravado por completo. A versão
+/// anterior é mantida como cópia de segurança (`<arquivo>.bak`), de modo que
+/// uma queda no meio da gravação nunca deixa o jogo sem nenhuma cópia válida.
+///
+/// # Argumentos
+///
+/// * `path` - Arquivo de destino
+/// * `text` - Conteúdo do arquivo
+pub fn write_atomic(path: &Path, text: &str) -> Result<(), String> {
+    let error = |e: std::io::Error| format!("{}: {}", path.display(), e);
+    let temp_path = with_suffix(path, "tmp");
+
+    let mut file = File::create(&temp_path).map_err(error)?;
+    file.write_all(text.as_bytes()).map_err(error)?;
+    file.sync_all().map_err(error)?;
+
+    if path.exists() {
+        fs::rename(path, backup_path(path)).map_err(error)?;
+    }
+    fs::rename(&temp_path, path).map_err(error)
+}
+
+/// Caminho da cópia de segurança mantida por `write_atomic`
+pub fn backup_path(path: &Path) -> PathBuf {
+    with_suffix(path, "bak")
+}
+
+/// Acrescenta uma extensão ao nome do arquivo (ex: `dados.dat` vira `dados.dat.bak`)
+pub fn with_suffix(path: &Path, suffix: &str) -> PathBuf {
+    let mut name = path.as_os_str().to_os_string();
+    name.push(".");
+    name.push(suffix);
+    PathBuf::from(name)
+}
+
+/// Acrescenta ao texto de um arquivo de dados a linha com a soma de verificação
+///
+/// A soma permite que `unseal` detecte arquivos truncados ou alterados.
+///
+/// # Argumentos
+///
+/// * `body` - Conteúdo do arquivo (terminado em quebra de linha)
+pub fn seal(body: &str) -> String {
+    format!("{}checksum = {:08x}\n", body, fnv1a(body))
+}
+
+/// Confere a soma de verificação gravada por `seal`
+///
+/// # Retorna
+///
+/// O conteúdo do arquivo sem a linha da soma, ou uma mensagem de erro se a
+/// soma estiver ausente ou não conferir
+pub fn unseal(text: &str) -> Result<&str, String> {
+    let body_end = text
+        .trim_end()
+        .rfind('\n')
+        .map(|index| index + 1)
+        .ok_or("arquivo vazio")?;
+    let (body, last_line) = text.split_at(body_end);
+    let checksum = last_line
+        .trim()
+        .strip_prefix("checksum =")
+        .map(str::trim)
+        .ok_or("soma de verificação ausente")?;
+    if checksum != format!("{:08x}", fnv1a(body)) {
+        return Err(String::from("soma de verificação não confere"));
+    }
+    Ok(body)
+}
+
+/// Lê um arquivo de dados gravado por `write_atomic`, recuperando a cópia de
+/// segurança se ele estiver corrompido
+///
+/// Um arquivo corrompido é renomeado para `<arquivo>.corrupt` (para não ser
+/// sobrescrito na próxima gravação) e os dados são lidos da cópia de
+/// segurança da última gravação.
+///
+/// # Argumentos
+///
+/// * `path` - Caminho do arquivo
+/// * `parse` - Função que lê os dados a partir do texto do arquivo
+///
+/// # Retorna
+///
+/// Os dados lidos, ou `None` se nem o arquivo nem a cópia de segurança
+/// puderem ser lidos
+pub fn load_recovering<T>(path: &Path, parse: impl Fn(&str) -> Result<T, String>) -> Option<T> {
+    match read_file(path, &parse) {
+        Ok(Some(data)) => return Some(data),
+        Ok(None) => {}
+        Err(e) => {
+            eprintln!("Arquivo inválido {}: {}", path.display(), e);
+            if let Err(e) = fs::rename(path, with_suffix(path, "corrupt")) {
+                eprintln!("Não foi possível mover {}: {}", path.display(), e);
+            }
+        }
+    }
+
+    // Sem arquivo (ou arquivo corrompido): tenta a cópia de segurança
+    let backup = backup_path(path);
+    match read_file(&backup, &parse) {
+        Ok(Some(data)) => {
+            eprintln!("Dados recuperados de {}", backup.display());
+            Some(data)
+        }
+        Ok(None) => None,
+        Err(e) => {
+            eprintln!("Arquivo inválido {}: {}", backup.display(), e);
+            None
+        }
+    }
+}
+
+/// Lê um arquivo de dados
+///
+/// # Retorna
+///
+/// `None` se o arquivo não existe
+fn read_file<T>(
+    path: &Path,
+    parse: impl Fn(&str) -> Result<T, String>,
+) -> Result<Option<T>, String> {
+    match fs::read_to_string(path) {
+        Ok(text) => parse(&text).map(Some),
+        Err(e) if e.kind() == ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+/// Soma de verificação FNV-1a (32 bits) de um texto
+fn fnv1a(text: &str) -> u32 {
+    text.bytes().fold(0x811c_9dc5, |hash, byte| {
+        (hash ^ byte as u32).wrapping_mul(0x0100_0193)
+    })
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    /// Diretório temporário vazio, exclusivo de um teste
+    pub(crate) fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("pong-rust-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    /// Lê um arquivo selado que guarda só um número
+    fn parse_number(text: &str) -> Result<u32, String> {
+        let body = unseal(text)?;
+        body.trim()
+            .parse()
+            .map_err(|_| String::from("número inválido"))
+    }
+
+    #[test]
+    fn seal_round_trip() {
+        let text = seal("version = 1\nscore = 3 2\n");
+        assert_eq!(unseal(&text).unwrap(), "version = 1\nscore = 3 2\n");
+    }
+
+    #[test]
+    fn bad_checksum_is_rejected() {
+        let text = seal("score = 3 2\n");
+        assert!(unseal(&text.replace("3 2", "9 2")).is_err());
+        assert!(unseal(&text[..text.len() - 2]).is_err());
+        assert!(unseal("score = 3 2\n").is_err());
+        assert!(unseal("").is_err());
+    }
+
+    #[test]
+    fn write_atomic_keeps_the_previous_version() {
+        let path = temp_dir("write-atomic").join("dados.dat");
+        write_atomic(&path, "1").unwrap();
+        write_atomic(&path, "2").unwrap();
+
+        assert_eq!(fs::read_to_string(&path).unwrap(), "2");
+        assert_eq!(fs::read_to_string(backup_path(&path)).unwrap(), "1");
+        assert!(!with_suffix(&path, "tmp").exists());
+    }
+
+    #[test]
+    fn corrupt_file_is_recovered_from_the_backup() {
+        let path = temp_dir("recovering").join("dados.dat");
+        write_atomic(&path, &seal("1\n")).unwrap();
+        write_atomic(&path, &seal("2\n")).unwrap();
+        assert_eq!(load_recovering(&path, parse_number), Some(2));
+
+        // Arquivo truncado: vai para `.corrupt` e a cópia de segurança vale
+        let text = fs::read_to_string(&path).unwrap();
+        fs::write(&path, &text[..text.len() - 4]).unwrap();
+        assert_eq!(load_recovering(&path, parse_number), Some(1));
+        assert!(!path.exists());
+        assert!(with_suffix(&path, "corrupt").exists());
+
+        // Sem arquivo e sem cópia de segurança
+        fs::remove_file(backup_path(&path)).unwrap();
+        assert_eq!(load_recovering(&path, parse_number), None);
+    }
+}
@@ -0,0 +1,375 @@
+// Perfis de jogadores com estatísticas de todas as partidas, salvos em disco
+
+use super::kv;
+use crate::game::game_state::Side;
+use crate::game::rating::{INITIAL_RATING, RATING_HISTORY_SIZE};
+use crate::game::scoring::MatchStats;
+use std::path::Path;
+
+/// Versão do formato do arquivo de perfis (a versão 2 acrescentou o rating)
+const FORMAT_VERSION: u32 = 2;
+
+/// Tamanho máximo do nome de um perfil (em caracteres)
+pub const PROFILE_NAME_MAX: usize = 12;
+
+/// Perfil de um jogador
+pub struct Profile {
+    /// Nome do jogador
+    pub name: String,
+    /// Partidas vencidas
+    pub wins: u32,
+    /// Partidas perdidas
+    pub losses: u32,
+    /// Pontos marcados
+    pub points_for: u32,
+    /// Pontos sofridos
+    pub points_against: u32,
+    /// Maior troca de bola em uma partida
+    pub longest_rally: u32,
+    /// Velocidade da bola mais rápida rebatida (pixels por segundo)
+    pub fastest_return: f32,
+    /// Tempo de bola em jogo somado de todas as partidas (em segundos)
+    pub playtime: f32,
+    /// Rating de habilidade (Elo)
+    pub rating: f32,
+    /// Partidas que mudaram o rating
+    pub rated_matches: u32,
+    /// Ratings depois das últimas partidas, da mais antiga para a mais recente
+    pub history: Vec<f32>,
+}
+
+impl Profile {
+    /// Cria um perfil sem partidas
+    ///
+    /// # Argumentos
+    ///
+    /// * `name` - Nome do jogador
+    pub fn new(name: &str) -> Profile {
+        Profile {
+            name: name.to_string(),
+            wins: 0,
+            losses: 0,
+            points_for: 0,
+            points_against: 0,
+            longest_rally: 0,
+            fastest_return: 0.0,
+            playtime: 0.0,
+            rating: INITIAL_RATING,
+            rated_matches: 0,
+            history: Vec::new(),
+        }
+    }
+
+    /// Partidas jogadas (vencidas e perdidas)
+    pub fn matches(&self) -> u32 {
+        self.wins + self.losses
+    }
+
+    /// Soma às estatísticas do perfil uma partida encerrada
+    ///
+    /// # Argumentos
+    ///
+    /// * `stats` - Estatísticas da partida
+    /// * `side` - Lado em que o jogador jogou
+    /// * `winner` - Lado vencedor (`None` se a partida não teve vencedor)
+    pub fn record(&mut self, stats: &MatchStats, side: Side, winner: Option<Side>) {
+        match winner {
+            Some(winner) if winner == side => self.wins += 1,
+            Some(_) => self.losses += 1,
+            None => {}
+        }
+
+        let (points_for, points_against) = match side {
+            Side::Left => (stats.points_left, stats.points_right),
+            Side::Right => (stats.points_right, stats.points_left),
+        };
+        self.points_for += points_for;
+        self.points_against += points_against;
+        self.longest_rally = self.longest_rally.max(stats.longest_rally);
+        self.fastest_return = self.fastest_return.max(stats.fastest_return[side.player()]);
+        self.playtime += stats.duration;
+    }
+
+    /// Registra o rating calculado depois de uma partida
+    ///
+    /// # Argumentos
+    ///
+    /// * `rating` - Novo rating
+    pub fn set_rating(&mut self, rating: f32) {
+        self.rating = rating;
+        self.rated_matches += 1;
+        self.history.push(rating);
+        if self.history.len() > RATING_HISTORY_SIZE {
+            self.history.remove(0);
+        }
+    }
+
+    /// Variação do rating na última partida (`None` antes da primeira partida)
+    pub fn last_rating_change(&self) -> Option<f32> {
+        let last = *self.history.last()?;
+        let previous = match self.history.len() {
+            1 => INITIAL_RATING,
+            len => self.history[len - 2],
+        };
+        Some(last - previous)
+    }
+}
+
+/// Perfis cadastrados, na ordem em que foram criados
+#[derive(Default)]
+pub struct Profiles {
+    /// Perfis cadastrados
+    profiles: Vec<Profile>,
+}
+
+impl Profiles {
+    /// Retorna todos os perfis
+    pub fn list(&self) -> &[Profile] {
+        &self.profiles
+    }
+
+    /// Procura um perfil pelo nome
+    pub fn find(&self, name: &str) -> Option<&Profile> {
+        self.profiles.iter().find(|profile| profile.name == name)
+    }
+
+    /// Procura um perfil pelo nome para alterá-lo
+    pub fn find_mut(&mut self, name: &str) -> Option<&mut Profile> {
+        self.profiles
+            .iter_mut()
+            .find(|profile| profile.name == name)
+    }
+
+    /// Confere se um nome pode ser usado em um perfil novo
+    ///
+    /// # Retorna
+    ///
+    /// A chave do catálogo de mensagens com o problema do nome, ou `None` se
+    /// ele é válido
+    pub fn name_error(&self, name: &str) -> Option<&'static str> {
+        if name.trim().is_empty() {
+            Some("profiles.name_empty")
+        } else if self
+            .profiles
+            .iter()
+            .any(|profile| profile.name.eq_ignore_ascii_case(name.trim()))
+        {
+            Some("profiles.name_taken")
+        } else {
+            None
+        }
+    }
+
+    /// Cria um perfil (o nome deve ter sido conferido com `name_error`)
+    ///
+    /// # Argumentos
+    ///
+    /// * `name` - Nome do jogador
+    pub fn add(&mut self, name: &str) {
+        self.profiles.push(Profile::new(name.trim()));
+    }
+
+    /// Lê os perfis de um arquivo
+    ///
+    /// Um arquivo corrompido é recuperado da cópia de segurança da última
+    /// gravação; se ela também não puder ser lida, a lista começa vazia.
+    ///
+    /// # Argumentos
+    ///
+    /// * `path` - Caminho do arquivo de perfis
+    pub fn load(path: &Path) -> Profiles {
+        super::load_recovering(path, Profiles::parse).unwrap_or_default()
+    }
+
+    /// Lê os perfis a partir do texto de um arquivo
+    pub fn parse(text: &str) -> Result<Profiles, String> {
+        let body = super::unseal(text)?;
+
+        let mut profiles = Profiles::default();
+        let mut version = 1;
+        for entry in kv::parse(body)? {
+            let invalid = || format!("linha {}: valor inválido '{}'", entry.line, entry.value);
+
+            match entry.key.as_str() {
+                "version" => {
+                    version = entry.value.parse().map_err(|_| invalid())?;
+                    if version > FORMAT_VERSION {
+                        return Err(format!("versão {} não suportada", version));
+                    }
+                }
+                "profile" => {
+                    // O nome fica por último e pode ter espaços; arquivos da
+                    // versão 1 não têm o rating (o perfil começa com o inicial)
+                    let stat_fields = if version >= 2 { 9 } else { 7 };
+                    let fields: Vec<&str> = entry.value.splitn(stat_fields + 1, ' ').collect();
+                    let (name, stats) = fields.split_last().ok_or_else(invalid)?;
+                    if stats.len() != stat_fields {
+                        return Err(invalid());
+                    }
+                    let number = |index: usize| stats[index].parse::<f32>().map_err(|_| invalid());
+                    let count = |index: usize| stats[index].parse::<u32>().map_err(|_| invalid());
+
+                    let mut profile = Profile::new(name);
+                    profile.wins = count(0)?;
+                    profile.losses = count(1)?;
+                    profile.points_for = count(2)?;
+                    profile.points_against = count(3)?;
+                    profile.longest_rally = count(4)?;
+                    profile.fastest_return = number(5)?;
+                    profile.playtime = number(6)?;
+                    if version >= 2 {
+                        profile.rating = number(7)?;
+                        profile.rated_matches = count(8)?;
+                    }
+                    profiles.profiles.push(profile);
+                }
+                "history" => {
+                    // Histórico do perfil da linha anterior
+                    let profile = profiles.profiles.last_mut().ok_or_else(invalid)?;
+                    profile.history = entry
+                        .value
+                        .split_whitespace()
+                        .map(|rating| rating.parse().map_err(|_| invalid()))
+                        .collect::<Result<_, _>>()?;
+                }
+                // Chaves desconhecidas (de versões mais novas) são ignoradas
+                _ => {}
+            }
+        }
+
+        Ok(profiles)
+    }
+
+    /// Converte os perfis para o formato do arquivo
+    pub fn to_text(&self) -> String {
+        let mut body = format!(
+            "# Perfis de jogadores do Pong with Rust\n\
+             # profile = <vitórias> <derrotas> <pontos a favor> <pontos contra> \
+             <maior troca> <bola mais rápida> <tempo de jogo> <rating> \
+             <partidas com rating> <nome>\n\
+             # history = <ratings depois das últimas partidas>\n\
+             version = {}\n",
+            FORMAT_VERSION
+        );
+        for profile in &self.profiles {
+            body.push_str(&format!(
+                "profile = {} {} {} {} {} {:.1} {:.1} {:.1} {} {}\n",
+                profile.wins,
+                profile.losses,
+                profile.points_for,
+                profile.points_against,
+                profile.longest_rally,
+                profile.fastest_return,
+                profile.playtime,
+                profile.rating,
+                profile.rated_matches,
+                profile.name,
+            ));
+            if !profile.history.is_empty() {
+                let history: Vec<String> = profile
+                    .history
+                    .iter()
+                    .map(|rating| format!("{:.1}", rating))
+                    .collect();
+                body.push_str(&format!("history = {}\n", history.join(" ")));
+            }
+        }
+
+        super::seal(&body)
+    }
+
+    /// Salva os perfis em um arquivo (gravação atômica)
+    ///
+    /// # Argumentos
+    ///
+    /// * `path` - Caminho do arquivo de perfis
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        super::write_atomic(path, &self.to_text())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::seal;
+
+    #[test]
+    fn text_round_trip() {
+        let mut profiles = Profiles::default();
+        profiles.add("Ana Maria Souza");
+        profiles.add("Bia");
+        let profile = profiles.find_mut("Ana Maria Souza").unwrap();
+        profile.wins = 3;
+        profile.losses = 1;
+        profile.points_for = 31;
+        profile.points_against = 17;
+        profile.longest_rally = 12;
+        profile.fastest_return = 640.5;
+        profile.playtime = 301.5;
+        profile.set_rating(1016.0);
+        profile.set_rating(1030.5);
+
+        let text = profiles.to_text();
+        let parsed = Profiles::parse(&text).unwrap();
+        assert_eq!(parsed.to_text(), text);
+
+        let names: Vec<&str> = parsed.list().iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, ["Ana Maria Souza", "Bia"]);
+        let profile = parsed.find("Ana Maria Souza").unwrap();
+        assert_eq!(profile.wins, 3);
+        assert_eq!(profile.rated_matches, 2);
+        assert_eq!(profile.history, [1016.0, 1030.5]);
+
+        // O histórico pertence ao perfil da linha anterior
+        assert!(parsed.find("Bia").unwrap().history.is_empty());
+    }
+
+    #[test]
+    fn version_1_profiles_start_with_the_initial_rating() {
+        let text = seal("version = 1\nprofile = 4 2 40 25 9 512.0 600.0 Carlos Lima\n");
+        let profiles = Profiles::parse(&text).unwrap();
+
+        let profile = profiles.find("Carlos Lima").unwrap();
+        assert_eq!(profile.wins, 4);
+        assert_eq!(profile.playtime, 600.0);
+        assert_eq!(profile.rating, INITIAL_RATING);
+        assert_eq!(profile.rated_matches, 0);
+        assert!(profile.last_rating_change().is_none());
+    }
+
+    #[test]
+    fn last_rating_change_follows_the_history() {
+        let mut profile = Profile::new("Bia");
+        assert!(profile.last_rating_change().is_none());
+
+        profile.set_rating(INITIAL_RATING + 20.0);
+        assert_eq!(profile.last_rating_change(), Some(20.0));
+        profile.set_rating(INITIAL_RATING + 8.0);
+        assert_eq!(profile.last_rating_change(), Some(-12.0));
+        assert_eq!(profile.rated_matches, 2);
+
+        // O histórico guarda só as últimas partidas
+        for match_number in 0..RATING_HISTORY_SIZE {
+            profile.set_rating(INITIAL_RATING + match_number as f32);
+        }
+        assert_eq!(profile.history.len(), RATING_HISTORY_SIZE);
+        assert_eq!(profile.last_rating_change(), Some(1.0));
+    }
+
+    #[test]
+    fn malformed_lines_are_rejected() {
+        // Campos a menos
+        let text = seal("version = 2\nprofile = 4 2 40 Carlos\n");
+        assert!(Profiles::parse(&text)
+            .err()
+            .unwrap()
+            .starts_with("linha 2:"));
+
+        // Histórico sem perfil
+        let text = seal("version = 2\nhistory = 1000.0\n");
+        assert!(Profiles::parse(&text).is_err());
+
+        let text = seal("version = 3\n");
+        assert!(Profiles::parse(&text).is_err());
+    }
+}
@@ -0,0 +1,121 @@
+// Jogo salvo: a partida pausada gravada em disco para continuar depois
+
+use super::kv;
+use crate::game::game_state::{GamePhase, GameState};
+use std::fs;
+use std::path::Path;
+
+/// Versão do formato do jogo salvo
+const FORMAT_VERSION: u32 = 1;
+
+/// Salva a partida em um arquivo (gravação atômica)
+///
+/// # Argumentos
+///
+/// * `game_state` - Partida pausada
+/// * `path` - Caminho do jogo salvo
+pub fn save(game_state: &GameState, path: &Path) -> Result<(), String> {
+    let body = format!(
+        "# Jogo salvo do Pong with Rust\n\
+         version = {}\n\
+         {}",
+        FORMAT_VERSION,
+        game_state.save_text()
+    );
+    super::write_atomic(path, &super::seal(&body))
+}
+
+/// Carrega uma partida salva
+///
+/// # Argumentos
+///
+/// * `path` - Caminho do jogo salvo
+/// * `screen_width` - Largura da tela
+/// * `screen_height` - Altura da tela
+///
+/// # Retorna
+///
+/// A partida (pausada), ou uma mensagem de erro se o arquivo não puder ser
+/// lido ou for de uma versão mais nova do jogo
+pub fn load(path: &Path, screen_width: f32, screen_height: f32) -> Result<GameState, String> {
+    let text = fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+    parse(&text, screen_width, screen_height)
+}
+
+/// Lê uma partida a partir do texto de um jogo salvo
+pub fn parse(text: &str, screen_width: f32, screen_height: f32) -> Result<GameState, String> {
+    let entries = kv::parse(super::unseal(text)?)?;
+
+    // A versão vem antes de tudo: versões mais novas não são lidas
+    let version = entries
+        .iter()
+        .find(|entry| entry.key == "version")
+        .ok_or("versão do arquivo ausente")?;
+    let number: u32 = version.value.parse().map_err(|_| {
+        format!(
+            "linha {}: versão inválida '{}'",
+            version.line, version.value
+        )
+    })?;
+    if number > FORMAT_VERSION {
+        return Err(format!(
+            "versão {} não suportada (esta versão do jogo lê até a {})",
+            number, FORMAT_VERSION
+        ));
+    }
+
+    let game_state = GameState::restore(screen_width, screen_height, &entries)?;
+    if game_state.phase != GamePhase::Paused {
+        return Err(String::from("o jogo salvo não é uma partida pausada"));
+    }
+    Ok(game_state)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::seal;
+
+    fn paused_match() -> GameState {
+        let mut game_state = GameState::new(800.0, 600.0);
+        game_state.start_match(None);
+        for _ in 0..100 {
+            game_state.update(0.016);
+        }
+        game_state.pause();
+        game_state
+    }
+
+    #[test]
+    fn saved_file_loads_back() {
+        let game_state = paused_match();
+        let text = seal(&format!("version = 1\n{}", game_state.save_text()));
+        let loaded = parse(&text, 800.0, 600.0).unwrap();
+        assert_eq!(loaded.save_text(), game_state.save_text());
+
+        // Arquivo alterado depois de gravado
+        assert!(parse(
+            &text.replacen("phase = paused", "phase = playing", 1),
+            800.0,
+            600.0
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn version_is_checked() {
+        let body = paused_match().save_text();
+
+        let newer = seal(&format!("version = 2\n{}", body));
+        assert!(parse(&newer, 800.0, 600.0)
+            .err()
+            .unwrap()
+            .contains("versão 2"));
+
+        let missing = seal(&body);
+        assert_eq!(
+            parse(&missing, 800.0, 600.0).err().unwrap(),
+            "versão do arquivo ausente"
+        );
+    }
+}
@@ -0,0 +1,530 @@
+// Configurações do jogador salvas em disco
+
+use super::kv;
+use crate::game::handicap::{Handicap, HandicapOption};
+use crate::game::powerups::PowerUpKind;
+use crate::game::practice::PracticeTarget;
+use crate::game::rules::{ServeRule, WinRule};
+use std::path::Path;
+
+/// Esquemas de controle das raquetes
+#[derive(PartialEq, Clone, Copy)]
+pub enum ControlScheme {
+    /// Jogador 1 com W / S e jogador 2 com as setas
+    Keyboard,
+    /// Jogador 1 com as setas e jogador 2 com W / S
+    Swapped,
+    /// Jogador 1 com o mouse e jogador 2 com as setas
+    Mouse,
+}
+
+impl ControlScheme {
+    /// Todos os esquemas, na ordem em que aparecem no menu
+    pub const ALL: [ControlScheme; 3] = [
+        ControlScheme::Keyboard,
+        ControlScheme::Swapped,
+        ControlScheme::Mouse,
+    ];
+
+    /// Nome usado no arquivo de configurações
+    pub fn id(self) -> &'static str {
+        match self {
+            ControlScheme::Keyboard => "keyboard",
+            ControlScheme::Swapped => "swapped",
+            ControlScheme::Mouse => "mouse",
+        }
+    }
+
+    /// Chave do catálogo de mensagens com o nome do esquema
+    pub fn label_key(self) -> &'static str {
+        match self {
+            ControlScheme::Keyboard => "controls.keyboard",
+            ControlScheme::Swapped => "controls.swapped",
+            ControlScheme::Mouse => "controls.mouse",
+        }
+    }
+
+    /// Chave do catálogo de mensagens com a explicação das teclas de cada jogador
+    pub fn help_key(self) -> &'static str {
+        match self {
+            ControlScheme::Keyboard => "controls.keyboard_help",
+            ControlScheme::Swapped => "controls.swapped_help",
+            ControlScheme::Mouse => "controls.mouse_help",
+        }
+    }
+}
+
+/// Modos de exibição da janela
+#[derive(PartialEq, Clone, Copy)]
+pub enum DisplayMode {
+    /// Janela com tamanho fixo
+    Windowed,
+    /// Tela cheia na resolução da área de trabalho
+    Fullscreen,
+}
+
+impl DisplayMode {
+    /// Todos os modos, na ordem em que aparecem no menu
+    pub const ALL: [DisplayMode; 2] = [DisplayMode::Windowed, DisplayMode::Fullscreen];
+
+    /// Nome usado no arquivo de configurações
+    pub fn id(self) -> &'static str {
+        match self {
+            DisplayMode::Windowed => "windowed",
+            DisplayMode::Fullscreen => "fullscreen",
+        }
+    }
+
+    /// Chave do catálogo de mensagens com o nome do modo
+    pub fn label_key(self) -> &'static str {
+        match self {
+            DisplayMode::Windowed => "display.windowed",
+            DisplayMode::Fullscreen => "display.fullscreen",
+        }
+    }
+}
+
+/// Configurações ajustáveis pelo jogador
+#[derive(Clone)]
+pub struct Settings {
+    /// Regra de vitória
+    pub win_rule: WinRule,
+    /// Pontos necessários para vencer
+    pub win_score: u32,
+    /// Duração da partida com tempo (minutos)
+    pub match_minutes: u32,
+    /// Número máximo de sets da partida (melhor de N)
+    pub best_of_sets: u32,
+    /// Games necessários para vencer um set
+    pub games_per_set: u32,
+    /// Vidas de cada jogador no modo de 4 jogadores
+    pub lives: u32,
+    /// Bolas lançadas em cada saque (mais de uma ativa o modo multibola)
+    pub balls: u32,
+    /// Velocidade inicial da bola (pixels por segundo)
+    pub ball_speed: u32,
+    /// Altura das raquetes (pixels)
+    pub paddle_height: u32,
+    /// Regra que define quem saca depois de cada ponto
+    pub serve_rule: ServeRule,
+    /// Se verdadeiro, quem saca lança a bola com uma tecla
+    pub press_to_serve: bool,
+    /// Se verdadeiro, power-ups aparecem na quadra
+    pub power_ups: bool,
+    /// Power-ups que podem aparecer quando os power-ups estão ligados
+    pub power_up_kinds: Vec<PowerUpKind>,
+    /// Identificador da arena (obstáculos da quadra)
+    pub arena: String,
+    /// Parede ou padrão do lançador no modo treino
+    pub practice_target: PracticeTarget,
+    /// Vantagens dos jogadores 1 e 2
+    pub handicaps: [Handicap; 2],
+    /// Nomes dos perfis dos jogadores 1 e 2 (vazio = convidado, sem perfil)
+    pub profiles: [String; 2],
+    /// Volume dos sons (0 a 100)
+    pub volume: u32,
+    /// Identificador do tema de cores
+    pub theme: String,
+    /// Código do idioma (vazio = idioma do sistema)
+    pub language: String,
+    /// Esquema de controle das raquetes
+    pub controls: ControlScheme,
+    /// Modo de exibição da janela
+    pub display_mode: DisplayMode,
+}
+
+impl Settings {
+    /// Limites e passo de cada configuração numérica (mínimo, máximo, passo)
+    pub const WIN_SCORE_RANGE: (u32, u32, u32) = (1, 21, 1);
+    pub const MATCH_MINUTES_RANGE: (u32, u32, u32) = (1, 10, 1);
+    pub const BEST_OF_SETS_RANGE: (u32, u32, u32) = (1, 5, 2);
+    pub const GAMES_PER_SET_RANGE: (u32, u32, u32) = (1, 6, 1);
+    pub const LIVES_RANGE: (u32, u32, u32) = (1, 9, 1);
+    pub const BALLS_RANGE: (u32, u32, u32) = (1, 5, 1);
+    pub const BALL_SPEED_RANGE: (u32, u32, u32) = (150, 600, 50);
+    pub const PADDLE_HEIGHT_RANGE: (u32, u32, u32) = (40, 160, 10);
+    pub const HANDICAP_SIZE_RANGE: (u32, u32, u32) = (50, 200, 10);
+    pub const HANDICAP_SPEED_RANGE: (u32, u32, u32) = (50, 200, 10);
+    pub const START_POINTS_RANGE: (u32, u32, u32) = (0, 10, 1);
+    pub const VOLUME_RANGE: (u32, u32, u32) = (0, 100, 10);
+
+    /// Lê as configurações de um arquivo
+    ///
+    /// Se o arquivo não existir (por exemplo, se a gravação foi interrompida
+    /// depois de a versão anterior virar cópia de segurança), as configurações
+    /// são lidas da cópia de segurança; sem nenhuma das duas, retorna as
+    /// configurações padrão. Linhas com valores inválidos são ignoradas (ficam
+    /// com o valor padrão) sem perder as demais configurações; os erros são
+    /// informados no terminal.
+    ///
+    /// # Argumentos
+    ///
+    /// * `path` - Caminho do arquivo de configurações
+    pub fn load(path: &Path) -> Settings {
+        let parse = |text: &str| {
+            let (settings, errors) = Settings::parse(text);
+            for error in errors {
+                eprintln!("Configuração ignorada em {}: {}", path.display(), error);
+            }
+            Ok(settings)
+        };
+        super::load_recovering(path, parse).unwrap_or_default()
+    }
+
+    /// Lê as configurações a partir do texto de um arquivo
+    ///
+    /// Cada linha é lida separadamente: uma linha inválida é ignorada e as
+    /// outras continuam valendo. Números fora dos limites são trazidos para o
+    /// limite mais próximo. Chaves desconhecidas são ignoradas para que
+    /// arquivos de versões mais novas do jogo continuem funcionando.
+    ///
+    /// # Retorna
+    ///
+    /// As configurações lidas e a lista de linhas ignoradas (com o motivo)
+    pub fn parse(text: &str) -> (Settings, Vec<String>) {
+        let mut settings = Settings::default();
+        let mut errors = Vec::new();
+
+        for entry in kv::parse_lines(text) {
+            if let Err(e) = entry.and_then(|entry| settings.apply(&entry)) {
+                errors.push(e);
+            }
+        }
+
+        (settings, errors)
+    }
+
+    /// Aplica uma linha do arquivo de configurações
+    ///
+    /// # Argumentos
+    ///
+    /// * `entry` - Linha "chave = valor"
+    fn apply(&mut self, entry: &kv::Entry) -> Result<(), String> {
+        let invalid = || format!("linha {}: valor inválido '{}'", entry.line, entry.value);
+        let value = entry.value.as_str();
+
+        match entry.key.as_str() {
+            "win_rule" => {
+                self.win_rule = WinRule::ALL
+                    .into_iter()
+                    .find(|rule| rule.id() == value)
+                    .ok_or_else(invalid)?;
+            }
+            "match_minutes" => {
+                self.match_minutes =
+                    parse_clamped(value, Settings::MATCH_MINUTES_RANGE).ok_or_else(invalid)?;
+            }
+            "best_of_sets" => {
+                self.best_of_sets =
+                    parse_clamped(value, Settings::BEST_OF_SETS_RANGE).ok_or_else(invalid)?;
+            }
+            "games_per_set" => {
+                self.games_per_set =
+                    parse_clamped(value, Settings::GAMES_PER_SET_RANGE).ok_or_else(invalid)?;
+            }
+            "lives" => {
+                self.lives = parse_clamped(value, Settings::LIVES_RANGE).ok_or_else(invalid)?;
+            }
+            "balls" => {
+                self.balls = parse_clamped(value, Settings::BALLS_RANGE).ok_or_else(invalid)?;
+            }
+            "win_score" => {
+                self.win_score =
+                    parse_clamped(value, Settings::WIN_SCORE_RANGE).ok_or_else(invalid)?;
+            }
+            "ball_speed" => {
+                self.ball_speed =
+                    parse_clamped(value, Settings::BALL_SPEED_RANGE).ok_or_else(invalid)?;
+            }
+            "paddle_height" => {
+                self.paddle_height =
+                    parse_clamped(value, Settings::PADDLE_HEIGHT_RANGE).ok_or_else(invalid)?;
+            }
+            "serve_rule" => {
+                self.serve_rule = ServeRule::ALL
+                    .into_iter()
+                    .find(|rule| rule.id() == value)
+                    .ok_or_else(invalid)?;
+            }
+            "press_to_serve" => {
+                self.press_to_serve = value.parse().map_err(|_| invalid())?;
+            }
+            "power_ups" => {
+                self.power_ups = value.parse().map_err(|_| invalid())?;
+            }
+            "power_up_kinds" => {
+                self.power_up_kinds = value
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|id| !id.is_empty())
+                    .map(|id| {
+                        PowerUpKind::ALL
+                            .into_iter()
+                            .find(|kind| kind.id() == id)
+                            .ok_or_else(invalid)
+                    })
+                    .collect::<Result<_, _>>()?;
+            }
+            "arena" => self.arena = value.to_string(),
+            "practice_target" => {
+                self.practice_target = PracticeTarget::ALL
+                    .into_iter()
+                    .find(|target| target.id() == value)
+                    .ok_or_else(invalid)?;
+            }
+            "volume" => {
+                self.volume = parse_clamped(value, Settings::VOLUME_RANGE).ok_or_else(invalid)?;
+            }
+            "profile1" => self.profiles[0] = value.to_string(),
+            "profile2" => self.profiles[1] = value.to_string(),
+            "theme" => self.theme = value.to_string(),
+            "language" => self.language = value.to_string(),
+            "controls" => {
+                self.controls = ControlScheme::ALL
+                    .into_iter()
+                    .find(|scheme| scheme.id() == value)
+                    .ok_or_else(invalid)?;
+            }
+            "display_mode" => {
+                self.display_mode = DisplayMode::ALL
+                    .into_iter()
+                    .find(|mode| mode.id() == value)
+                    .ok_or_else(invalid)?;
+            }
+            key => {
+                // Vantagens de cada jogador (ex: handicap1_paddle_size)
+                let Some((player, option)) = parse_handicap_key(key) else {
+                    return Ok(());
+                };
+                let handicap = &mut self.handicaps[player];
+                match option {
+                    HandicapOption::PaddleSize => {
+                        handicap.paddle_size = parse_clamped(value, Settings::HANDICAP_SIZE_RANGE)
+                            .ok_or_else(invalid)?;
+                    }
+                    HandicapOption::PaddleSpeed => {
+                        handicap.paddle_speed =
+                            parse_clamped(value, Settings::HANDICAP_SPEED_RANGE)
+                                .ok_or_else(invalid)?;
+                    }
+                    HandicapOption::StartPoints => {
+                        handicap.start_points = parse_clamped(value, Settings::START_POINTS_RANGE)
+                            .ok_or_else(invalid)?;
+                    }
+                    HandicapOption::AiAssist => {
+                        handicap.ai_assist = value.parse().map_err(|_| invalid())?;
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Converte as configurações para o formato do arquivo
+    pub fn to_text(&self) -> String {
+        let mut text = format!(
+            "# Configurações do Pong with Rust\n\
+             win_rule = {}\n\
+             win_score = {}\n\
+             match_minutes = {}\n\
+             best_of_sets = {}\n\
+             games_per_set = {}\n\
+             lives = {}\n\
+             balls = {}\n\
+             ball_speed = {}\n\
+             paddle_height = {}\n\
+             serve_rule = {}\n\
+             press_to_serve = {}\n\
+             power_ups = {}\n\
+             power_up_kinds = {}\n\
+             arena = {}\n\
+             practice_target = {}\n\
+             volume = {}\n\
+             theme = {}\n\
+             language = {}\n\
+             controls = {}\n\
+             display_mode = {}\n",
+            self.win_rule.id(),
+            self.win_score,
+            self.match_minutes,
+            self.best_of_sets,
+            self.games_per_set,
+            self.lives,
+            self.balls,
+            self.ball_speed,
+            self.paddle_height,
+            self.serve_rule.id(),
+            self.press_to_serve,
+            self.power_ups,
+            self.power_up_kinds
+                .iter()
+                .map(|kind| kind.id())
+                .collect::<Vec<_>>()
+                .join(","),
+            self.arena,
+            self.practice_target.id(),
+            self.volume,
+            self.theme,
+            self.language,
+            self.controls.id(),
+            self.display_mode.id(),
+        );
+
+        for (player, handicap) in self.handicaps.iter().enumerate() {
+            let values = [
+                (HandicapOption::PaddleSize, handicap.paddle_size.to_string()),
+                (
+                    HandicapOption::PaddleSpeed,
+                    handicap.paddle_speed.to_string(),
+                ),
+                (
+                    HandicapOption::StartPoints,
+                    handicap.start_points.to_string(),
+                ),
+                (HandicapOption::AiAssist, handicap.ai_assist.to_string()),
+            ];
+            for (option, value) in values {
+                text.push_str(&format!(
+                    "handicap{}_{} = {}\n",
+                    player + 1,
+                    option.id(),
+                    value
+                ));
+            }
+        }
+
+        for (player, profile) in self.profiles.iter().enumerate() {
+            text.push_str(&format!("profile{} = {}\n", player + 1, profile));
+        }
+
+        text
+    }
+
+    /// Salva as configurações em um arquivo (gravação atômica)
+    ///
+    /// # Argumentos
+    ///
+    /// * `path` - Caminho do arquivo de configurações
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        super::write_atomic(path, &self.to_text())
+    }
+}
+
+impl Default for Settings {
+    fn default() -> Settings {
+        Settings {
+            win_rule: WinRule::FirstTo,
+            win_score: 5,
+            match_minutes: 3,
+            best_of_sets: 1,
+            games_per_set: 1,
+            lives: 3,
+            balls: 1,
+            ball_speed: 300,
+            paddle_height: 80,
+            serve_rule: ServeRule::LoserServes,
+            press_to_serve: false,
+            power_ups: false,
+            power_up_kinds: PowerUpKind::ALL.to_vec(),
+            arena: String::from("classic"),
+            practice_target: PracticeTarget::Wall,
+            handicaps: [Handicap::default(); 2],
+            profiles: [String::new(), String::new()],
+            volume: 80,
+            theme: String::from("classic"),
+            language: String::new(),
+            controls: ControlScheme::Keyboard,
+            display_mode: DisplayMode::Windowed,
+        }
+    }
+}
+
+/// Altera um valor numérico em um passo, respeitando os limites
+///
+/// # Argumentos
+///
+/// * `value` - Valor atual
+/// * `range` - Limites e passo (mínimo, máximo, passo)
+/// * `direction` - 1 para aumentar, -1 para diminuir
+pub fn step_value(value: u32, range: (u32, u32, u32), direction: i32) -> u32 {
+    let (min, max, step) = range;
+    if direction < 0 {
+        value.saturating_sub(step).max(min)
+    } else {
+        (value + step).min(max)
+    }
+}
+
+/// Separa uma chave de vantagem (`handicap<jogador>_<opção>`) no índice do
+/// jogador e na opção
+fn parse_handicap_key(key: &str) -> Option<(usize, HandicapOption)> {
+    let (player, option) = key.strip_prefix("handicap")?.split_once('_')?;
+    let player = match player {
+        "1" => 0,
+        "2" => 1,
+        _ => return None,
+    };
+    let option = HandicapOption::ALL
+        .into_iter()
+        .find(|other| other.id() == option)?;
+    Some((player, option))
+}
+
+/// Converte um texto em número, trazendo valores fora dos limites para o
+/// limite mais próximo
+fn parse_clamped(value: &str, range: (u32, u32, u32)) -> Option<u32> {
+    let (min, max, _) = range;
+    value
+        .parse::<u32>()
+        .ok()
+        .map(|number| number.clamp(min, max))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn invalid_line_keeps_the_other_settings() {
+        let text = "win_score = 11\nball_speed = fast\nsem igual\nvolume = 40\n";
+        let (settings, errors) = Settings::parse(text);
+
+        assert_eq!(settings.win_score, 11);
+        assert_eq!(settings.ball_speed, Settings::default().ball_speed);
+        assert_eq!(settings.volume, 40);
+        assert_eq!(errors.len(), 2);
+        assert!(errors[0].starts_with("linha 2:"));
+        assert!(errors[1].starts_with("linha 3:"));
+    }
+
+    #[test]
+    fn out_of_range_numbers_are_clamped() {
+        let (settings, errors) = Settings::parse("win_score = 99\nlives = 0\n");
+
+        assert!(errors.is_empty());
+        assert_eq!(settings.win_score, Settings::WIN_SCORE_RANGE.1);
+        assert_eq!(settings.lives, Settings::LIVES_RANGE.0);
+    }
+
+    #[test]
+    fn interrupted_save_loads_the_backup() {
+        let path = crate::storage::tests::temp_dir("settings").join("settings.cfg");
+        let old = Settings {
+            win_score: 7,
+            ..Settings::default()
+        };
+        old.save(&path).unwrap();
+        Settings {
+            win_score: 9,
+            ..Settings::default()
+        }
+        .save(&path)
+        .unwrap();
+        assert_eq!(Settings::load(&path).win_score, 9);
+
+        // A gravação parou depois de a versão anterior virar cópia de segurança
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(Settings::load(&path).win_score, 7);
+    }
+}
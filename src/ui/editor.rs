@@ -0,0 +1,528 @@
+// Editor de arenas: obstáculos, raquetes e pontos de lançamento com o mouse
+
+use crate::game::arena::{Arena, Obstacle, ObstacleKind, Segment};
+use crate::game::game_state::{Side, PADDLE_OFFSET};
+use crate::game::theme::Theme;
+use sdl2::keyboard::Keycode;
+use sdl2::rect::{Point, Rect};
+use sdl2::render::Canvas;
+use sdl2::video::Window;
+
+/// Tamanho de cada célula da grade (os itens se alinham a ela)
+const GRID_SIZE: f32 = 20.0;
+
+/// Distância máxima do cursor para pegar uma parede ou um defletor
+const PICK_DISTANCE: f32 = 8.0;
+
+/// Tamanho do marcador de um ponto de lançamento
+const SPAWN_SIZE: f32 = 12.0;
+
+/// Lado dos blocos, defletores e portais novos
+const NEW_ITEM_SIZE: f32 = 40.0;
+
+/// Lado dos bumpers novos
+const NEW_BUMPER_SIZE: f32 = 30.0;
+
+/// Comprimento das paredes novas
+const NEW_WALL_LENGTH: f32 = 80.0;
+
+/// Item colocado com o clique do mouse
+#[derive(PartialEq, Clone, Copy)]
+pub enum Tool {
+    /// Bloco retangular
+    Block,
+    /// Bumper (acelera a bola)
+    Bumper,
+    /// Defletor inclinado
+    Deflector,
+    /// Par de portais
+    Portal,
+    /// Parede vertical
+    Wall,
+    /// Ponto de lançamento da bola
+    Spawn,
+}
+
+impl Tool {
+    /// Todas as ferramentas, na ordem das teclas 1 a 6
+    pub const ALL: [Tool; 6] = [
+        Tool::Block,
+        Tool::Bumper,
+        Tool::Deflector,
+        Tool::Portal,
+        Tool::Wall,
+        Tool::Spawn,
+    ];
+
+    /// Chave do catálogo de mensagens com o nome da ferramenta
+    pub fn label_key(self) -> &'static str {
+        match self {
+            Tool::Block => "editor.tool.block",
+            Tool::Bumper => "editor.tool.bumper",
+            Tool::Deflector => "editor.tool.deflector",
+            Tool::Portal => "editor.tool.portal",
+            Tool::Wall => "editor.tool.wall",
+            Tool::Spawn => "editor.tool.spawn",
+        }
+    }
+}
+
+/// Item da arena sob o cursor (ou sendo arrastado)
+#[derive(PartialEq, Clone, Copy)]
+enum Grab {
+    /// Parede (índice em `walls`)
+    Wall(usize),
+    /// Obstáculo (índice em `obstacles`); nos portais, o primeiro portal
+    Obstacle(usize),
+    /// Segundo portal de um par (índice em `obstacles`)
+    PortalExit(usize),
+    /// Ponto de lançamento (índice em `spawns`)
+    Spawn(usize),
+    /// Raquete de um dos lados
+    Lane(Side),
+}
+
+/// O que o editor pede à aplicação
+pub enum EditorAction {
+    /// Jogar na arena editada
+    TestPlay,
+    /// Salvar a arena em arquivo
+    Save,
+    /// Fechar o editor
+    Exit,
+}
+
+/// Estado do editor de arenas
+pub struct Editor {
+    /// Arena sendo editada
+    pub arena: Arena,
+    /// Ferramenta usada no próximo clique
+    pub tool: Tool,
+    /// Mensagem exibida depois de salvar (sucesso ou erro)
+    pub status: Option<String>,
+    /// Indica que a arena está sendo testada em uma partida
+    pub testing: bool,
+    /// Item sendo arrastado e a última posição (na grade) do cursor
+    grab: Option<(Grab, f32, f32)>,
+    /// Posição do cursor alinhada à grade
+    cursor: (f32, f32),
+    /// Largura das raquetes (para desenhá-las e pegá-las)
+    paddle_width: f32,
+    /// Altura das raquetes (para desenhá-las e pegá-las)
+    paddle_height: f32,
+}
+
+impl Editor {
+    /// Abre o editor com uma arena
+    ///
+    /// # Argumentos
+    ///
+    /// * `arena` - Arena inicial (já ajustada ao tamanho da quadra)
+    /// * `paddle_width` - Largura das raquetes
+    /// * `paddle_height` - Altura das raquetes
+    pub fn new(arena: Arena, paddle_width: f32, paddle_height: f32) -> Editor {
+        Editor {
+            arena,
+            tool: Tool::Block,
+            status: None,
+            testing: false,
+            grab: None,
+            cursor: (0.0, 0.0),
+            paddle_width,
+            paddle_height,
+        }
+    }
+
+    /// Trata teclas pressionadas no editor
+    ///
+    /// # Argumentos
+    ///
+    /// * `keycode` - Código da tecla pressionada
+    ///
+    /// # Retorna
+    ///
+    /// O que a aplicação deve fazer, se a tecla pedir algo além da edição
+    pub fn handle_keydown(&mut self, keycode: Keycode) -> Option<EditorAction> {
+        let tool = match keycode {
+            Keycode::Num1 | Keycode::Kp1 => 0,
+            Keycode::Num2 | Keycode::Kp2 => 1,
+            Keycode::Num3 | Keycode::Kp3 => 2,
+            Keycode::Num4 | Keycode::Kp4 => 3,
+            Keycode::Num5 | Keycode::Kp5 => 4,
+            Keycode::Num6 | Keycode::Kp6 => 5,
+            Keycode::Tab => {
+                let index = Tool::ALL
+                    .iter()
+                    .position(|tool| *tool == self.tool)
+                    .unwrap_or(0);
+                (index + 1) % Tool::ALL.len()
+            }
+            Keycode::Delete | Keycode::Backspace => {
+                let (x, y) = self.cursor;
+                self.remove(x as i32, y as i32);
+                return None;
+            }
+            Keycode::T | Keycode::Return | Keycode::KpEnter => return Some(EditorAction::TestPlay),
+            Keycode::S => return Some(EditorAction::Save),
+            Keycode::Escape => return Some(EditorAction::Exit),
+            _ => return None,
+        };
+        self.tool = Tool::ALL[tool];
+        None
+    }
+
+    /// Clique do mouse: pega o item sob o cursor ou coloca um novo
+    ///
+    /// # Argumentos
+    ///
+    /// * `x` - Posição X do cursor
+    /// * `y` - Posição Y do cursor
+    pub fn press(&mut self, x: i32, y: i32) {
+        let (grid_x, grid_y) = self.snap(x, y);
+        self.cursor = (grid_x, grid_y);
+
+        // O item colocado já fica preso ao cursor para ser arrastado
+        let grab = self
+            .item_at(x as f32, y as f32)
+            .unwrap_or_else(|| self.place(grid_x, grid_y));
+        self.grab = Some((grab, grid_x, grid_y));
+        self.status = None;
+    }
+
+    /// Movimento do mouse: arrasta o item preso ao cursor
+    ///
+    /// # Argumentos
+    ///
+    /// * `x` - Posição X do cursor
+    /// * `y` - Posição Y do cursor
+    pub fn drag(&mut self, x: i32, y: i32) {
+        let (grid_x, grid_y) = self.snap(x, y);
+        self.cursor = (grid_x, grid_y);
+
+        let Some((grab, last_x, last_y)) = self.grab else {
+            return;
+        };
+        let (dx, dy) = (grid_x - last_x, grid_y - last_y);
+        if dx != 0.0 || dy != 0.0 {
+            self.move_item(grab, dx, dy);
+            self.grab = Some((grab, grid_x, grid_y));
+        }
+    }
+
+    /// Botão do mouse solto: larga o item arrastado
+    pub fn release(&mut self) {
+        self.grab = None;
+    }
+
+    /// Apaga o item sob o cursor (as raquetes voltam à posição padrão)
+    ///
+    /// # Argumentos
+    ///
+    /// * `x` - Posição X do cursor
+    /// * `y` - Posição Y do cursor
+    pub fn remove(&mut self, x: i32, y: i32) {
+        let Some(grab) = self.item_at(x as f32, y as f32) else {
+            return;
+        };
+        match grab {
+            Grab::Wall(index) => {
+                self.arena.walls.remove(index);
+            }
+            Grab::Obstacle(index) | Grab::PortalExit(index) => {
+                self.arena.obstacles.remove(index);
+            }
+            Grab::Spawn(index) => {
+                self.arena.spawns.remove(index);
+            }
+            Grab::Lane(Side::Left) => self.arena.lane_left = None,
+            Grab::Lane(Side::Right) => self.arena.lane_right = None,
+        }
+        self.grab = None;
+        self.status = None;
+    }
+
+    /// Alinha uma posição da tela à grade
+    fn snap(&self, x: i32, y: i32) -> (f32, f32) {
+        let snap = |value: i32| (value as f32 / GRID_SIZE).round() * GRID_SIZE;
+        (
+            snap(x).clamp(0.0, self.arena.width),
+            snap(y).clamp(0.0, self.arena.height),
+        )
+    }
+
+    /// Coloca um item da ferramenta atual na posição indicada
+    ///
+    /// # Retorna
+    ///
+    /// O item colocado
+    fn place(&mut self, x: f32, y: f32) -> Grab {
+        let half = NEW_ITEM_SIZE / 2.0;
+        let kind = match self.tool {
+            Tool::Block => ObstacleKind::Block {
+                x: x - half,
+                y: y - half,
+                width: NEW_ITEM_SIZE,
+                height: NEW_ITEM_SIZE,
+            },
+            Tool::Bumper => ObstacleKind::Bumper {
+                x,
+                y,
+                size: NEW_BUMPER_SIZE,
+            },
+            Tool::Deflector => {
+                ObstacleKind::Deflector(Segment::new(x - half, y - half, x + half, y + half))
+            }
+            // O segundo portal aparece do outro lado da quadra
+            Tool::Portal => ObstacleKind::Portal {
+                x,
+                y,
+                exit_x: self.arena.width - x,
+                exit_y: self.arena.height - y,
+                size: NEW_ITEM_SIZE,
+            },
+            Tool::Wall => {
+                let half_length = NEW_WALL_LENGTH / 2.0;
+                self.arena
+                    .walls
+                    .push(Segment::new(x, y - half_length, x, y + half_length));
+                return Grab::Wall(self.arena.walls.len() - 1);
+            }
+            Tool::Spawn => {
+                self.arena.spawns.push((x, y));
+                return Grab::Spawn(self.arena.spawns.len() - 1);
+            }
+        };
+        self.arena.obstacles.push(Obstacle::fixed(kind));
+        Grab::Obstacle(self.arena.obstacles.len() - 1)
+    }
+
+    /// Retorna a posição da raquete de um lado (canto superior esquerdo)
+    fn paddle_position(&self, side: Side) -> (f32, f32) {
+        let y = (self.arena.height - self.paddle_height) / 2.0;
+        match side {
+            Side::Left => (self.arena.lane_left.unwrap_or(PADDLE_OFFSET), y),
+            Side::Right => (
+                self.arena.width
+                    - self.arena.lane_right.unwrap_or(PADDLE_OFFSET)
+                    - self.paddle_width,
+                y,
+            ),
+        }
+    }
+
+    /// Procura o item sob o cursor (o item desenhado por cima tem preferência)
+    fn item_at(&self, x: f32, y: f32) -> Option<Grab> {
+        let inside_square = |cx: f32, cy: f32, size: f32| {
+            (x - cx).abs() <= size / 2.0 && (y - cy).abs() <= size / 2.0
+        };
+
+        if let Some(index) = self
+            .arena
+            .spawns
+            .iter()
+            .rposition(|&(sx, sy)| inside_square(sx, sy, SPAWN_SIZE))
+        {
+            return Some(Grab::Spawn(index));
+        }
+
+        for side in [Side::Left, Side::Right] {
+            let (px, py) = self.paddle_position(side);
+            if x >= px && x <= px + self.paddle_width && y >= py && y <= py + self.paddle_height {
+                return Some(Grab::Lane(side));
+            }
+        }
+
+        for (index, obstacle) in self.arena.obstacles.iter().enumerate().rev() {
+            match obstacle.kind {
+                ObstacleKind::Block {
+                    x: bx,
+                    y: by,
+                    width,
+                    height,
+                } => {
+                    if x >= bx && x <= bx + width && y >= by && y <= by + height {
+                        return Some(Grab::Obstacle(index));
+                    }
+                }
+                ObstacleKind::Bumper { x: bx, y: by, size } => {
+                    if inside_square(bx, by, size) {
+                        return Some(Grab::Obstacle(index));
+                    }
+                }
+                ObstacleKind::Deflector(segment) => {
+                    if segment_distance(&segment, x, y) <= PICK_DISTANCE {
+                        return Some(Grab::Obstacle(index));
+                    }
+                }
+                ObstacleKind::Portal {
+                    x: px,
+                    y: py,
+                    exit_x,
+                    exit_y,
+                    size,
+                } => {
+                    if inside_square(px, py, size) {
+                        return Some(Grab::Obstacle(index));
+                    }
+                    if inside_square(exit_x, exit_y, size) {
+                        return Some(Grab::PortalExit(index));
+                    }
+                }
+            }
+        }
+
+        self.arena
+            .walls
+            .iter()
+            .rposition(|wall| segment_distance(wall, x, y) <= PICK_DISTANCE)
+            .map(Grab::Wall)
+    }
+
+    /// Desloca um item da arena
+    fn move_item(&mut self, grab: Grab, dx: f32, dy: f32) {
+        let arena = &mut self.arena;
+        match grab {
+            Grab::Wall(index) => arena.walls[index] = arena.walls[index].offset(dx, dy),
+            Grab::Spawn(index) => {
+                let spawn = &mut arena.spawns[index];
+                *spawn = (spawn.0 + dx, spawn.1 + dy);
+            }
+            // As raquetes só andam na horizontal, até o meio da quadra
+            Grab::Lane(side) => {
+                let max_lane = arena.width / 2.0 - self.paddle_width;
+                let (lane, dx) = match side {
+                    Side::Left => (&mut arena.lane_left, dx),
+                    Side::Right => (&mut arena.lane_right, -dx),
+                };
+                *lane = Some((lane.unwrap_or(PADDLE_OFFSET) + dx).clamp(0.0, max_lane));
+            }
+            Grab::Obstacle(index) | Grab::PortalExit(index) => {
+                let obstacle = &mut arena.obstacles[index];
+                obstacle.kind = match obstacle.kind {
+                    ObstacleKind::Block {
+                        x,
+                        y,
+                        width,
+                        height,
+                    } => ObstacleKind::Block {
+                        x: x + dx,
+                        y: y + dy,
+                        width,
+                        height,
+                    },
+                    ObstacleKind::Bumper { x, y, size } => ObstacleKind::Bumper {
+                        x: x + dx,
+                        y: y + dy,
+                        size,
+                    },
+                    ObstacleKind::Deflector(segment) => {
+                        ObstacleKind::Deflector(segment.offset(dx, dy))
+                    }
+                    // Cada portal do par é arrastado separadamente
+                    ObstacleKind::Portal {
+                        x,
+                        y,
+                        exit_x,
+                        exit_y,
+                        size,
+                    } => {
+                        if grab == Grab::PortalExit(index) {
+                            ObstacleKind::Portal {
+                                x,
+                                y,
+                                exit_x: exit_x + dx,
+                                exit_y: exit_y + dy,
+                                size,
+                            }
+                        } else {
+                            ObstacleKind::Portal {
+                                x: x + dx,
+                                y: y + dy,
+                                exit_x,
+                                exit_y,
+                                size,
+                            }
+                        }
+                    }
+                };
+            }
+        }
+    }
+
+    /// Desenha a grade, a arena, as raquetes e os pontos de lançamento
+    ///
+    /// # Argumentos
+    ///
+    /// * `canvas` - Canvas SDL para desenhar
+    /// * `theme` - Tema de cores
+    pub fn render(&self, canvas: &mut Canvas<Window>, theme: &Theme) {
+        canvas.set_draw_color(theme.background);
+        canvas.clear();
+
+        // Grade de pontos
+        canvas.set_draw_color(theme.center_line);
+        let mut y = 0.0;
+        while y <= self.arena.height {
+            let mut x = 0.0;
+            while x <= self.arena.width {
+                canvas.draw_point(Point::new(x as i32, y as i32)).unwrap();
+                x += GRID_SIZE;
+            }
+            y += GRID_SIZE;
+        }
+
+        self.arena.render(canvas, theme);
+
+        for (side, color) in [
+            (Side::Left, theme.player_left),
+            (Side::Right, theme.player_right),
+        ] {
+            let (x, y) = self.paddle_position(side);
+            canvas.set_draw_color(color);
+            canvas
+                .fill_rect(Rect::new(
+                    x as i32,
+                    y as i32,
+                    self.paddle_width as u32,
+                    self.paddle_height as u32,
+                ))
+                .unwrap();
+        }
+
+        // Pontos de lançamento são desenhados como a bola, só com o contorno
+        canvas.set_draw_color(theme.ball);
+        for &(x, y) in &self.arena.spawns {
+            let half = SPAWN_SIZE / 2.0;
+            canvas
+                .draw_rect(Rect::new(
+                    (x - half) as i32,
+                    (y - half) as i32,
+                    SPAWN_SIZE as u32,
+                    SPAWN_SIZE as u32,
+                ))
+                .unwrap();
+        }
+
+        // Cruz na posição da grade onde o próximo item será colocado
+        let (x, y) = (self.cursor.0 as i32, self.cursor.1 as i32);
+        canvas.set_draw_color(theme.accent);
+        canvas
+            .draw_line(Point::new(x - 6, y), Point::new(x + 6, y))
+            .unwrap();
+        canvas
+            .draw_line(Point::new(x, y - 6), Point::new(x, y + 6))
+            .unwrap();
+    }
+}
+
+/// Distância de um ponto até um segmento
+fn segment_distance(segment: &Segment, x: f32, y: f32) -> f32 {
+    let (dx, dy) = (segment.x2 - segment.x1, segment.y2 - segment.y1);
+    let length_sq = dx * dx + dy * dy;
+    let t = if length_sq > 0.0 {
+        (((x - segment.x1) * dx + (y - segment.y1) * dy) / length_sq).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    (x - (segment.x1 + dx * t)).hypot(y - (segment.y1 + dy * t))
+}
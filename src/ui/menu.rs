@@ -0,0 +1,726 @@
+// Sistema de menus navegáveis com submenus e pilha de navegação
+
+use crate::game::ai::Difficulty;
+use crate::game::arcade::ArcadeMode;
+use crate::game::handicap::HandicapOption;
+use crate::game::powerups::PowerUpKind;
+
+/// Telas de menu disponíveis
+#[derive(PartialEq, Clone, Copy)]
+pub enum MenuId {
+    /// Menu principal
+    Main,
+    /// Escolha da dificuldade da CPU
+    PlayCpu,
+    /// Outros modos de jogo (4 jogadores, duplas, breakout, treino, arcade, torneio)
+    Modes,
+    /// Treino sozinho (alvo e início)
+    Practice,
+    /// Modos arcade (sobrevivência e contra o relógio)
+    Arcade,
+    /// Inscrição dos jogadores e formato do torneio
+    Tournament,
+    /// Chave do torneio em andamento (mostrada entre as partidas)
+    Bracket,
+    /// Recordes de cada modo
+    Leaderboards,
+    /// Perfis dos jogadores 1 e 2 e cadastro de perfis
+    Profiles,
+    /// Estatísticas de um perfil
+    ProfileStats,
+    /// Perfis ordenados pelo rating
+    Ladder,
+    /// Configurações
+    Settings,
+    /// Configurações da partida (pontos, bola, raquetes, saque)
+    GameSettings,
+    /// Configurações de áudio e vídeo
+    AudioVideoSettings,
+    /// Power-ups ligados e desligados
+    PowerUpSettings,
+    /// Vantagens de cada jogador
+    HandicapSettings,
+    /// Menu de pausa (aberto sobre a partida)
+    Pause,
+    /// Escolha de qual jogador desiste da partida
+    Forfeit,
+}
+
+impl MenuId {
+    /// Chave do catálogo de mensagens com o título do menu
+    pub fn title_key(self) -> &'static str {
+        match self {
+            MenuId::Main => "menu.title",
+            MenuId::PlayCpu => "menu.play_cpu",
+            MenuId::Modes => "menu.modes",
+            MenuId::Practice => "menu.practice",
+            MenuId::Arcade => "menu.arcade",
+            MenuId::Tournament => "menu.tournament",
+            MenuId::Bracket => "tournament.bracket",
+            MenuId::Leaderboards => "menu.leaderboards",
+            MenuId::Profiles => "menu.profiles",
+            MenuId::ProfileStats => "profiles.stats",
+            MenuId::Ladder => "profiles.ladder",
+            MenuId::Settings => "menu.settings",
+            MenuId::GameSettings => "settings.game",
+            MenuId::AudioVideoSettings => "settings.audio_video",
+            MenuId::PowerUpSettings => "settings.power_ups",
+            MenuId::HandicapSettings => "settings.handicaps",
+            MenuId::Pause => "pause.title",
+            MenuId::Forfeit => "pause.forfeit",
+        }
+    }
+
+    /// Posição Y do primeiro item e distância vertical entre os itens
+    pub fn layout(self) -> (i32, i32) {
+        match self {
+            // Configurações da partida têm mais itens e ficam mais próximas
+            MenuId::GameSettings | MenuId::HandicapSettings => (115, 34),
+            MenuId::PowerUpSettings => (140, 40),
+            MenuId::Modes => (170, 42),
+            // O menu principal tem mais itens e começa abaixo da regra de vitória
+            MenuId::Main => (165, 33),
+            // Os recordes e as estatísticas ocupam o meio da tela e os itens ficam embaixo
+            MenuId::Leaderboards => (400, 50),
+            MenuId::ProfileStats => (420, 45),
+            MenuId::Ladder => (490, 45),
+            // Os jogadores inscritos e a chave ficam acima dos itens
+            MenuId::Tournament => (300, 38),
+            MenuId::Bracket => (420, 40),
+            _ => (190, 45),
+        }
+    }
+
+    /// Indica se o menu faz parte das configurações (usado para salvá-las ao sair)
+    pub fn is_settings(self) -> bool {
+        matches!(
+            self,
+            MenuId::Settings
+                | MenuId::Profiles
+                | MenuId::Practice
+                | MenuId::GameSettings
+                | MenuId::AudioVideoSettings
+                | MenuId::PowerUpSettings
+                | MenuId::HandicapSettings
+        )
+    }
+}
+
+/// Configurações que podem ser alteradas pelo menu
+#[derive(PartialEq, Clone, Copy)]
+pub enum SettingId {
+    /// Regra de vitória
+    WinRule,
+    /// Pontos para vencer
+    WinScore,
+    /// Duração da partida com tempo
+    MatchTime,
+    /// Número máximo de sets (melhor de N)
+    BestOfSets,
+    /// Games para vencer um set
+    GamesPerSet,
+    /// Vidas no modo de 4 jogadores
+    Lives,
+    /// Bolas lançadas em cada saque (modo multibola)
+    Balls,
+    /// Velocidade inicial da bola
+    BallSpeed,
+    /// Altura das raquetes
+    PaddleSize,
+    /// Quem saca depois de cada ponto
+    ServeRule,
+    /// Saque com tecla em vez de automático
+    PressToServe,
+    /// Power-ups ligados ou desligados
+    PowerUps,
+    /// Um power-up específico ligado ou desligado
+    PowerUp(PowerUpKind),
+    /// Arena (obstáculos da quadra)
+    Arena,
+    /// Parede ou padrão do lançador no treino
+    PracticeTarget,
+    /// Uma vantagem de um jogador (índice 0 ou 1)
+    Handicap(usize, HandicapOption),
+    /// Volume dos sons
+    Volume,
+    /// Tema de cores
+    Theme,
+    /// Idioma da interface
+    Language,
+    /// Esquema de controle das raquetes
+    Controls,
+    /// Ranking mostrado na tela de recordes
+    LeaderboardBoard,
+    /// Perfil de um jogador (índice 0 ou 1)
+    Profile(usize),
+    /// Perfil mostrado na tela de estatísticas
+    StatsProfile,
+    /// Formato do torneio
+    TournamentFormat,
+    /// Modo de exibição (janela ou tela cheia)
+    DisplayMode,
+}
+
+impl SettingId {
+    /// Indica se a configuração muda as regras ou o tamanho das coisas da
+    /// partida (as demais não mexem no estado do jogo)
+    pub fn affects_match(self) -> bool {
+        matches!(
+            self,
+            SettingId::WinRule
+                | SettingId::WinScore
+                | SettingId::MatchTime
+                | SettingId::BestOfSets
+                | SettingId::GamesPerSet
+                | SettingId::Lives
+                | SettingId::Balls
+                | SettingId::BallSpeed
+                | SettingId::PaddleSize
+                | SettingId::ServeRule
+                | SettingId::PressToServe
+                | SettingId::PowerUps
+                | SettingId::PowerUp(_)
+                | SettingId::Handicap(..)
+        )
+    }
+}
+
+/// O que acontece quando um item é escolhido
+#[derive(PartialEq, Clone, Copy)]
+pub enum ItemKind {
+    /// Inicia uma partida (contra a CPU quando há dificuldade)
+    Play(Option<Difficulty>),
+    /// Abre um submenu
+    Submenu(MenuId),
+    /// Altera uma configuração (com esquerda/direita ou confirmando)
+    Setting(SettingId),
+    /// Pede um comando diretamente à aplicação
+    Command(MenuCommand),
+    /// Volta ao menu anterior
+    Back,
+    /// Sai do jogo
+    Quit,
+}
+
+/// Um item selecionável do menu
+pub struct MenuItem {
+    /// Chave do catálogo de mensagens com o texto do item
+    pub label: &'static str,
+    /// Ação do item
+    pub kind: ItemKind,
+    /// Itens desabilitados aparecem no menu mas não podem ser escolhidos
+    pub enabled: bool,
+}
+
+impl MenuItem {
+    /// Cria um item habilitado
+    fn new(label: &'static str, kind: ItemKind) -> MenuItem {
+        MenuItem {
+            label,
+            kind,
+            enabled: true,
+        }
+    }
+
+    /// Cria um item desabilitado (recurso ainda não disponível)
+    fn disabled(label: &'static str, kind: ItemKind) -> MenuItem {
+        MenuItem {
+            label,
+            kind,
+            enabled: false,
+        }
+    }
+}
+
+/// Entradas de navegação (vindas do teclado ou do controle)
+#[derive(PartialEq, Clone, Copy)]
+pub enum MenuInput {
+    Up,
+    Down,
+    Left,
+    Right,
+    Select,
+    Back,
+}
+
+/// Comandos que o menu pede para a aplicação executar
+#[derive(PartialEq, Clone, Copy)]
+pub enum MenuCommand {
+    /// Inicia uma partida (contra a CPU quando há dificuldade)
+    StartMatch(Option<Difficulty>),
+    /// Altera uma configuração na direção indicada (-1 ou 1)
+    ChangeSetting(SettingId, i32),
+    /// Continua a partida pausada
+    Resume,
+    /// Reinicia a partida atual
+    Restart,
+    /// Salva a partida pausada para continuar depois
+    SaveGame,
+    /// Continua a partida salva
+    LoadGame,
+    /// Inicia uma partida de 4 jogadores
+    StartFourPlayer,
+    /// Inicia uma partida de duplas (2 contra 2)
+    StartDoubles,
+    /// Inicia uma partida de breakout (paredes de tijolos atrás das raquetes)
+    StartBreakout,
+    /// Inicia um treino (um jogador contra a parede ou o lançador)
+    StartPractice,
+    /// Inicia uma partida de arcade
+    StartArcade(ArcadeMode),
+    /// Abre o editor de arenas
+    OpenEditor,
+    /// Abre a tela de nome de um perfil novo
+    NewProfile,
+    /// Abre a tela de nome de um jogador do torneio
+    AddTournamentPlayer,
+    /// Retira o último jogador inscrito no torneio
+    RemoveTournamentPlayer,
+    /// Sorteia a chave e começa o torneio
+    StartTournament,
+    /// Começa a próxima partida do torneio
+    PlayTournamentMatch,
+    /// Encerra o torneio em andamento
+    AbandonTournament,
+    /// Registra a desistência de um jogador (índice de 0 a 3)
+    Forfeit(usize),
+    /// Encerra a partida e volta ao menu principal
+    QuitToMenu,
+    /// Voltar foi pedido no primeiro menu da pilha
+    Close,
+    /// Sai do jogo
+    Quit,
+}
+
+/// Menu com pilha de navegação (cada submenu aberto é empilhado)
+pub struct Menu {
+    /// Menus abertos e o item selecionado em cada um
+    stack: Vec<(MenuId, usize)>,
+    /// Número de jogadores da partida (define as opções de desistência)
+    pub players: usize,
+}
+
+impl Menu {
+    /// Cria o menu começando pelo menu principal
+    pub fn new() -> Menu {
+        Menu {
+            stack: vec![(MenuId::Main, 0)],
+            players: 2,
+        }
+    }
+
+    /// Retorna o menu aberto no momento
+    pub fn current(&self) -> MenuId {
+        self.stack.last().map(|(id, _)| *id).unwrap_or(MenuId::Main)
+    }
+
+    /// Retorna o índice do item selecionado
+    pub fn selected(&self) -> usize {
+        self.stack.last().map(|(_, index)| *index).unwrap_or(0)
+    }
+
+    /// Retorna os itens do menu aberto
+    pub fn items(&self) -> Vec<MenuItem> {
+        items_for(self.current(), self.players)
+    }
+
+    /// Volta para o menu principal (fecha todos os submenus)
+    pub fn reset(&mut self) {
+        self.stack = vec![(MenuId::Main, 0)];
+    }
+
+    /// Abre um menu como primeiro da pilha (ex: menu de pausa)
+    pub fn open_root(&mut self, id: MenuId) {
+        self.stack = vec![(id, 0)];
+    }
+
+    /// Abre um submenu
+    pub fn open(&mut self, id: MenuId) {
+        self.stack.push((id, 0));
+    }
+
+    /// Volta ao menu anterior
+    ///
+    /// # Retorna
+    ///
+    /// `false` se já está no primeiro menu da pilha
+    pub fn back(&mut self) -> bool {
+        if self.stack.len() > 1 {
+            self.stack.pop();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Trata uma entrada de navegação
+    ///
+    /// # Retorna
+    ///
+    /// O comando a ser executado pela aplicação, se houver
+    pub fn handle_input(&mut self, input: MenuInput) -> Option<MenuCommand> {
+        let items = self.items();
+
+        match input {
+            MenuInput::Up => {
+                self.move_selection(&items, -1);
+                None
+            }
+            MenuInput::Down => {
+                self.move_selection(&items, 1);
+                None
+            }
+            MenuInput::Left | MenuInput::Right => {
+                let direction = if input == MenuInput::Left { -1 } else { 1 };
+                match items.get(self.selected()).map(|item| item.kind) {
+                    Some(ItemKind::Setting(id)) => Some(MenuCommand::ChangeSetting(id, direction)),
+                    _ => None,
+                }
+            }
+            MenuInput::Select => self.activate(&items),
+            MenuInput::Back => {
+                // No primeiro menu da pilha, a aplicação decide o que fazer
+                if self.back() {
+                    None
+                } else {
+                    Some(MenuCommand::Close)
+                }
+            }
+        }
+    }
+
+    /// Retorna o índice do item na posição Y informada (usado pelo mouse)
+    pub fn item_at(&self, y: i32) -> Option<usize> {
+        let items = self.items();
+        let (first_item_y, item_spacing) = self.current().layout();
+        let offset = y - first_item_y;
+        if offset < 0 {
+            return None;
+        }
+
+        let index = (offset / item_spacing) as usize;
+        items.get(index).filter(|item| item.enabled).map(|_| index)
+    }
+
+    /// Seleciona o item sob o mouse
+    pub fn hover(&mut self, y: i32) {
+        if let Some(index) = self.item_at(y) {
+            self.set_selected(index);
+        }
+    }
+
+    /// Escolhe o item sob o mouse
+    ///
+    /// # Retorna
+    ///
+    /// O comando a ser executado pela aplicação, se houver
+    pub fn click(&mut self, y: i32) -> Option<MenuCommand> {
+        let index = self.item_at(y)?;
+        self.set_selected(index);
+        let items = self.items();
+        self.activate(&items)
+    }
+
+    /// Executa o item selecionado
+    fn activate(&mut self, items: &[MenuItem]) -> Option<MenuCommand> {
+        let item = items.get(self.selected())?;
+        if !item.enabled {
+            return None;
+        }
+
+        match item.kind {
+            ItemKind::Play(difficulty) => Some(MenuCommand::StartMatch(difficulty)),
+            ItemKind::Submenu(id) => {
+                self.open(id);
+                None
+            }
+            ItemKind::Setting(id) => Some(MenuCommand::ChangeSetting(id, 1)),
+            ItemKind::Command(command) => Some(command),
+            ItemKind::Back => {
+                if self.back() {
+                    None
+                } else {
+                    Some(MenuCommand::Close)
+                }
+            }
+            ItemKind::Quit => Some(MenuCommand::Quit),
+        }
+    }
+
+    /// Move a seleção pulando itens desabilitados
+    fn move_selection(&mut self, items: &[MenuItem], step: i32) {
+        if items.iter().all(|item| !item.enabled) {
+            return;
+        }
+
+        let count = items.len() as i32;
+        let mut index = self.selected() as i32;
+        loop {
+            index = (index + step).rem_euclid(count);
+            if items[index as usize].enabled {
+                break;
+            }
+        }
+        self.set_selected(index as usize);
+    }
+
+    /// Altera o item selecionado do menu aberto
+    fn set_selected(&mut self, index: usize) {
+        if let Some((_, selected)) = self.stack.last_mut() {
+            *selected = index;
+        }
+    }
+}
+
+/// Retorna os itens de cada menu
+///
+/// # Argumentos
+///
+/// * `id` - Menu desejado
+/// * `players` - Número de jogadores da partida atual
+fn items_for(id: MenuId, players: usize) -> Vec<MenuItem> {
+    match id {
+        MenuId::Main => vec![
+            MenuItem::new("menu.play_human", ItemKind::Play(None)),
+            MenuItem::new("menu.play_cpu", ItemKind::Submenu(MenuId::PlayCpu)),
+            MenuItem::new("menu.modes", ItemKind::Submenu(MenuId::Modes)),
+            MenuItem::new("menu.load_game", ItemKind::Command(MenuCommand::LoadGame)),
+            MenuItem::new("menu.leaderboards", ItemKind::Submenu(MenuId::Leaderboards)),
+            MenuItem::new("menu.profiles", ItemKind::Submenu(MenuId::Profiles)),
+            // Recursos ainda não implementados aparecem desabilitados
+            MenuItem::disabled("menu.online", ItemKind::Back),
+            MenuItem::new("menu.settings", ItemKind::Submenu(MenuId::Settings)),
+            MenuItem::disabled("menu.replays", ItemKind::Back),
+            MenuItem::new("menu.quit", ItemKind::Quit),
+        ],
+        MenuId::PlayCpu => {
+            let mut items: Vec<MenuItem> = Difficulty::ALL
+                .iter()
+                .map(|difficulty| {
+                    MenuItem::new(difficulty.label_key(), ItemKind::Play(Some(*difficulty)))
+                })
+                .collect();
+            items.push(MenuItem::new("menu.back", ItemKind::Back));
+            items
+        }
+        MenuId::Modes => vec![
+            MenuItem::new(
+                "menu.play_four",
+                ItemKind::Command(MenuCommand::StartFourPlayer),
+            ),
+            MenuItem::new(
+                "menu.play_doubles",
+                ItemKind::Command(MenuCommand::StartDoubles),
+            ),
+            MenuItem::new(
+                "menu.play_breakout",
+                ItemKind::Command(MenuCommand::StartBreakout),
+            ),
+            MenuItem::new("menu.practice", ItemKind::Submenu(MenuId::Practice)),
+            MenuItem::new("menu.arcade", ItemKind::Submenu(MenuId::Arcade)),
+            MenuItem::new("menu.tournament", ItemKind::Submenu(MenuId::Tournament)),
+            MenuItem::new(
+                "menu.arena_editor",
+                ItemKind::Command(MenuCommand::OpenEditor),
+            ),
+            MenuItem::new("menu.back", ItemKind::Back),
+        ],
+        MenuId::Practice => vec![
+            MenuItem::new(
+                "practice.start",
+                ItemKind::Command(MenuCommand::StartPractice),
+            ),
+            MenuItem::new(
+                "practice.target",
+                ItemKind::Setting(SettingId::PracticeTarget),
+            ),
+            MenuItem::new("menu.back", ItemKind::Back),
+        ],
+        MenuId::Arcade => {
+            let mut items: Vec<MenuItem> = ArcadeMode::ALL
+                .iter()
+                .map(|mode| {
+                    MenuItem::new(
+                        mode.label_key(),
+                        ItemKind::Command(MenuCommand::StartArcade(*mode)),
+                    )
+                })
+                .collect();
+            items.push(MenuItem::new("menu.back", ItemKind::Back));
+            items
+        }
+        MenuId::Tournament => vec![
+            MenuItem::new(
+                "tournament.format",
+                ItemKind::Setting(SettingId::TournamentFormat),
+            ),
+            MenuItem::new(
+                "tournament.add_player",
+                ItemKind::Command(MenuCommand::AddTournamentPlayer),
+            ),
+            MenuItem::new(
+                "tournament.remove_player",
+                ItemKind::Command(MenuCommand::RemoveTournamentPlayer),
+            ),
+            MenuItem::new(
+                "tournament.start",
+                ItemKind::Command(MenuCommand::StartTournament),
+            ),
+            MenuItem::new("tournament.bracket", ItemKind::Submenu(MenuId::Bracket)),
+            MenuItem::new("menu.back", ItemKind::Back),
+        ],
+        MenuId::Bracket => vec![
+            MenuItem::new(
+                "tournament.play_next",
+                ItemKind::Command(MenuCommand::PlayTournamentMatch),
+            ),
+            MenuItem::new(
+                "tournament.abandon",
+                ItemKind::Command(MenuCommand::AbandonTournament),
+            ),
+            MenuItem::new("menu.back", ItemKind::Back),
+        ],
+        MenuId::Leaderboards => vec![
+            MenuItem::new(
+                "leaderboard.board",
+                ItemKind::Setting(SettingId::LeaderboardBoard),
+            ),
+            MenuItem::new("menu.back", ItemKind::Back),
+        ],
+        MenuId::Profiles => vec![
+            MenuItem::new("profiles.player1", ItemKind::Setting(SettingId::Profile(0))),
+            MenuItem::new("profiles.player2", ItemKind::Setting(SettingId::Profile(1))),
+            MenuItem::new("profiles.new", ItemKind::Command(MenuCommand::NewProfile)),
+            MenuItem::new("profiles.stats", ItemKind::Submenu(MenuId::ProfileStats)),
+            MenuItem::new("profiles.ladder", ItemKind::Submenu(MenuId::Ladder)),
+            MenuItem::new("menu.back", ItemKind::Back),
+        ],
+        MenuId::ProfileStats => vec![
+            MenuItem::new(
+                "profiles.profile",
+                ItemKind::Setting(SettingId::StatsProfile),
+            ),
+            MenuItem::new("menu.back", ItemKind::Back),
+        ],
+        MenuId::Ladder => vec![MenuItem::new("menu.back", ItemKind::Back)],
+        MenuId::Settings => vec![
+            MenuItem::new("settings.game", ItemKind::Submenu(MenuId::GameSettings)),
+            MenuItem::new(
+                "settings.power_ups",
+                ItemKind::Submenu(MenuId::PowerUpSettings),
+            ),
+            MenuItem::new(
+                "settings.handicaps",
+                ItemKind::Submenu(MenuId::HandicapSettings),
+            ),
+            MenuItem::new("settings.arena", ItemKind::Setting(SettingId::Arena)),
+            MenuItem::new(
+                "settings.audio_video",
+                ItemKind::Submenu(MenuId::AudioVideoSettings),
+            ),
+            MenuItem::new("settings.language", ItemKind::Setting(SettingId::Language)),
+            MenuItem::new("settings.controls", ItemKind::Setting(SettingId::Controls)),
+            MenuItem::new("menu.back", ItemKind::Back),
+        ],
+        MenuId::GameSettings => vec![
+            MenuItem::new("settings.win_rule", ItemKind::Setting(SettingId::WinRule)),
+            MenuItem::new("settings.win_score", ItemKind::Setting(SettingId::WinScore)),
+            MenuItem::new(
+                "settings.match_time",
+                ItemKind::Setting(SettingId::MatchTime),
+            ),
+            MenuItem::new(
+                "settings.best_of_sets",
+                ItemKind::Setting(SettingId::BestOfSets),
+            ),
+            MenuItem::new(
+                "settings.games_per_set",
+                ItemKind::Setting(SettingId::GamesPerSet),
+            ),
+            MenuItem::new("settings.lives", ItemKind::Setting(SettingId::Lives)),
+            MenuItem::new("settings.balls", ItemKind::Setting(SettingId::Balls)),
+            MenuItem::new(
+                "settings.ball_speed",
+                ItemKind::Setting(SettingId::BallSpeed),
+            ),
+            MenuItem::new(
+                "settings.paddle_size",
+                ItemKind::Setting(SettingId::PaddleSize),
+            ),
+            MenuItem::new(
+                "settings.serve_rule",
+                ItemKind::Setting(SettingId::ServeRule),
+            ),
+            MenuItem::new(
+                "settings.press_to_serve",
+                ItemKind::Setting(SettingId::PressToServe),
+            ),
+            MenuItem::new("menu.back", ItemKind::Back),
+        ],
+        MenuId::PowerUpSettings => {
+            let mut items = vec![MenuItem::new(
+                "settings.power_ups_enabled",
+                ItemKind::Setting(SettingId::PowerUps),
+            )];
+            items.extend(PowerUpKind::ALL.iter().map(|kind| {
+                MenuItem::new(
+                    kind.label_key(),
+                    ItemKind::Setting(SettingId::PowerUp(*kind)),
+                )
+            }));
+            items.push(MenuItem::new("menu.back", ItemKind::Back));
+            items
+        }
+        MenuId::HandicapSettings => {
+            let mut items = Vec::new();
+            for player in 0..2 {
+                items.extend(HandicapOption::ALL.iter().map(|option| {
+                    MenuItem::new(
+                        option.label_key(player),
+                        ItemKind::Setting(SettingId::Handicap(player, *option)),
+                    )
+                }));
+            }
+            items.push(MenuItem::new("menu.back", ItemKind::Back));
+            items
+        }
+        MenuId::AudioVideoSettings => vec![
+            MenuItem::new("settings.theme", ItemKind::Setting(SettingId::Theme)),
+            MenuItem::new(
+                "settings.display_mode",
+                ItemKind::Setting(SettingId::DisplayMode),
+            ),
+            MenuItem::new("settings.volume", ItemKind::Setting(SettingId::Volume)),
+            MenuItem::new("menu.back", ItemKind::Back),
+        ],
+        MenuId::Pause => vec![
+            MenuItem::new("pause.resume", ItemKind::Command(MenuCommand::Resume)),
+            MenuItem::new("pause.restart", ItemKind::Command(MenuCommand::Restart)),
+            MenuItem::new("pause.save", ItemKind::Command(MenuCommand::SaveGame)),
+            MenuItem::new("menu.settings", ItemKind::Submenu(MenuId::Settings)),
+            MenuItem::new("pause.forfeit", ItemKind::Submenu(MenuId::Forfeit)),
+            MenuItem::new(
+                "pause.quit_to_menu",
+                ItemKind::Command(MenuCommand::QuitToMenu),
+            ),
+        ],
+        MenuId::Forfeit => {
+            const LABELS: [&str; 4] = [
+                "pause.forfeit_player1",
+                "pause.forfeit_player2",
+                "pause.forfeit_player3",
+                "pause.forfeit_player4",
+            ];
+            let mut items: Vec<MenuItem> = LABELS
+                .iter()
+                .take(players)
+                .enumerate()
+                .map(|(player, label)| {
+                    MenuItem::new(label, ItemKind::Command(MenuCommand::Forfeit(player)))
+                })
+                .collect();
+            items.push(MenuItem::new("menu.back", ItemKind::Back));
+            items
+        }
+    }
+}
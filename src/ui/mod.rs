@@ -0,0 +1,17 @@
+// Interface do jogo: menus, telas e renderização de texto
+
+pub mod editor;
+pub mod menu;
+pub mod name_entry;
+pub mod screens;
+pub mod text;
+
+use sdl2::ttf::Font;
+
+/// Fontes usadas pela interface
+pub struct Fonts<'ttf> {
+    /// Fonte principal (títulos, placar e itens de menu)
+    pub large: Font<'ttf, 'static>,
+    /// Fonte pequena (dicas de controle e informações secundárias)
+    pub small: Font<'ttf, 'static>,
+}
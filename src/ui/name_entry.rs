@@ -0,0 +1,87 @@
+// Tela de digitação de nomes (perfis novos e jogadores de torneio)
+
+use crate::storage::profiles::PROFILE_NAME_MAX;
+use sdl2::keyboard::Keycode;
+
+/// Pedidos da tela de nome que a aplicação executa
+#[derive(PartialEq, Clone, Copy)]
+pub enum NameEntryAction {
+    /// Usa o nome digitado
+    Confirm,
+    /// Volta ao menu sem usar o nome
+    Cancel,
+}
+
+/// O que será feito com o nome digitado
+#[derive(PartialEq, Clone, Copy)]
+pub enum NamePurpose {
+    /// Cria um perfil
+    Profile,
+    /// Inscreve um jogador no torneio
+    TournamentPlayer,
+}
+
+/// Nome sendo digitado
+pub struct NameEntry {
+    /// O que será feito com o nome
+    pub purpose: NamePurpose,
+    /// Texto digitado
+    pub text: String,
+    /// Chave do catálogo de mensagens com o problema do nome (mostrada depois
+    /// de uma confirmação recusada)
+    pub error: Option<&'static str>,
+}
+
+impl NameEntry {
+    /// Abre a tela com o nome vazio
+    ///
+    /// # Argumentos
+    ///
+    /// * `purpose` - O que será feito com o nome
+    pub fn new(purpose: NamePurpose) -> NameEntry {
+        NameEntry {
+            purpose,
+            text: String::new(),
+            error: None,
+        }
+    }
+
+    /// Acrescenta ao nome o texto digitado
+    ///
+    /// Só letras, números, espaços, `-` e `_` são aceitos, até o tamanho
+    /// máximo do nome.
+    ///
+    /// # Argumentos
+    ///
+    /// * `text` - Texto recebido do teclado
+    pub fn insert(&mut self, text: &str) {
+        for character in text.chars() {
+            // O nome não começa com espaço (ex: a tecla que abriu esta tela)
+            let allowed = character.is_alphanumeric()
+                || matches!(character, '-' | '_')
+                || (character == ' ' && !self.text.is_empty());
+            if allowed && self.text.chars().count() < PROFILE_NAME_MAX {
+                self.text.push(character);
+            }
+        }
+        self.error = None;
+    }
+
+    /// Trata as teclas de edição e de confirmação
+    ///
+    /// # Argumentos
+    ///
+    /// * `keycode` - Código da tecla pressionada
+    pub fn handle_keydown(&mut self, keycode: Keycode) -> Option<NameEntryAction> {
+        match keycode {
+            Keycode::Backspace => {
+                self.text.pop();
+                self.error = None;
+                None
+            }
+            Keycode::Return | Keycode::KpEnter => Some(NameEntryAction::Confirm),
+            Keycode::Escape => Some(NameEntryAction::Cancel),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,1663 @@
+// Telas de texto do jogo: menu, placar, pausa e fim de jogo
+
+use super::menu::{ItemKind, MenuId, SettingId};
+use super::name_entry::NamePurpose;
+use super::text::render_text;
+use super::Fonts;
+use crate::app::App;
+use crate::game::ai::Difficulty;
+use crate::game::arcade::ArcadeMode;
+use crate::game::arena::Arena;
+use crate::game::four_player::{FourPlayer, Wall};
+use crate::game::game_state::{GamePhase, GameState, Side};
+use crate::game::handicap::HandicapOption;
+use crate::game::rating;
+use crate::game::rules::WinRule;
+use crate::game::scoring::MatchScore;
+use crate::game::theme::Theme;
+use crate::game::tournament::{
+    Bracket, Pairing, Tournament, TournamentFormat, TOURNAMENT_MAX_PLAYERS,
+};
+use crate::locale::Locale;
+use crate::storage::leaderboards::{Board, Record};
+use crate::storage::profiles::Profile;
+use sdl2::pixels::Color;
+use sdl2::rect::{Point, Rect};
+use sdl2::render::{BlendMode, Canvas};
+use sdl2::video::Window;
+
+/// Retorna o nome de um tema no idioma atual
+///
+/// Temas embutidos têm o nome traduzido no catálogo (`theme.<id>`); temas
+/// carregados de arquivos usam o nome definido no próprio arquivo.
+pub fn theme_name<'a>(locale: &'a Locale, theme: &'a Theme) -> &'a str {
+    locale
+        .lookup(&format!("theme.{}", theme.id))
+        .unwrap_or(&theme.name)
+}
+
+/// Retorna o nome de uma arena no idioma atual
+///
+/// Arenas embutidas têm o nome traduzido no catálogo (`arena.<id>`); as demais
+/// usam o próprio nome.
+pub fn arena_name<'a>(locale: &'a Locale, arena: &'a Arena) -> &'a str {
+    locale
+        .lookup(&format!("arena.{}", arena.id))
+        .unwrap_or(&arena.name)
+}
+
+/// Retorna a explicação da regra de vitória ativa (ex: "PRIMEIRO A 5 PONTOS VENCE")
+pub fn win_rule_text(locale: &Locale, game_state: &GameState) -> String {
+    let key = game_state.win_rule.help_key();
+    if game_state.win_rule.uses_target() {
+        locale.format(key, &[("score", &game_state.max_score)])
+    } else {
+        let minutes = (game_state.match_time / 60.0).round() as u32;
+        locale.format(key, &[("minutes", &minutes)])
+    }
+}
+
+/// Retorna o formato da partida em sets e games (ex: "MELHOR DE 3 SETS DE 6 GAMES")
+pub fn match_format_text(locale: &Locale, match_score: &MatchScore) -> String {
+    let games = match_score.games_per_set;
+    if match_score.best_of_sets > 1 {
+        let sets = match_score.best_of_sets;
+        locale.format("match_format.sets", &[("sets", &sets), ("games", &games)])
+    } else {
+        locale.format("match_format.single_set", &[("games", &games)])
+    }
+}
+
+/// Linhas mostradas na ladder (perfis e CPUs)
+const LADDER_ROWS: usize = 13;
+
+/// Linhas de cada coluna da lista de jogadores inscritos no torneio
+const TOURNAMENT_PLAYER_ROWS: usize = 8;
+
+/// Linhas de cada coluna das partidas da rodada na chave do torneio
+const BRACKET_ROWS: usize = 9;
+
+/// Formata um tempo em segundos como "m:ss"
+fn format_time(seconds: f32) -> String {
+    let seconds = seconds.ceil() as u32;
+    format!("{}:{:02}", seconds / 60, seconds % 60)
+}
+
+/// Retorna os detalhes de um resultado de arcade (tempo sobrevivido ou pontos
+/// a favor e contra)
+fn arcade_detail(locale: &Locale, mode: ArcadeMode, result: &Record) -> String {
+    match mode {
+        ArcadeMode::Survival => locale.format(
+            "arcade.survival_detail",
+            &[
+                ("time", &format_time(result.time.floor())),
+                ("returns", &result.returns),
+            ],
+        ),
+        ArcadeMode::TimeAttack => locale.format(
+            "arcade.time_attack_detail",
+            &[("won", &result.points_won), ("lost", &result.points_lost)],
+        ),
+    }
+}
+
+/// Retorna uma linha de um ranking
+///
+/// # Argumentos
+///
+/// * `locale` - Idioma da interface
+/// * `board` - Ranking
+/// * `rank` - Posição no ranking (0 é o primeiro lugar)
+/// * `record` - Resultado
+fn leaderboard_entry(locale: &Locale, board: Board, rank: usize, record: &Record) -> String {
+    // Na sobrevivência conta o tempo completo em jogo; nos demais, o tempo
+    // arredondado para cima (igual ao valor da vitória mais rápida)
+    let time = match board {
+        Board::Survival => record.time.floor(),
+        _ => record.time,
+    };
+    locale.format(
+        board.entry_key(),
+        &[
+            ("rank", &(rank + 1)),
+            ("value", &record.value),
+            ("time", &format_time(time)),
+            ("returns", &record.returns),
+            ("won", &record.points_won),
+            ("lost", &record.points_lost),
+        ],
+    )
+}
+
+/// Renderiza as linhas de um ranking (ou um aviso, se ele estiver vazio)
+///
+/// # Argumentos
+///
+/// * `canvas` - Canvas SDL para desenhar
+/// * `fonts` - Fontes para renderizar texto
+/// * `app` - Estado da aplicação
+/// * `board` - Ranking
+/// * `highlight` - Posição destacada (resultado da última partida)
+/// * `top_y` - Posição Y da primeira linha
+fn render_leaderboard(
+    canvas: &mut Canvas<Window>,
+    fonts: &Fonts,
+    app: &App,
+    board: Board,
+    highlight: Option<usize>,
+    top_y: i32,
+) -> Result<(), String> {
+    let locale = &app.locale;
+    let theme = &app.game_state.theme;
+    let texture_creator = canvas.texture_creator();
+    let center_x = app.game_state.screen_width as i32 / 2;
+
+    let entries = app.leaderboards.entries(board);
+    if entries.is_empty() {
+        return render_text(
+            canvas,
+            &texture_creator,
+            &fonts.small,
+            locale.get("leaderboard.empty"),
+            center_x,
+            top_y,
+            theme.text_dim,
+        );
+    }
+
+    for (index, entry) in entries.iter().enumerate() {
+        let color = if highlight == Some(index) {
+            theme.player_left
+        } else {
+            theme.text
+        };
+        render_text(
+            canvas,
+            &texture_creator,
+            &fonts.small,
+            &leaderboard_entry(locale, board, index, entry),
+            center_x,
+            top_y + index as i32 * 28,
+            color,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Renderiza o menu aberto
+///
+/// Na fase de menu a tela é limpa; quando o menu é aberto durante a partida
+/// (ex: configurações na pausa), o jogo continua visível por trás.
+///
+/// # Argumentos
+///
+/// * `canvas` - Canvas SDL para desenhar
+/// * `fonts` - Fontes para renderizar texto
+/// * `app` - Estado da aplicação
+pub fn render_menu(canvas: &mut Canvas<Window>, fonts: &Fonts, app: &App) -> Result<(), String> {
+    let game_state = &app.game_state;
+    let locale = &app.locale;
+    let theme = &game_state.theme;
+    let center_x = game_state.screen_width as i32 / 2;
+
+    if game_state.phase == GamePhase::Menu {
+        // Limpa a tela
+        canvas.set_draw_color(theme.background);
+        canvas.clear();
+    } else {
+        draw_overlay(canvas, game_state);
+    }
+
+    let texture_creator = canvas.texture_creator();
+
+    // Título do menu aberto
+    let menu_id = app.menu.current();
+    render_text(
+        canvas,
+        &texture_creator,
+        &fonts.large,
+        locale.get(menu_id.title_key()),
+        center_x,
+        60,
+        theme.player_left,
+    )?;
+
+    // Regra de vitória (somente no menu principal)
+    if menu_id == MenuId::Main {
+        render_text(
+            canvas,
+            &texture_creator,
+            &fonts.small,
+            &win_rule_text(locale, game_state),
+            center_x,
+            120,
+            theme.highlight,
+        )?;
+
+        if game_state.match_score.has_games() {
+            render_text(
+                canvas,
+                &texture_creator,
+                &fonts.small,
+                &match_format_text(locale, &game_state.match_score),
+                center_x,
+                145,
+                theme.highlight,
+            )?;
+        }
+    }
+
+    // Ranking escolhido na tela de recordes
+    if menu_id == MenuId::Leaderboards {
+        render_text(
+            canvas,
+            &texture_creator,
+            &fonts.small,
+            locale.get(app.leaderboard_board.label_key()),
+            center_x,
+            130,
+            theme.highlight,
+        )?;
+        render_leaderboard(canvas, fonts, app, app.leaderboard_board, None, 170)?;
+    }
+
+    // Estatísticas do perfil escolhido e ladder de rating
+    match menu_id {
+        MenuId::ProfileStats => render_profile_stats(canvas, fonts, app)?,
+        MenuId::Ladder => render_ladder(canvas, fonts, app)?,
+        MenuId::Tournament => render_tournament_players(canvas, fonts, app)?,
+        MenuId::Bracket => render_bracket(canvas, fonts, app)?,
+        _ => {}
+    }
+
+    // Itens do menu
+    let suggested = app
+        .profiles
+        .find(&app.settings.profiles[0])
+        .map(|profile| rating::suggested_difficulty(profile.rating));
+    let selected = app.menu.selected();
+    let (first_item_y, item_spacing) = menu_id.layout();
+    for (index, item) in app.menu.items().iter().enumerate() {
+        let mut label = locale.get(item.label).to_string();
+
+        // Configurações mostram o valor atual ao lado do nome
+        if let ItemKind::Setting(setting) = item.kind {
+            let value = setting_value(app, setting);
+            label = format!("{}: < {} >", label, value.to_uppercase());
+        }
+
+        if !item.enabled {
+            label = format!("{} ({})", label, locale.get("menu.coming_soon"));
+        }
+
+        // Dificuldade com o rating mais próximo do perfil do jogador 1
+        if item.kind == ItemKind::Play(suggested) && suggested.is_some() {
+            label = format!("{} ({})", label, locale.get("profiles.suggested"));
+        }
+
+        let (text, color) = if !item.enabled {
+            (label, theme.text_dim)
+        } else if index == selected {
+            (format!("> {} <", label), theme.accent)
+        } else {
+            (label, theme.text)
+        };
+
+        render_text(
+            canvas,
+            &texture_creator,
+            &fonts.large,
+            &text,
+            center_x,
+            first_item_y + index as i32 * item_spacing,
+            color,
+        )?;
+    }
+
+    // Resultado do jogo salvo (no lugar da ajuda dos controles) ou controles
+    // dos jogadores (somente no menu principal)
+    if let Some(status) = app
+        .save_status
+        .as_ref()
+        .filter(|_| matches!(menu_id, MenuId::Main | MenuId::Pause))
+    {
+        render_text(
+            canvas,
+            &texture_creator,
+            &fonts.small,
+            status,
+            center_x,
+            500,
+            theme.highlight,
+        )?;
+    } else if menu_id == MenuId::Main {
+        render_text(
+            canvas,
+            &texture_creator,
+            &fonts.small,
+            locale.get(app.settings.controls.help_key()),
+            center_x,
+            500,
+            theme.text,
+        )?;
+    }
+
+    // Dicas de navegação
+    render_text(
+        canvas,
+        &texture_creator,
+        &fonts.small,
+        locale.get("menu.hint"),
+        center_x,
+        545,
+        theme.text_dim,
+    )?;
+
+    Ok(())
+}
+
+/// Renderiza as estatísticas e o histórico de rating do perfil escolhido
+///
+/// # Argumentos
+///
+/// * `canvas` - Canvas SDL para desenhar
+/// * `fonts` - Fontes para renderizar texto
+/// * `app` - Estado da aplicação
+fn render_profile_stats(
+    canvas: &mut Canvas<Window>,
+    fonts: &Fonts,
+    app: &App,
+) -> Result<(), String> {
+    let locale = &app.locale;
+    let theme = &app.game_state.theme;
+    let texture_creator = canvas.texture_creator();
+    let center_x = app.game_state.screen_width as i32 / 2;
+
+    let Some(profile) = app.profiles.list().get(app.stats_profile) else {
+        return render_text(
+            canvas,
+            &texture_creator,
+            &fonts.small,
+            locale.get("profiles.none"),
+            center_x,
+            140,
+            theme.text_dim,
+        );
+    };
+
+    // Aproveitamento: porcentagem de vitórias nas partidas jogadas
+    let win_rate = (profile.wins * 100)
+        .checked_div(profile.matches())
+        .unwrap_or(0);
+    let rating = profile.rating.round() as i32;
+    let lines = [
+        match profile.last_rating_change() {
+            Some(change) => locale.format(
+                "profiles.rating_change",
+                &[
+                    ("rating", &rating),
+                    ("change", &format!("{:+}", change.round() as i32)),
+                ],
+            ),
+            None => locale.format("profiles.rating", &[("rating", &rating)]),
+        },
+        locale.format(
+            "profiles.record",
+            &[
+                ("matches", &profile.matches()),
+                ("wins", &profile.wins),
+                ("losses", &profile.losses),
+            ],
+        ),
+        locale.format("profiles.win_rate", &[("rate", &win_rate)]),
+        locale.format(
+            "profiles.points",
+            &[
+                ("for", &profile.points_for),
+                ("against", &profile.points_against),
+            ],
+        ),
+        locale.format(
+            "profiles.longest_rally",
+            &[("rally", &profile.longest_rally)],
+        ),
+        locale.format(
+            "profiles.fastest_return",
+            &[("speed", &(profile.fastest_return.round() as u32))],
+        ),
+        locale.format(
+            "profiles.playtime",
+            &[("time", &format_time(profile.playtime.floor()))],
+        ),
+    ];
+
+    for (index, line) in lines.iter().enumerate() {
+        let color = if index == 0 {
+            theme.highlight
+        } else {
+            theme.text
+        };
+        render_text(
+            canvas,
+            &texture_creator,
+            &fonts.small,
+            line,
+            center_x,
+            125 + index as i32 * 30,
+            color,
+        )?;
+    }
+
+    // Gráfico do rating nas últimas partidas
+    if profile.history.len() < 2 {
+        return Ok(());
+    }
+    let area = Rect::new(center_x - 200, 340, 400, 55);
+    let min = profile
+        .history
+        .iter()
+        .copied()
+        .fold(f32::INFINITY, f32::min);
+    let max = profile
+        .history
+        .iter()
+        .copied()
+        .fold(f32::NEG_INFINITY, f32::max);
+    let range = (max - min).max(1.0);
+    let step = area.width() as f32 / (profile.history.len() - 1) as f32;
+    let points: Vec<Point> = profile
+        .history
+        .iter()
+        .enumerate()
+        .map(|(index, rating)| {
+            let x = area.x() as f32 + index as f32 * step;
+            let y = area.bottom() as f32 - (rating - min) / range * area.height() as f32;
+            Point::new(x as i32, y as i32)
+        })
+        .collect();
+
+    canvas.set_draw_color(theme.text_dim);
+    canvas.draw_rect(area)?;
+    canvas.set_draw_color(theme.accent);
+    canvas.draw_lines(points.as_slice())
+}
+
+/// Renderiza a ladder: os perfis do maior para o menor rating, com as CPUs
+/// (de rating fixo) como referência
+///
+/// # Argumentos
+///
+/// * `canvas` - Canvas SDL para desenhar
+/// * `fonts` - Fontes para renderizar texto
+/// * `app` - Estado da aplicação
+fn render_ladder(canvas: &mut Canvas<Window>, fonts: &Fonts, app: &App) -> Result<(), String> {
+    let locale = &app.locale;
+    let theme = &app.game_state.theme;
+    let texture_creator = canvas.texture_creator();
+    let center_x = app.game_state.screen_width as i32 / 2;
+
+    // Linhas da ladder: (rating, perfil ou dificuldade da CPU)
+    let mut rows: Vec<(f32, Result<&Profile, Difficulty>)> = app
+        .profiles
+        .list()
+        .iter()
+        .map(|profile| (profile.rating, Ok(profile)))
+        .chain(
+            Difficulty::ALL
+                .into_iter()
+                .map(|difficulty| (difficulty.rating(), Err(difficulty))),
+        )
+        .collect();
+    rows.sort_by(|a, b| b.0.total_cmp(&a.0));
+
+    let mut rank = 0;
+    for (index, (rating, row)) in rows.iter().take(LADDER_ROWS).enumerate() {
+        let rating = rating.round() as i32;
+        let (line, color) = match row {
+            Ok(profile) => {
+                rank += 1;
+                let line = locale.format(
+                    "profiles.ladder_entry",
+                    &[
+                        ("rank", &rank),
+                        ("name", &profile.name),
+                        ("rating", &rating),
+                        ("wins", &profile.wins),
+                        ("losses", &profile.losses),
+                    ],
+                );
+                (line, theme.text)
+            }
+            Err(difficulty) => {
+                let line = locale.format(
+                    "profiles.ladder_cpu",
+                    &[
+                        ("difficulty", &locale.get(difficulty.label_key())),
+                        ("rating", &rating),
+                    ],
+                );
+                (line, theme.text_dim)
+            }
+        };
+        render_text(
+            canvas,
+            &texture_creator,
+            &fonts.small,
+            &line,
+            center_x,
+            115 + index as i32 * 27,
+            color,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Renderiza os jogadores inscritos no próximo torneio, em colunas
+///
+/// # Argumentos
+///
+/// * `canvas` - Canvas SDL para desenhar
+/// * `fonts` - Fontes para renderizar texto
+/// * `app` - Estado da aplicação
+fn render_tournament_players(
+    canvas: &mut Canvas<Window>,
+    fonts: &Fonts,
+    app: &App,
+) -> Result<(), String> {
+    let locale = &app.locale;
+    let theme = &app.game_state.theme;
+    let texture_creator = canvas.texture_creator();
+    let center_x = app.game_state.screen_width as i32 / 2;
+    let players = &app.tournament_players;
+
+    // O motivo de o torneio não ter começado ocupa o lugar da contagem
+    let (header, color) = match app.tournament_error {
+        Some(error) => (locale.get(error).to_string(), theme.highlight),
+        None => (
+            locale.format(
+                "tournament.players",
+                &[("count", &players.len()), ("max", &TOURNAMENT_MAX_PLAYERS)],
+            ),
+            theme.text,
+        ),
+    };
+    render_text(
+        canvas,
+        &texture_creator,
+        &fonts.small,
+        &header,
+        center_x,
+        100,
+        color,
+    )?;
+
+    if players.is_empty() {
+        return render_text(
+            canvas,
+            &texture_creator,
+            &fonts.small,
+            locale.get("tournament.no_players"),
+            center_x,
+            170,
+            theme.text_dim,
+        );
+    }
+
+    for (index, name) in players.iter().enumerate() {
+        let column = (index / TOURNAMENT_PLAYER_ROWS) as i32;
+        let row = (index % TOURNAMENT_PLAYER_ROWS) as i32;
+        render_text(
+            canvas,
+            &texture_creator,
+            &fonts.small,
+            &format!("{}. {}", index + 1, name),
+            center_x - 270 + column * 180,
+            130 + row * 20,
+            theme.text_dim,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Retorna a linha de uma partida da chave (jogadores e placar ou vencedor)
+///
+/// # Argumentos
+///
+/// * `locale` - Idioma dos textos
+/// * `tournament` - Torneio em andamento
+/// * `pairing` - Partida da rodada
+fn pairing_line(locale: &Locale, tournament: &Tournament, pairing: &Pairing) -> String {
+    let name = |index: usize| tournament.entrants[index].name.as_str();
+    let left = name(pairing.left);
+
+    let line = match (pairing.right, pairing.winner, pairing.score) {
+        (None, _, _) => locale.format("tournament.bye", &[("name", &left)]),
+        (Some(right), _, Some((left_points, right_points))) => locale.format(
+            "tournament.result",
+            &[
+                ("left", &left),
+                ("left_points", &left_points),
+                ("right_points", &right_points),
+                ("right", &name(right)),
+            ],
+        ),
+        (Some(right), _, None) => locale.format(
+            "tournament.pairing",
+            &[("left", &left), ("right", &name(right))],
+        ),
+    };
+
+    // Na eliminatória dupla, a chave da partida vem na frente
+    if tournament.format != TournamentFormat::DoubleElimination {
+        return line;
+    }
+    let tag = match pairing.bracket {
+        Bracket::Winners => "tournament.tag_winners",
+        Bracket::Losers => "tournament.tag_losers",
+        Bracket::Final => "tournament.tag_final",
+    };
+    format!("{} {}", locale.get(tag), line)
+}
+
+/// Renderiza a chave do torneio em andamento: partidas da rodada, próxima
+/// partida, classificação e campeão
+///
+/// # Argumentos
+///
+/// * `canvas` - Canvas SDL para desenhar
+/// * `fonts` - Fontes para renderizar texto
+/// * `app` - Estado da aplicação
+fn render_bracket(canvas: &mut Canvas<Window>, fonts: &Fonts, app: &App) -> Result<(), String> {
+    let locale = &app.locale;
+    let theme = &app.game_state.theme;
+    let texture_creator = canvas.texture_creator();
+    let center_x = app.game_state.screen_width as i32 / 2;
+
+    let Some(tournament) = &app.tournament else {
+        return render_text(
+            canvas,
+            &texture_creator,
+            &fonts.small,
+            locale.get("tournament.none"),
+            center_x,
+            200,
+            theme.text_dim,
+        );
+    };
+
+    // Formato e rodada
+    let round = tournament.round + 1;
+    let round = match tournament.format {
+        TournamentFormat::RoundRobin => locale.format(
+            "tournament.round_of",
+            &[("round", &round), ("rounds", &tournament.rounds.len())],
+        ),
+        _ => locale.format("tournament.round", &[("round", &round)]),
+    };
+    let format = locale.get(tournament.format.label_key());
+    render_text(
+        canvas,
+        &texture_creator,
+        &fonts.small,
+        &locale.format(
+            "tournament.header",
+            &[("format", &format), ("round", &round)],
+        ),
+        center_x,
+        100,
+        theme.highlight,
+    )?;
+
+    // Partidas da rodada em duas colunas (a próxima em destaque)
+    let next = tournament.next_pairing();
+    for (index, pairing) in tournament.current_round().iter().enumerate() {
+        let column = (index / BRACKET_ROWS) as i32;
+        let row = (index % BRACKET_ROWS) as i32;
+        let color = if Some(index) == next {
+            theme.accent
+        } else if pairing.winner.is_some() {
+            theme.text_dim
+        } else {
+            theme.text
+        };
+        render_text(
+            canvas,
+            &texture_creator,
+            &fonts.small,
+            &pairing_line(locale, tournament, pairing),
+            center_x - 195 + column * 390,
+            130 + row * 24,
+            color,
+        )?;
+    }
+
+    // Campeão ou próxima partida
+    let (status, color) = match tournament.champion {
+        Some(champion) => (
+            locale.format(
+                "tournament.champion",
+                &[("name", &tournament.entrants[champion].name)],
+            ),
+            theme.highlight,
+        ),
+        None => (
+            locale.format(
+                "tournament.next",
+                &[(
+                    "match",
+                    &next
+                        .map(|index| {
+                            pairing_line(locale, tournament, &tournament.current_round()[index])
+                        })
+                        .unwrap_or_default(),
+                )],
+            ),
+            theme.accent,
+        ),
+    };
+    render_text(
+        canvas,
+        &texture_creator,
+        &fonts.large,
+        &status,
+        center_x,
+        340,
+        color,
+    )?;
+
+    // Jogadores restantes (eliminatórias) ou os primeiros da classificação
+    let detail = match tournament.format {
+        TournamentFormat::RoundRobin => {
+            let leaders: Vec<String> = tournament
+                .standings()
+                .iter()
+                .take(3)
+                .enumerate()
+                .map(|(rank, index)| {
+                    let entrant = &tournament.entrants[*index];
+                    locale.format(
+                        "tournament.standing",
+                        &[
+                            ("rank", &(rank + 1)),
+                            ("name", &entrant.name),
+                            ("wins", &entrant.wins),
+                            ("losses", &entrant.losses),
+                        ],
+                    )
+                })
+                .collect();
+            leaders.join("    ")
+        }
+        _ => locale.format(
+            "tournament.remaining",
+            &[("count", &tournament.remaining())],
+        ),
+    };
+    render_text(
+        canvas,
+        &texture_creator,
+        &fonts.small,
+        &detail,
+        center_x,
+        385,
+        theme.text,
+    )
+}
+
+/// Retorna o valor atual de uma configuração formatado para o menu
+fn setting_value(app: &App, setting: SettingId) -> String {
+    let settings = &app.settings;
+    let locale = &app.locale;
+
+    match setting {
+        SettingId::WinRule => locale.get(settings.win_rule.label_key()).to_string(),
+        SettingId::WinScore => settings.win_score.to_string(),
+        SettingId::BestOfSets => settings.best_of_sets.to_string(),
+        SettingId::GamesPerSet => settings.games_per_set.to_string(),
+        SettingId::Lives => settings.lives.to_string(),
+        SettingId::Balls => settings.balls.to_string(),
+        SettingId::MatchTime => {
+            locale.format("settings.minutes", &[("minutes", &settings.match_minutes)])
+        }
+        SettingId::BallSpeed => settings.ball_speed.to_string(),
+        SettingId::PaddleSize => settings.paddle_height.to_string(),
+        SettingId::ServeRule => locale.get(settings.serve_rule.label_key()).to_string(),
+        SettingId::PressToServe => on_off(locale, settings.press_to_serve),
+        SettingId::PowerUps => on_off(locale, settings.power_ups),
+        SettingId::PowerUp(kind) => on_off(locale, settings.power_up_kinds.contains(&kind)),
+        SettingId::Volume => format!("{}%", settings.volume),
+        SettingId::Handicap(player, option) => {
+            let handicap = &settings.handicaps[player];
+            match option {
+                HandicapOption::PaddleSize => format!("{}%", handicap.paddle_size),
+                HandicapOption::PaddleSpeed => format!("{}%", handicap.paddle_speed),
+                HandicapOption::StartPoints => handicap.start_points.to_string(),
+                HandicapOption::AiAssist => on_off(locale, handicap.ai_assist),
+            }
+        }
+        SettingId::PracticeTarget => locale.get(settings.practice_target.label_key()).to_string(),
+        SettingId::Arena => {
+            // A arena escolhida (a partida de um jogo salvo mantém a sua)
+            let arena = app
+                .arenas
+                .iter()
+                .find(|arena| arena.id == settings.arena)
+                .unwrap_or(&app.game_state.arena);
+            arena_name(locale, arena).to_string()
+        }
+        SettingId::Theme => theme_name(locale, &app.game_state.theme).to_string(),
+        SettingId::Language => locale.language().name.clone(),
+        SettingId::Controls => locale.get(settings.controls.label_key()).to_string(),
+        SettingId::DisplayMode => locale.get(settings.display_mode.label_key()).to_string(),
+        SettingId::LeaderboardBoard => locale.get(app.leaderboard_board.label_key()).to_string(),
+        SettingId::Profile(player) => match settings.profiles[player].as_str() {
+            "" => locale.get("profiles.guest").to_string(),
+            name => name.to_string(),
+        },
+        SettingId::TournamentFormat => locale.get(app.tournament_format.label_key()).to_string(),
+        SettingId::StatsProfile => match app.profiles.list().get(app.stats_profile) {
+            Some(profile) => profile.name.clone(),
+            None => String::from("-"),
+        },
+    }
+}
+
+/// Retorna "ligado" ou "desligado" no idioma atual
+fn on_off(locale: &Locale, value: bool) -> String {
+    let key = if value { "settings.on" } else { "settings.off" };
+    locale.get(key).to_string()
+}
+
+/// Escurece a tela do jogo para desenhar um menu por cima
+fn draw_overlay(canvas: &mut Canvas<Window>, game_state: &GameState) {
+    let background = game_state.theme.background;
+    canvas.set_blend_mode(BlendMode::Blend);
+    canvas.set_draw_color(Color::RGBA(background.r, background.g, background.b, 220));
+    canvas
+        .fill_rect(Rect::new(
+            0,
+            0,
+            game_state.screen_width as u32,
+            game_state.screen_height as u32,
+        ))
+        .unwrap();
+    canvas.set_blend_mode(BlendMode::None);
+}
+
+/// Renderiza a pontuação, o relógio da partida com tempo e situações
+/// especiais (iguais, vantagem, ponto de ouro, morte súbita)
+///
+/// # Argumentos
+///
+/// * `canvas` - Canvas SDL para desenhar
+/// * `fonts` - Fontes para renderizar texto
+/// * `game_state` - Estado atual do jogo
+/// * `locale` - Idioma dos textos
+pub fn render_score(
+    canvas: &mut Canvas<Window>,
+    fonts: &Fonts,
+    game_state: &GameState,
+    locale: &Locale,
+) -> Result<(), String> {
+    let texture_creator = canvas.texture_creator();
+
+    // Símbolo de cada power-up na quadra (centralizado no quadrado)
+    for item in &game_state.power_ups.items {
+        render_text(
+            canvas,
+            &texture_creator,
+            &fonts.small,
+            item.kind.symbol(),
+            item.x as i32,
+            item.y as i32 - fonts.small.height() / 2,
+            game_state.theme.background,
+        )?;
+    }
+
+    if let Some(four_player) = &game_state.four_player {
+        return render_lives(canvas, fonts, game_state, four_player, locale);
+    }
+
+    // No treino, o placar dá lugar à sequência de rebatidas
+    if let Some(practice) = &game_state.practice {
+        render_text(
+            canvas,
+            &texture_creator,
+            &fonts.large,
+            &practice.streak.to_string(),
+            game_state.screen_width as i32 / 4,
+            30,
+            game_state.theme.player_left,
+        )?;
+        render_text(
+            canvas,
+            &texture_creator,
+            &fonts.small,
+            &locale.format(
+                "hud.practice",
+                &[
+                    ("streak", &practice.streak),
+                    ("best", &practice.best_streak),
+                ],
+            ),
+            game_state.screen_width as i32 / 2,
+            10,
+            game_state.theme.text,
+        )?;
+        return Ok(());
+    }
+
+    // No breakout, o placar mostra os tijolos de pé de cada jogador
+    if let Some(breakout) = &game_state.breakout {
+        let screen_width = game_state.screen_width as i32;
+        for (side, x) in [
+            (Side::Left, screen_width / 4),
+            (Side::Right, 3 * screen_width / 4),
+        ] {
+            render_text(
+                canvas,
+                &texture_creator,
+                &fonts.large,
+                &breakout.remaining(side).to_string(),
+                x,
+                30,
+                game_state.theme.player_color(side.player()),
+            )?;
+        }
+        render_text(
+            canvas,
+            &texture_creator,
+            &fonts.small,
+            locale.get("hud.bricks"),
+            screen_width / 2,
+            10,
+            game_state.theme.text,
+        )?;
+        return Ok(());
+    }
+
+    // No arcade, o placar mostra a pontuação do modo e o relógio
+    if let Some(arcade) = &game_state.arcade {
+        render_text(
+            canvas,
+            &texture_creator,
+            &fonts.large,
+            &arcade.score().to_string(),
+            game_state.screen_width as i32 / 4,
+            30,
+            game_state.theme.player_left,
+        )?;
+        let line = match arcade.mode {
+            ArcadeMode::Survival => locale.format(
+                "hud.survival",
+                &[
+                    ("time", &format_time(arcade.elapsed.floor())),
+                    ("balls", &game_state.balls.len()),
+                ],
+            ),
+            ArcadeMode::TimeAttack => locale.format(
+                "hud.time_attack",
+                &[
+                    ("time", &format_time(arcade.time_left())),
+                    ("won", &arcade.points_won),
+                    ("lost", &arcade.points_lost),
+                ],
+            ),
+        };
+        render_text(
+            canvas,
+            &texture_creator,
+            &fonts.small,
+            &line,
+            game_state.screen_width as i32 / 2,
+            10,
+            game_state.theme.text,
+        )?;
+        return Ok(());
+    }
+
+    let screen_width = game_state.screen_width as i32;
+
+    // Pontuação do jogador 1 (esquerda)
+    let score_left_text = format!("{}", game_state.score_left);
+    render_text(
+        canvas,
+        &texture_creator,
+        &fonts.large,
+        &score_left_text,
+        screen_width / 4,
+        30,
+        game_state.theme.player_left,
+    )?;
+
+    // Pontuação do jogador 2 (direita)
+    let score_right_text = format!("{}", game_state.score_right);
+    render_text(
+        canvas,
+        &texture_creator,
+        &fonts.large,
+        &score_right_text,
+        3 * screen_width / 4,
+        30,
+        game_state.theme.player_right,
+    )?;
+
+    // Placar de sets e games
+    let match_score = &game_state.match_score;
+    if match_score.has_games() {
+        let sets = format!("{}-{}", match_score.sets_left, match_score.sets_right);
+        let games = format!("{}-{}", match_score.games_left, match_score.games_right);
+        render_text(
+            canvas,
+            &texture_creator,
+            &fonts.small,
+            &locale.format("hud.sets_games", &[("sets", &sets), ("games", &games)]),
+            screen_width / 2,
+            10,
+            game_state.theme.text,
+        )?;
+    }
+
+    // Relógio da partida com tempo
+    if game_state.win_rule == WinRule::Timed {
+        render_text(
+            canvas,
+            &texture_creator,
+            &fonts.small,
+            &format_time(game_state.time_left),
+            screen_width / 2,
+            40,
+            game_state.theme.text,
+        )?;
+    }
+
+    if let Some(status) = game_state.status() {
+        render_text(
+            canvas,
+            &texture_creator,
+            &fonts.small,
+            locale.get(status.label_key()),
+            screen_width / 2,
+            70,
+            game_state.theme.highlight,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Renderiza a contagem regressiva antes de a partida continuar
+///
+/// # Argumentos
+///
+/// * `canvas` - Canvas SDL para desenhar
+/// * `fonts` - Fontes para renderizar texto
+/// * `game_state` - Estado atual do jogo
+pub fn render_countdown(
+    canvas: &mut Canvas<Window>,
+    fonts: &Fonts,
+    game_state: &GameState,
+) -> Result<(), String> {
+    let texture_creator = canvas.texture_creator();
+
+    // Mostra 3, 2, 1 (arredondando para cima o tempo restante)
+    let seconds = game_state.countdown.ceil().max(1.0) as u32;
+    render_text(
+        canvas,
+        &texture_creator,
+        &fonts.large,
+        &seconds.to_string(),
+        game_state.screen_width as i32 / 2,
+        game_state.screen_height as i32 / 2 - 80,
+        game_state.theme.highlight,
+    )?;
+
+    Ok(())
+}
+
+/// Renderiza as informações do saque: quem saca e a contagem regressiva ou a
+/// tecla que lança a bola
+///
+/// # Argumentos
+///
+/// * `canvas` - Canvas SDL para desenhar
+/// * `fonts` - Fontes para renderizar texto
+/// * `app` - Estado da aplicação
+pub fn render_serve(canvas: &mut Canvas<Window>, fonts: &Fonts, app: &App) -> Result<(), String> {
+    let game_state = &app.game_state;
+    let locale = &app.locale;
+    let server = game_state.server;
+    let texture_creator = canvas.texture_creator();
+
+    // No modo de 4 jogadores a bola sai do centro para um jogador sorteado
+    if game_state.four_player.is_some() {
+        render_countdown(canvas, fonts, game_state)?;
+        render_text(
+            canvas,
+            &texture_creator,
+            &fonts.small,
+            locale.get("controls.four_player_help"),
+            game_state.screen_width as i32 / 2,
+            game_state.screen_height as i32 / 2 - 40,
+            game_state.theme.text_dim,
+        )?;
+        return Ok(());
+    }
+
+    // O lançador do treino atira sem aviso
+    if game_state.practice.is_some() && server == Side::Right {
+        return Ok(());
+    }
+
+    let (player_key, color) = match server {
+        Side::Left => ("player.1", game_state.theme.player_left),
+        Side::Right if game_state.cpu.is_some() => ("player.cpu", game_state.theme.player_right),
+        Side::Right => ("player.2", game_state.theme.player_right),
+    };
+    // No torneio, os jogadores são chamados pelo nome
+    let player = match &app.tournament_match {
+        Some(players) => [players.left.as_str(), players.right.as_str()][server.player()],
+        None => locale.get(player_key),
+    };
+
+    let text = if game_state.waiting_for_serve() {
+        let key = locale.get(app.serve_key_label(server));
+        locale.format("serve.press", &[("player", &player), ("key", &key)])
+    } else {
+        if !game_state.press_to_serve {
+            render_countdown(canvas, fonts, game_state)?;
+        }
+        locale.format("serve.server", &[("player", &player)])
+    };
+
+    render_text(
+        canvas,
+        &texture_creator,
+        &fonts.small,
+        &text,
+        game_state.screen_width as i32 / 2,
+        game_state.screen_height as i32 / 2 - 40,
+        color,
+    )?;
+
+    // Nas duplas, lembra as teclas das raquetes da frente
+    if game_state.is_doubles() {
+        render_text(
+            canvas,
+            &texture_creator,
+            &fonts.small,
+            locale.get("controls.doubles_help"),
+            game_state.screen_width as i32 / 2,
+            game_state.screen_height as i32 / 2 + 40,
+            game_state.theme.text_dim,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Renderiza as vidas de cada jogador no modo de 4 jogadores, perto da
+/// parede que ele defende
+fn render_lives(
+    canvas: &mut Canvas<Window>,
+    fonts: &Fonts,
+    game_state: &GameState,
+    four_player: &FourPlayer,
+    locale: &Locale,
+) -> Result<(), String> {
+    let texture_creator = canvas.texture_creator();
+    let width = game_state.screen_width as i32;
+    let height = game_state.screen_height as i32;
+
+    for wall in Wall::ALL {
+        let player = wall.player();
+        let (x, y) = match wall {
+            Wall::Left => (110, height / 2),
+            Wall::Right => (width - 110, height / 2),
+            Wall::Top => (width / 2, 60),
+            Wall::Bottom => (width / 2, height - 85),
+        };
+
+        let name_key = format!("player.{}", player + 1);
+        let name = locale.get(&name_key);
+        let (text, color) = if four_player.alive(wall) {
+            (
+                locale.format(
+                    "hud.lives",
+                    &[("player", &name), ("lives", &four_player.lives[player])],
+                ),
+                game_state.theme.player_color(player),
+            )
+        } else {
+            (
+                locale.format("hud.eliminated", &[("player", &name)]),
+                game_state.theme.text_dim,
+            )
+        };
+
+        render_text(canvas, &texture_creator, &fonts.small, &text, x, y, color)?;
+    }
+
+    Ok(())
+}
+
+/// Renderiza a tela de fim de jogo
+///
+/// # Argumentos
+///
+/// * `canvas` - Canvas SDL para desenhar
+/// * `fonts` - Fontes para renderizar texto
+/// * `game_state` - Estado atual do jogo
+/// * `locale` - Idioma dos textos
+pub fn render_game_over(
+    canvas: &mut Canvas<Window>,
+    fonts: &Fonts,
+    game_state: &GameState,
+    locale: &Locale,
+) -> Result<(), String> {
+    let texture_creator = canvas.texture_creator();
+    let center_x = game_state.screen_width as i32 / 2;
+    let center_y = game_state.screen_height as i32 / 2;
+
+    // Determina o vencedor
+    let winner = game_state.winner_player().unwrap_or(0);
+    let winner_key = if game_state.is_doubles() {
+        format!("game_over.team{}_wins", winner + 1)
+    } else {
+        format!("game_over.player{}_wins", winner + 1)
+    };
+    let mut winner_text = locale.get(&winner_key);
+    let mut winner_color = game_state.theme.player_color(winner);
+
+    let rule_text = if let Some(practice) = &game_state.practice {
+        // O treino não tem vencedor
+        winner_text = locale.get("game_over.practice_over");
+        winner_color = game_state.theme.player_left;
+        let target = locale.get(practice.target.label_key());
+        locale.format("practice.rule", &[("target", &target)])
+    } else if game_state.four_player.is_some() {
+        locale.format("four_player.rule", &[("lives", &game_state.lives)])
+    } else if game_state.breakout.is_some() {
+        locale.get("breakout.rule").to_string()
+    } else {
+        win_rule_text(locale, game_state)
+    };
+
+    render_text(
+        canvas,
+        &texture_creator,
+        &fonts.small,
+        &rule_text,
+        center_x,
+        center_y - 90,
+        game_state.theme.highlight,
+    )?;
+
+    render_text(
+        canvas,
+        &texture_creator,
+        &fonts.large,
+        winner_text,
+        center_x,
+        center_y - 40,
+        winner_color,
+    )?;
+
+    render_text(
+        canvas,
+        &texture_creator,
+        &fonts.large,
+        locale.get("game_over.new_game"),
+        center_x,
+        center_y + 20,
+        game_state.theme.text,
+    )?;
+
+    render_text(
+        canvas,
+        &texture_creator,
+        &fonts.large,
+        locale.get("game_over.back_to_menu"),
+        center_x,
+        center_y + 60,
+        game_state.theme.text_dim,
+    )?;
+
+    // Resumo da partida
+    let stats = &game_state.stats;
+    let mut summary = Vec::new();
+    if let Some(practice) = &game_state.practice {
+        summary.push(locale.format("summary.best_streak", &[("hits", &practice.best_streak)]));
+        summary.push(locale.format(
+            "summary.practice_returns",
+            &[("returns", &practice.returns), ("misses", &practice.misses)],
+        ));
+    } else if game_state.four_player.is_none() && game_state.match_score.has_games() {
+        let sets: Vec<String> = game_state
+            .match_score
+            .finished_sets
+            .iter()
+            .map(|(left, right)| format!("{}-{}", left, right))
+            .collect();
+        summary.push(locale.format("summary.sets", &[("sets", &sets.join("  "))]));
+    }
+    if let Some(breakout) = &game_state.breakout {
+        summary.push(locale.format(
+            "summary.bricks",
+            &[
+                ("left", &breakout.broken[0]),
+                ("right", &breakout.broken[1]),
+            ],
+        ));
+    } else if game_state.four_player.is_none() && game_state.practice.is_none() {
+        summary.push(locale.format(
+            "summary.points",
+            &[("left", &stats.points_left), ("right", &stats.points_right)],
+        ));
+    }
+    summary.push(locale.format("summary.longest_rally", &[("hits", &stats.longest_rally)]));
+    summary.push(locale.format(
+        "summary.duration",
+        &[("time", &format_time(stats.duration))],
+    ));
+
+    for (index, line) in summary.iter().enumerate() {
+        render_text(
+            canvas,
+            &texture_creator,
+            &fonts.small,
+            line,
+            center_x,
+            center_y + 110 + index as i32 * 25,
+            game_state.theme.text,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Renderiza a tela de resultado de uma partida de arcade: pontuação, detalhes
+/// e o ranking do modo, com o resultado da partida destacado
+///
+/// # Argumentos
+///
+/// * `canvas` - Canvas SDL para desenhar
+/// * `fonts` - Fontes para renderizar texto
+/// * `app` - Estado da aplicação
+pub fn render_arcade_results(
+    canvas: &mut Canvas<Window>,
+    fonts: &Fonts,
+    app: &App,
+) -> Result<(), String> {
+    let game_state = &app.game_state;
+    let Some(arcade) = &game_state.arcade else {
+        return Ok(());
+    };
+    let locale = &app.locale;
+    let theme = &game_state.theme;
+    let texture_creator = canvas.texture_creator();
+    let center_x = game_state.screen_width as i32 / 2;
+
+    draw_overlay(canvas, game_state);
+
+    // Modo e regra de pontuação
+    render_text(
+        canvas,
+        &texture_creator,
+        &fonts.small,
+        locale.get(arcade.mode.label_key()),
+        center_x,
+        40,
+        theme.highlight,
+    )?;
+    render_text(
+        canvas,
+        &texture_creator,
+        &fonts.small,
+        locale.get(arcade.mode.rule_key()),
+        center_x,
+        65,
+        theme.text_dim,
+    )?;
+
+    // Pontuação final e detalhes da partida
+    let result = arcade.result();
+    render_text(
+        canvas,
+        &texture_creator,
+        &fonts.large,
+        &locale.format("arcade.score", &[("score", &result.value)]),
+        center_x,
+        100,
+        theme.player_left,
+    )?;
+    render_text(
+        canvas,
+        &texture_creator,
+        &fonts.small,
+        &arcade_detail(locale, arcade.mode, &result),
+        center_x,
+        145,
+        theme.text,
+    )?;
+    if app.arcade_rank == Some(0) {
+        render_text(
+            canvas,
+            &texture_creator,
+            &fonts.small,
+            locale.get("arcade.new_record"),
+            center_x,
+            170,
+            theme.highlight,
+        )?;
+    }
+
+    // Ranking do modo
+    render_text(
+        canvas,
+        &texture_creator,
+        &fonts.small,
+        locale.get("arcade.leaderboard"),
+        center_x,
+        215,
+        theme.highlight,
+    )?;
+    render_leaderboard(
+        canvas,
+        fonts,
+        app,
+        Board::arcade(arcade.mode),
+        app.arcade_rank,
+        245,
+    )?;
+
+    render_text(
+        canvas,
+        &texture_creator,
+        &fonts.large,
+        locale.get("game_over.back_to_menu"),
+        center_x,
+        440,
+        theme.text_dim,
+    )?;
+
+    Ok(())
+}
+
+/// Renderiza a tela de nome de um perfil novo
+///
+/// # Argumentos
+///
+/// * `canvas` - Canvas SDL para desenhar
+/// * `fonts` - Fontes para renderizar texto
+/// * `app` - Estado da aplicação
+pub fn render_name_entry(
+    canvas: &mut Canvas<Window>,
+    fonts: &Fonts,
+    app: &App,
+) -> Result<(), String> {
+    let Some(name_entry) = &app.name_entry else {
+        return Ok(());
+    };
+    let locale = &app.locale;
+    let theme = &app.game_state.theme;
+    let center_x = app.game_state.screen_width as i32 / 2;
+
+    canvas.set_draw_color(theme.background);
+    canvas.clear();
+
+    let texture_creator = canvas.texture_creator();
+
+    let (title, prompt) = match name_entry.purpose {
+        NamePurpose::Profile => ("profiles.new", "profiles.enter_name"),
+        NamePurpose::TournamentPlayer => ("tournament.add_player", "tournament.enter_name"),
+    };
+    render_text(
+        canvas,
+        &texture_creator,
+        &fonts.large,
+        locale.get(title),
+        center_x,
+        60,
+        theme.player_left,
+    )?;
+    render_text(
+        canvas,
+        &texture_creator,
+        &fonts.small,
+        locale.get(prompt),
+        center_x,
+        200,
+        theme.text,
+    )?;
+
+    // Nome digitado com o cursor no fim
+    render_text(
+        canvas,
+        &texture_creator,
+        &fonts.large,
+        &format!("{}_", name_entry.text),
+        center_x,
+        250,
+        theme.accent,
+    )?;
+
+    if let Some(error) = name_entry.error {
+        render_text(
+            canvas,
+            &texture_creator,
+            &fonts.small,
+            locale.get(error),
+            center_x,
+            310,
+            theme.highlight,
+        )?;
+    }
+
+    render_text(
+        canvas,
+        &texture_creator,
+        &fonts.small,
+        locale.get("profiles.name_hint"),
+        center_x,
+        545,
+        theme.text_dim,
+    )
+}
+
+/// Renderiza, no fim de uma partida de torneio, quem venceu a partida
+///
+/// # Argumentos
+///
+/// * `canvas` - Canvas SDL para desenhar
+/// * `fonts` - Fontes para renderizar texto
+/// * `app` - Estado da aplicação
+pub fn render_tournament_result(
+    canvas: &mut Canvas<Window>,
+    fonts: &Fonts,
+    app: &App,
+) -> Result<(), String> {
+    let (Some(players), Some(winner)) = (&app.tournament_match, app.game_state.winner) else {
+        return Ok(());
+    };
+    let game_state = &app.game_state;
+    let texture_creator = canvas.texture_creator();
+
+    let name = match winner {
+        Side::Left => &players.left,
+        Side::Right => &players.right,
+    };
+    render_text(
+        canvas,
+        &texture_creator,
+        &fonts.small,
+        &app.locale
+            .format("tournament.match_winner", &[("name", name)]),
+        game_state.screen_width as i32 / 2,
+        game_state.screen_height as i32 / 2 - 125,
+        game_state.theme.accent,
+    )
+}
+
+/// Renderiza o editor de arenas: a quadra em edição, a ferramenta escolhida e
+/// as teclas do editor
+pub fn render_editor(canvas: &mut Canvas<Window>, fonts: &Fonts, app: &App) -> Result<(), String> {
+    let Some(editor) = &app.editor else {
+        return Ok(());
+    };
+    let locale = &app.locale;
+    let theme = &app.game_state.theme;
+    let center_x = app.game_state.screen_width as i32 / 2;
+
+    editor.render(canvas, theme);
+
+    let texture_creator = canvas.texture_creator();
+
+    let tool = locale.get(editor.tool.label_key());
+    let title = locale.format(
+        "editor.title",
+        &[
+            ("arena", &arena_name(locale, &editor.arena)),
+            ("tool", &tool),
+        ],
+    );
+    render_text(
+        canvas,
+        &texture_creator,
+        &fonts.small,
+        &title,
+        center_x,
+        10,
+        theme.text,
+    )?;
+
+    // Resultado da última vez que a arena foi salva
+    if let Some(status) = &editor.status {
+        render_text(
+            canvas,
+            &texture_creator,
+            &fonts.small,
+            status,
+            center_x,
+            35,
+            theme.highlight,
+        )?;
+    }
+
+    for (index, key) in ["editor.help_mouse", "editor.help_keys"].iter().enumerate() {
+        render_text(
+            canvas,
+            &texture_creator,
+            &fonts.small,
+            locale.get(key),
+            center_x,
+            545 + index as i32 * 25,
+            theme.text_dim,
+        )?;
+    }
+
+    Ok(())
+}
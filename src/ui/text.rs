@@ -0,0 +1,65 @@
+// Renderização de texto com SDL2_ttf
+
+use crate::locale;
+use sdl2::pixels::Color;
+use sdl2::rect::Rect;
+use sdl2::render::{Canvas, TextureCreator};
+use sdl2::ttf::Font;
+use sdl2::video::{Window, WindowContext};
+
+/// Função auxiliar para renderizar texto centralizado
+///
+/// # Argumentos
+///
+/// * `canvas` - Canvas SDL para desenhar
+/// * `texture_creator` - Criador de texturas SDL
+/// * `font` - Fonte para renderizar texto
+/// * `text` - Texto a ser renderizado
+/// * `x` - Posição X do centro do texto
+/// * `y` - Posição Y do topo do texto
+/// * `color` - Cor do texto
+pub fn render_text(
+    canvas: &mut Canvas<Window>,
+    texture_creator: &TextureCreator<WindowContext>,
+    font: &Font,
+    text: &str,
+    x: i32,
+    y: i32,
+    color: Color,
+) -> Result<(), String> {
+    // Substitui caracteres que a fonte não possui por versões sem acento
+    let text: String = text
+        .chars()
+        .map(|ch| {
+            if ch.is_whitespace() || font.find_glyph(ch).is_some() {
+                ch
+            } else {
+                locale::fallback_glyph(ch)
+            }
+        })
+        .collect();
+
+    // Cria uma superfície com o texto renderizado
+    let surface = font
+        .render(&text)
+        .blended(color)
+        .map_err(|e| e.to_string())?;
+
+    // Converte a superfície em textura
+    let texture = texture_creator
+        .create_texture_from_surface(&surface)
+        .map_err(|e| e.to_string())?;
+
+    // Obtém as dimensões do texto
+    let text_query = texture.query();
+    let text_width = text_query.width;
+    let text_height = text_query.height;
+
+    // Calcula a posição para centralizar o texto
+    let target = Rect::new(x - text_width as i32 / 2, y, text_width, text_height);
+
+    // Desenha o texto na tela
+    canvas.copy(&texture, None, Some(target))?;
+
+    Ok(())
+}